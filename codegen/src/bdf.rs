@@ -0,0 +1,424 @@
+//! Export and import of rasterized characters as BDF bitmap fonts, as used by X11
+//! and many embedded toolchains. PCF fonts can be created from BDF fonts with
+//! `bdftopcf`.
+//!
+//! Format: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5005.BDF_Spec.pdf>
+
+use crate::SUPPORTED_UNICODE_RANGES;
+use crate::font::{FontWeight, RasterizationInfo};
+use crate::monochrome::Monochrome;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The resolution in DPI that the BDF fonts are created for. At 72 DPI, one point
+/// is one pixel.
+const BDF_RESOLUTION: usize = 72;
+
+/// A monospace BDF font whose glyphs all fill the same cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BdfFont {
+    /// Width of the cell in pixels.
+    pub width: usize,
+    /// Height of the cell in pixels, i.e., `ascent + descent`.
+    pub height: usize,
+    /// Number of rows above the baseline.
+    pub ascent: usize,
+    /// Number of rows below the baseline.
+    pub descent: usize,
+    /// All glyphs in the order of the file.
+    pub glyphs: Vec<BdfGlyph>,
+}
+
+/// A glyph of a [`BdfFont`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BdfGlyph {
+    /// The character of the glyph.
+    pub c: char,
+    /// Rows of pixels that are on (`true`) or off, covering the whole cell.
+    pub pixels: Vec<Vec<bool>>,
+}
+
+/// Creates a BDF font with all characters of [`SUPPORTED_UNICODE_RANGES`]
+/// rasterized by `font`, converted to 1 bit per pixel with `mode`.
+///
+/// The font properties, such as the ascent, the x-height, and the underline
+/// position, are computed from the TTF font.
+pub fn bdf_from_font(font: &RasterizationInfo, weight: &FontWeight, mode: Monochrome) -> String {
+    let bdf = BdfFont {
+        width: font.raster_width(),
+        height: font.raster_height(),
+        ascent: font.baseline(),
+        descent: font.raster_height() - font.baseline(),
+        glyphs: SUPPORTED_UNICODE_RANGES
+            .iter()
+            .flat_map(|range| range.iter())
+            .map(|c| BdfGlyph {
+                c,
+                pixels: mode.convert(&font.rasterize(c)),
+            })
+            .collect(),
+    };
+
+    let pixel_size = font.font_size().round() as usize;
+    let point_size = pixel_size * 10 * 72 / BDF_RESOLUTION;
+    let weight_name = format!("{:?}", weight.name());
+    let decorations = font.decoration_metrics();
+
+    let name = format!(
+        "-Google-Noto Sans Mono-{weight_name}-R-Normal--{pixel_size}-{point_size}-{BDF_RESOLUTION}-{BDF_RESOLUTION}-C-{}-ISO10646-1",
+        bdf.width * 10
+    );
+    let mut properties = vec![
+        ("FOUNDRY", "\"Google\"".to_string()),
+        ("FAMILY_NAME", "\"Noto Sans Mono\"".to_string()),
+        ("WEIGHT_NAME", format!("\"{weight_name}\"")),
+        ("SLANT", "\"R\"".to_string()),
+        ("SETWIDTH_NAME", "\"Normal\"".to_string()),
+        ("ADD_STYLE_NAME", "\"\"".to_string()),
+        ("PIXEL_SIZE", pixel_size.to_string()),
+        ("POINT_SIZE", point_size.to_string()),
+        ("RESOLUTION_X", BDF_RESOLUTION.to_string()),
+        ("RESOLUTION_Y", BDF_RESOLUTION.to_string()),
+        ("SPACING", "\"C\"".to_string()),
+        ("AVERAGE_WIDTH", (bdf.width * 10).to_string()),
+        ("CHARSET_REGISTRY", "\"ISO10646\"".to_string()),
+        ("CHARSET_ENCODING", "\"1\"".to_string()),
+        ("FONT_ASCENT", bdf.ascent.to_string()),
+        ("FONT_DESCENT", bdf.descent.to_string()),
+        ("X_HEIGHT", (font.x_height().round() as usize).to_string()),
+        (
+            "CAP_HEIGHT",
+            (font.capital_height().round() as usize).to_string(),
+        ),
+        // offset from the baseline to the top of the underline, positive values are
+        // below the baseline
+        (
+            "UNDERLINE_POSITION",
+            (decorations.underline_position as isize - bdf.ascent as isize).to_string(),
+        ),
+        (
+            "UNDERLINE_THICKNESS",
+            decorations.underline_thickness.to_string(),
+        ),
+    ];
+    if bdf
+        .glyphs
+        .iter()
+        .any(|g| g.c == char::REPLACEMENT_CHARACTER)
+    {
+        properties.push((
+            "DEFAULT_CHAR",
+            (char::REPLACEMENT_CHARACTER as u32).to_string(),
+        ));
+    }
+
+    bdf.encode(&name, pixel_size, &properties)
+}
+
+impl BdfFont {
+    /// Encodes the font as BDF file with the given XLFD name and properties.
+    pub fn encode(&self, name: &str, pixel_size: usize, properties: &[(&str, String)]) -> String {
+        let mut bdf = String::new();
+        writeln!(bdf, "STARTFONT 2.1").unwrap();
+        writeln!(bdf, "FONT {name}").unwrap();
+        writeln!(bdf, "SIZE {pixel_size} {BDF_RESOLUTION} {BDF_RESOLUTION}").unwrap();
+        writeln!(
+            bdf,
+            "FONTBOUNDINGBOX {} {} 0 -{}",
+            self.width, self.height, self.descent
+        )
+        .unwrap();
+        writeln!(bdf, "STARTPROPERTIES {}", properties.len()).unwrap();
+        for (key, value) in properties {
+            writeln!(bdf, "{key} {value}").unwrap();
+        }
+        writeln!(bdf, "ENDPROPERTIES").unwrap();
+
+        writeln!(bdf, "CHARS {}", self.glyphs.len()).unwrap();
+        for glyph in &self.glyphs {
+            writeln!(bdf, "STARTCHAR U+{:04X}", glyph.c as u32).unwrap();
+            writeln!(bdf, "ENCODING {}", glyph.c as u32).unwrap();
+            // advance width in 1/1000 of the pixel size
+            writeln!(bdf, "SWIDTH {} 0", self.width * 1000 / pixel_size.max(1)).unwrap();
+            writeln!(bdf, "DWIDTH {} 0", self.width).unwrap();
+            writeln!(
+                bdf,
+                "BBX {} {} 0 -{}",
+                self.width, self.height, self.descent
+            )
+            .unwrap();
+            writeln!(bdf, "BITMAP").unwrap();
+            for row in &glyph.pixels {
+                let mut row_bytes = vec![0_u8; self.width.div_ceil(8)];
+                for (x, _) in row.iter().enumerate().filter(|(_, on)| **on) {
+                    row_bytes[x / 8] |= 0x80 >> (x % 8);
+                }
+                for byte in row_bytes {
+                    write!(bdf, "{byte:02X}").unwrap();
+                }
+                writeln!(bdf).unwrap();
+            }
+            writeln!(bdf, "ENDCHAR").unwrap();
+        }
+        writeln!(bdf, "ENDFONT").unwrap();
+        bdf
+    }
+
+    /// Parses a BDF font. The glyphs are placed in the cell of the font, which is
+    /// given by the `FONTBOUNDINGBOX` and the `FONT_ASCENT` and `FONT_DESCENT`
+    /// properties. Pixels outside the cell are cut off. Glyphs without a unicode
+    /// encoding are skipped.
+    pub fn parse(bdf: &str) -> Result<Self, String> {
+        let mut width = None;
+        let mut bounding_box_descent = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut glyphs = Vec::new();
+
+        // state of the current glyph: encoding, bounding box, and bitmap rows
+        let mut encoding = None;
+        let mut bbx = None;
+        let mut bitmap: Option<Vec<Vec<u8>>> = None;
+
+        let number = |value: Option<&str>, line: &str| {
+            value
+                .and_then(|v| v.parse::<isize>().ok())
+                .ok_or_else(|| format!("invalid line '{line}'"))
+        };
+
+        for line in bdf.lines().map(str::trim) {
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+
+            if let Some(rows) = bitmap.as_mut() {
+                if keyword == "ENDCHAR" {
+                    let (w, h, x, y) = bbx.ok_or("glyph without BBX")?;
+                    if let Some(c) = encoding.and_then(|e: isize| char::from_u32(e as u32)) {
+                        glyphs.push((c, (w, h, x, y), bitmap.take().unwrap()));
+                    } else {
+                        bitmap = None;
+                    }
+                    encoding = None;
+                    bbx = None;
+                } else {
+                    let invalid = || format!("invalid bitmap row '{line}'");
+                    // each byte of the row is encoded in two hex digits
+                    if !line.is_ascii() || line.len() % 2 != 0 {
+                        return Err(invalid());
+                    }
+                    let row = (0..line.len() / 2)
+                        .map(|i| u8::from_str_radix(&line[2 * i..2 * i + 2], 16))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| invalid())?;
+                    rows.push(row);
+                }
+                continue;
+            }
+
+            match keyword {
+                "FONTBOUNDINGBOX" => {
+                    width = Some(number(words.next(), line)?);
+                    let _height = number(words.next(), line)?;
+                    let _x = number(words.next(), line)?;
+                    bounding_box_descent = Some(-number(words.next(), line)?);
+                }
+                "FONT_ASCENT" => ascent = Some(number(words.next(), line)?),
+                "FONT_DESCENT" => descent = Some(number(words.next(), line)?),
+                "ENCODING" => encoding = Some(number(words.next(), line)?),
+                "BBX" => {
+                    bbx = Some((
+                        number(words.next(), line)?,
+                        number(words.next(), line)?,
+                        number(words.next(), line)?,
+                        number(words.next(), line)?,
+                    ))
+                }
+                "BITMAP" => bitmap = Some(Vec::new()),
+                _ => {}
+            }
+        }
+
+        let width = width.ok_or("missing FONTBOUNDINGBOX")?;
+        let descent = descent
+            .or(bounding_box_descent)
+            .ok_or("missing FONT_DESCENT")?;
+        let ascent = ascent.ok_or("missing FONT_ASCENT")?;
+        let height = ascent + descent;
+        if width <= 0 || ascent < 0 || descent < 0 || height <= 0 {
+            return Err(format!("invalid cell {width}x{ascent}+{descent}"));
+        }
+
+        let glyphs = glyphs
+            .into_iter()
+            .map(|(c, (w, h, x_offset, y_offset), rows)| {
+                let mut pixels = vec![vec![false; width as usize]; height as usize];
+                for (row_i, row) in rows.iter().enumerate().take(h.max(0) as usize) {
+                    // the bottom of the bounding box is `y_offset` rows above the baseline
+                    let y = ascent - (y_offset + h) + row_i as isize;
+                    for col_i in 0..w.max(0) as usize {
+                        let x = x_offset + col_i as isize;
+                        let on = row
+                            .get(col_i / 8)
+                            .is_some_and(|byte| byte & (0x80 >> (col_i % 8)) != 0);
+                        if on && (0..height).contains(&y) && (0..width).contains(&x) {
+                            pixels[y as usize][x as usize] = true;
+                        }
+                    }
+                }
+                BdfGlyph { c, pixels }
+            })
+            .collect();
+
+        Ok(Self {
+            width: width as usize,
+            height: height as usize,
+            ascent: ascent as usize,
+            descent: descent as usize,
+            glyphs,
+        })
+    }
+
+    /// Converts the glyphs into rasters for [`RasterizationInfo::with_overrides`].
+    /// The cell of the font must match the raster of `font`, i.e., the font must be
+    /// derived from a BDF font that [`bdf_from_font`] created for the same font
+    /// weight and raster height.
+    pub fn to_rasters(
+        &self,
+        font: &RasterizationInfo,
+    ) -> Result<HashMap<char, Vec<Vec<u8>>>, String> {
+        let expected = (font.raster_width(), font.raster_height(), font.baseline());
+        let actual = (self.width, self.height, self.ascent);
+        if expected != actual {
+            return Err(format!(
+                "the cell (width, height, ascent) of the BDF font is {actual:?} but the raster is {expected:?}"
+            ));
+        }
+
+        Ok(self
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let raster = glyph
+                    .pixels
+                    .iter()
+                    .map(|row| row.iter().map(|&on| if on { 255 } else { 0 }).collect())
+                    .collect();
+                (glyph.c, raster)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::{FontWeightName, noto_font_by_weight};
+
+    #[test]
+    fn test_bdf_roundtrip() {
+        let weight = FontWeight::new(FontWeightName::Regular, false);
+        let font = RasterizationInfo::new(16, noto_font_by_weight(&weight));
+        let bdf = bdf_from_font(&font, &weight, Monochrome::default());
+
+        assert!(bdf.contains("FONTBOUNDINGBOX 7 16 0 -5\n"));
+        assert!(bdf.contains("FONT_ASCENT 11\n"));
+        assert!(bdf.contains("FONT_DESCENT 5\n"));
+        assert!(bdf.contains("STARTCHAR U+0041\nENCODING 65\n"));
+
+        let parsed = BdfFont::parse(&bdf).unwrap();
+        assert_eq!((parsed.width, parsed.height), (7, 16));
+        assert_eq!((parsed.ascent, parsed.descent), (11, 5));
+        let a = parsed.glyphs.iter().find(|g| g.c == 'A').unwrap();
+        assert_eq!(
+            a.pixels,
+            Monochrome::default().convert(&font.rasterize('A'))
+        );
+
+        let rasters = parsed.to_rasters(&font).unwrap();
+        assert_eq!(rasters.len(), parsed.glyphs.len());
+        let font = font.with_overrides(rasters);
+        let raster = font.rasterize('A');
+        assert!(raster.iter().flatten().all(|&i| i == 0 || i == 255));
+
+        // the subpixel raster has the same glyph
+        let subpixel_raster = font.rasterize_subpixel('A');
+        for (row, subpixel_row) in raster.iter().zip(&subpixel_raster) {
+            assert_eq!(subpixel_row.len(), 3 * row.len());
+            for (&intensity, subpixels) in row.iter().zip(subpixel_row.chunks(3)) {
+                assert_eq!(subpixels, [intensity; 3]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_bounding_boxes() {
+        // a glyph with a small bounding box, a glyph without encoding, and a glyph that
+        // exceeds the cell
+        let bdf = "\
+STARTFONT 2.1
+FONTBOUNDINGBOX 4 4 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 3
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 3
+STARTCHAR dot
+ENCODING 46
+BBX 1 1 1 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR bar
+ENCODING 124
+BBX 1 6 3 -2
+BITMAP
+80
+80
+80
+80
+80
+80
+ENDCHAR
+ENDFONT
+";
+        let font = BdfFont::parse(bdf).unwrap();
+        assert_eq!((font.width, font.height, font.ascent), (4, 4, 3));
+        assert_eq!(font.glyphs.len(), 2);
+
+        let dot = &font.glyphs[0];
+        assert_eq!(dot.c, '.');
+        assert_eq!(
+            dot.pixels,
+            [
+                [false, false, false, false],
+                [false, false, false, false],
+                [false, true, false, false],
+                [false, false, false, false],
+            ]
+        );
+
+        let bar = &font.glyphs[1];
+        assert!(
+            bar.pixels
+                .iter()
+                .all(|row| row == &[false, false, false, true])
+        );
+
+        assert!(BdfFont::parse("STARTFONT 2.1\nENDFONT\n").is_err());
+
+        // bitmap rows with non-ASCII characters or an incomplete byte
+        for row in ["8ä", "80F", "8"] {
+            let bdf = bdf.replacen("BITMAP\n80\n", &format!("BITMAP\n{row}\n"), 1);
+            assert_eq!(
+                BdfFont::parse(&bdf).err(),
+                Some(format!("invalid bitmap row '{row}'"))
+            );
+        }
+    }
+}
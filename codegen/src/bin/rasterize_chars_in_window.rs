@@ -1,20 +1,28 @@
 //! Helper binary to rasterize a few letters with fontdue in a window and display its
 //! rasterized dimensions.
+//!
+//! The upper row shows the letters without hinting, the lower row with
+//! [`Hinting::GridFit`]. This way, both can be compared.
 
-use codegen::font::RasterizationInfo;
+use codegen::font::{Hinting, RasterizationInfo};
 use minifb::{Key, Window, WindowOptions};
 
+/// Each pixel of the raster is drawn as a square of this many pixels, so that the
+/// pixel grid is visible.
+const ZOOM: usize = 4;
+
 fn main() {
     // letters we want to print
-    let msg = "ÄyA-#+`?ß§qg";
+    let msg = "ÄyA-#+`?ß§qgmwl";
 
     let font_bytes = include_bytes!("../res/NotoSansMono-Regular.ttf") as &[u8];
 
-    let raster_height = 36;
-    let font = RasterizationInfo::new(raster_height, font_bytes);
-    let height = font.raster_height();
+    let raster_height = 16;
+    let fonts = [Hinting::None, Hinting::GridFit]
+        .map(|hinting| RasterizationInfo::new(raster_height, font_bytes).with_hinting(hinting));
+    let height = fonts[0].raster_height() * fonts.len() * ZOOM;
     // make sure N mono-sized letters can be inside the frame buffer
-    let width = font.raster_width() * msg.chars().count();
+    let width = fonts[0].raster_width() * msg.chars().count() * ZOOM;
     let mut draw_buffer: Vec<u32> = vec![0; width * height];
 
     // set white background
@@ -24,28 +32,38 @@ fn main() {
         *i = rgb_32;
     }
 
-    // rasterize each char and draw it into the framebuffer
-    for (char_i, char) in msg.chars().enumerate() {
-        let letter_bitmap = font.rasterize(char);
-        for (row_i, row) in letter_bitmap.iter().enumerate() {
-            for (col_i, intensity) in row.iter().enumerate() {
-                let (r, g, b) = (*intensity as u32, *intensity as u32, *intensity as u32);
-                let (r, g, b) = (255 - r, 255 - g, 255 - b);
-                let rgb_32 = /*0 << 24 | */r << 16 | g << 8 | b;
-
-                let index = char_i * font.raster_width() + col_i + row_i * width;
+    // rasterize each char and draw it into the framebuffer, one row per hinting option
+    for (font_i, font) in fonts.iter().enumerate() {
+        let mut blurriness = 0;
+        for (char_i, char) in msg.chars().enumerate() {
+            let letter_bitmap = font.rasterize(char);
+            blurriness += RasterizationInfo::blurriness(&letter_bitmap);
+            for (row_i, row) in letter_bitmap.iter().enumerate() {
+                for (col_i, intensity) in row.iter().enumerate() {
+                    let (r, g, b) = (*intensity as u32, *intensity as u32, *intensity as u32);
+                    let (r, g, b) = (255 - r, 255 - g, 255 - b);
+                    let rgb_32 = /*0 << 24 | */r << 16 | g << 8 | b;
 
-                draw_buffer[index] = rgb_32;
+                    let x = (char_i * font.raster_width() + col_i) * ZOOM;
+                    let y = (font_i * font.raster_height() + row_i) * ZOOM;
+                    for zoom_y in 0..ZOOM {
+                        for zoom_x in 0..ZOOM {
+                            draw_buffer[x + zoom_x + (y + zoom_y) * width] = rgb_32;
+                        }
+                    }
+                }
             }
         }
+        // lower is crisper
+        println!("{:?}: blurriness = {}", font.hinting(), blurriness);
     }
 
-    // draw red borders for 3x1 grid
+    // draw red borders for the grid
     for border_i in 1..msg.chars().count() {
         let (r, g, b) = (255, 0, 0);
         let rgb_32 = /*0 << 24 |*/ r << 16 | g << 8 | b;
         for j in 0..height {
-            let index = border_i * font.raster_width() + j * width;
+            let index = border_i * fonts[0].raster_width() * ZOOM + j * width;
             draw_buffer[index] = rgb_32;
         }
     }
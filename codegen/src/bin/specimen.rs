@@ -0,0 +1,191 @@
+//! Headless helper binary that renders specimen sheets of all glyphs, so that the
+//! glyph quality can be reviewed without a display, e.g., by diffing the images in
+//! a pull request.
+//!
+//! Usage: `cargo run --bin specimen [--screenshot] [output directory]`
+//!
+//! Writes one PGM and one PNG file per font weight and raster height into the output
+//! directory (default: `codegen/target/specimen`). Each sheet shows all characters of
+//! all unicode ranges in a labeled grid. With `--screenshot`, it also regenerates the
+//! screenshot in the README of the library (`screenshot_bitmap_font.png`).
+
+use codegen::font::{FontWeight, RasterizationInfo, SUPPORTED_FONT_WEIGHTS, noto_font_by_weight};
+use codegen::image::Image;
+use codegen::unicode::UnicodeRange;
+use codegen::{DEFAULT_OUTPUT_DIR, HINTING, SUPPORTED_RASTER_HEIGHTS, SUPPORTED_UNICODE_RANGES};
+use std::path::{Path, PathBuf};
+
+/// Number of glyphs per row of a specimen sheet.
+const COLUMNS: usize = 16;
+/// Space around the content of a sheet in pixels.
+const MARGIN: usize = 8;
+/// Gray value of the lines between the glyphs.
+const GRID_COLOR: u8 = 200;
+/// Raster height of the labels.
+const LABEL_RASTER_HEIGHT: usize = 16;
+
+/// Characters of the screenshot in the README.
+const SCREENSHOT_CHARS: &str = "ÄyA-#+`?ß§";
+/// Each pixel of the screenshot is drawn as a square of this many pixels.
+const SCREENSHOT_ZOOM: usize = 3;
+/// File name of the screenshot in the README, relative to [`DEFAULT_OUTPUT_DIR`].
+const SCREENSHOT_FILE_NAME: &str = "screenshot_bitmap_font.png";
+/// The default output directory of the specimen sheets.
+const DEFAULT_SPECIMEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/specimen");
+
+/// A line of a specimen sheet.
+enum Line {
+    /// A label, such as the name of a unicode range.
+    Label(String),
+    /// A row of the glyph grid.
+    Glyphs(Vec<char>),
+    /// Space between sections.
+    Space,
+}
+
+fn main() {
+    let mut screenshot_requested = false;
+    let mut out_dir = PathBuf::from(DEFAULT_SPECIMEN_DIR);
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--screenshot" => screenshot_requested = true,
+            arg if arg.starts_with("--") => {
+                eprintln!("Usage: specimen [--screenshot] [output directory]");
+                std::process::exit(1)
+            }
+            _ => out_dir = PathBuf::from(arg),
+        }
+    }
+    std::fs::create_dir_all(&out_dir).unwrap();
+
+    let label_font = RasterizationInfo::new(
+        LABEL_RASTER_HEIGHT,
+        noto_font_by_weight(&SUPPORTED_FONT_WEIGHTS[1]),
+    );
+
+    for weight in SUPPORTED_FONT_WEIGHTS {
+        for size in SUPPORTED_RASTER_HEIGHTS {
+            let font = RasterizationInfo::new(size.value() as usize, noto_font_by_weight(weight))
+                .with_hinting(HINTING);
+            let sheet = specimen_sheet(&font, weight, SUPPORTED_UNICODE_RANGES, &label_font);
+
+            let name = format!("{}_{}", weight.mod_name(), size.value());
+            sheet.write_pnm(&out_dir.join(format!("{name}.pgm")));
+            sheet.write_png(&out_dir.join(format!("{name}.png")));
+            println!(
+                "INFO: Wrote specimen sheet '{}'",
+                out_dir.join(&name).display()
+            );
+        }
+    }
+
+    if screenshot_requested {
+        let font = RasterizationInfo::new(32, noto_font_by_weight(&SUPPORTED_FONT_WEIGHTS[1]))
+            .with_hinting(HINTING);
+        let path = Path::new(DEFAULT_OUTPUT_DIR).join(SCREENSHOT_FILE_NAME);
+        screenshot(&font).write_png(&path);
+        println!("INFO: Wrote screenshot '{}'", path.display());
+    }
+}
+
+/// Renders all characters of `ranges` with `font` into a labeled grid.
+fn specimen_sheet(
+    font: &RasterizationInfo,
+    weight: &FontWeight,
+    ranges: &[UnicodeRange],
+    label_font: &RasterizationInfo,
+) -> Image {
+    let mut lines = vec![
+        Line::Label(format!(
+            "Noto Sans Mono {:?}, raster height {}px, font size {}px",
+            weight.name(),
+            font.raster_height(),
+            font.font_size()
+        )),
+        Line::Space,
+    ];
+    for range in ranges {
+        lines.push(Line::Label(range.feature_name().to_string()));
+        let chars = range.iter().collect::<Vec<_>>();
+        lines.extend(chars.chunks(COLUMNS).map(|row| Line::Glyphs(row.to_vec())));
+        lines.push(Line::Space);
+    }
+
+    // each row of glyphs starts with the code point of its first character
+    let row_label_width = "U+0000 ".len() * label_font.raster_width();
+    let cell_width = font.raster_width() + 1;
+    let cell_height = font.raster_height() + 1;
+    let line_height = |line: &Line| match line {
+        Line::Label(_) => label_font.raster_height(),
+        Line::Glyphs(_) => cell_height,
+        Line::Space => MARGIN,
+    };
+
+    let label_width = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Label(label) => Some(label.chars().count() * label_font.raster_width()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0);
+    let grid_width = row_label_width + COLUMNS * cell_width + 1;
+    let width = 2 * MARGIN + label_width.max(grid_width);
+    let height = 2 * MARGIN + lines.iter().map(line_height).sum::<usize>() + 1;
+
+    let mut image = Image::gray(width, height, 255);
+    let mut y = MARGIN;
+    for line in &lines {
+        match line {
+            Line::Label(label) => {
+                image.draw_text(MARGIN, y, label, label_font);
+            }
+            Line::Glyphs(chars) => {
+                let label = format!("U+{:04X}", chars[0] as u32);
+                // vertically centered in the cell
+                let label_y = y + cell_height.saturating_sub(label_font.raster_height()) / 2;
+                image.draw_text(MARGIN, label_y, &label, label_font);
+
+                let x = MARGIN + row_label_width;
+                for (i, c) in chars.iter().enumerate() {
+                    image.draw_raster(x + i * cell_width + 1, y + 1, &font.rasterize(*c), 1);
+                }
+                // grid lines
+                for i in 0..=chars.len() {
+                    for dy in 0..=cell_height {
+                        image.set_pixel(x + i * cell_width, y + dy, &[GRID_COLOR]);
+                    }
+                }
+                for dx in 0..=chars.len() * cell_width {
+                    image.set_pixel(x + dx, y, &[GRID_COLOR]);
+                    image.set_pixel(x + dx, y + cell_height, &[GRID_COLOR]);
+                }
+            }
+            Line::Space => {}
+        }
+        y += line_height(line);
+    }
+    image
+}
+
+/// Renders the screenshot in the README: a few characters, magnified, with red lines
+/// between them.
+fn screenshot(font: &RasterizationInfo) -> Image {
+    let char_width = font.raster_width() * SCREENSHOT_ZOOM;
+    let count = SCREENSHOT_CHARS.chars().count();
+    let mut image = Image::rgb(
+        char_width * count,
+        font.raster_height() * SCREENSHOT_ZOOM,
+        [255; 3],
+    );
+
+    for (i, c) in SCREENSHOT_CHARS.chars().enumerate() {
+        image.draw_raster(i * char_width, 0, &font.rasterize(c), SCREENSHOT_ZOOM);
+    }
+    for i in 1..count {
+        for y in 0..image.height() {
+            image.set_pixel(i * char_width, y, &[255, 0, 0]);
+        }
+    }
+    image
+}
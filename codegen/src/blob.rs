@@ -0,0 +1,207 @@
+//! Export of rasterized characters as a font blob, the binary format that
+//! `FontBlob::parse` of the library reads at runtime.
+//!
+//! This way, bootloaders and kernels can load the glyphs from disk instead of
+//! linking them into the binary.
+//!
+//! Format version 1. All integers are little-endian.
+//!
+//! | Offset | Size               | Content                                           |
+//! |--------|--------------------|---------------------------------------------------|
+//! | 0      | 4                  | Magic `NSMB`                                      |
+//! | 4      | 2                  | Version                                           |
+//! | 6      | 2                  | Number of fonts `f`                               |
+//! | 8      | 4                  | Number of chars `c`                               |
+//! | 12     | 4                  | CRC-32 of all bytes of the blob except these four |
+//! | 16     | `36 * f`           | Font table                                        |
+//! | ...    | `4 * c`            | Index: the code points, sorted in ascending order |
+//! | ...    | ...                | Pixel data of the fonts                           |
+//!
+//! Each entry of the font table describes one font weight in one raster height:
+//!
+//! | Offset | Size | Content                                               |
+//! |--------|------|-------------------------------------------------------|
+//! | 0      | 16   | Name of the font weight, such as `regular`, 0-padded  |
+//! | 16     | 2    | Raster height                                         |
+//! | 18     | 2    | Raster width                                          |
+//! | 20     | 2    | Baseline                                              |
+//! | 22     | 10   | Underline position and thickness, strikeout position  |
+//! |        |      | and thickness, and overline position                  |
+//! | 32     | 4    | Offset of the pixel data of the font in the blob      |
+//!
+//! The pixel data of a font contains one raster of `width * height` bytes per char,
+//! in the order of the index. Each raster is stored row by row.
+
+use crate::SUPPORTED_UNICODE_RANGES;
+use crate::font::{FontWeight, RasterizationInfo};
+
+/// Magic bytes at the beginning of each font blob.
+pub const BLOB_MAGIC: [u8; 4] = *b"NSMB";
+/// Version of the format that this module writes.
+pub const BLOB_VERSION: u16 = 1;
+/// Size of the header in bytes.
+const BLOB_HEADER_SIZE: usize = 16;
+/// Byte range of the checksum in the header.
+const BLOB_CHECKSUM: core::ops::Range<usize> = 12..16;
+/// Size of an entry of the font table in bytes.
+const BLOB_FONT_ENTRY_SIZE: usize = 36;
+/// Size of the name of the font weight in a font table entry.
+const BLOB_WEIGHT_NAME_SIZE: usize = 16;
+
+/// A font of a blob, i.e., one font weight in one raster height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobFont {
+    /// Name of the font weight, such as `regular`.
+    pub weight: String,
+    /// Raster height in pixels.
+    pub height: usize,
+    /// Raster width in pixels.
+    pub width: usize,
+    /// Row of the baseline.
+    pub baseline: usize,
+    /// Underline position and thickness, strikeout position and thickness, and
+    /// overline position.
+    pub decoration_metrics: [usize; 5],
+    /// One raster per char of the blob.
+    pub rasters: Vec<Vec<Vec<u8>>>,
+}
+
+impl BlobFont {
+    /// Rasterizes all `chars` with `font`.
+    pub fn new(weight: &FontWeight, font: &RasterizationInfo, chars: &[char]) -> Self {
+        let metrics = font.decoration_metrics();
+        Self {
+            weight: weight.mod_name().to_string(),
+            height: font.raster_height(),
+            width: font.raster_width(),
+            baseline: font.baseline(),
+            decoration_metrics: [
+                metrics.underline_position,
+                metrics.underline_thickness,
+                metrics.strikeout_position,
+                metrics.strikeout_thickness,
+                metrics.overline_position,
+            ],
+            rasters: chars.iter().map(|&c| font.rasterize(c)).collect(),
+        }
+    }
+}
+
+/// Creates a font blob with all characters of [`SUPPORTED_UNICODE_RANGES`] in all
+/// given fonts.
+pub fn blob_from_fonts(fonts: &[(&FontWeight, RasterizationInfo)]) -> Vec<u8> {
+    let chars = SUPPORTED_UNICODE_RANGES
+        .iter()
+        .flat_map(|range| range.iter())
+        .collect::<Vec<_>>();
+    let fonts = fonts
+        .iter()
+        .map(|(weight, font)| BlobFont::new(weight, font, &chars))
+        .collect::<Vec<_>>();
+    encode_blob(&chars, &fonts)
+}
+
+/// Encodes `fonts` with one raster per char of `chars` as a font blob.
+///
+/// # Panics
+/// Panics if `chars` are not sorted or if a value doesn't fit into its field.
+pub fn encode_blob(chars: &[char], fonts: &[BlobFont]) -> Vec<u8> {
+    assert!(chars.is_sorted_by(|a, b| a < b), "chars must be sorted");
+    let u16 = |value: usize| u16::try_from(value).unwrap().to_le_bytes();
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&BLOB_MAGIC);
+    bytes.extend_from_slice(&BLOB_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&u16(fonts.len()));
+    bytes.extend_from_slice(&u32::try_from(chars.len()).unwrap().to_le_bytes());
+    bytes.extend_from_slice(&[0; 4]); // checksum
+
+    let index_size = 4 * chars.len();
+    let mut data_offset = BLOB_HEADER_SIZE + BLOB_FONT_ENTRY_SIZE * fonts.len() + index_size;
+    for font in fonts {
+        let mut name = [0; BLOB_WEIGHT_NAME_SIZE];
+        assert!(font.weight.len() <= name.len());
+        name[..font.weight.len()].copy_from_slice(font.weight.as_bytes());
+        bytes.extend_from_slice(&name);
+        for field in [font.height, font.width, font.baseline] {
+            bytes.extend_from_slice(&u16(field));
+        }
+        for field in font.decoration_metrics {
+            bytes.extend_from_slice(&u16(field));
+        }
+        bytes.extend_from_slice(&u32::try_from(data_offset).unwrap().to_le_bytes());
+        data_offset += chars.len() * font.width * font.height;
+    }
+
+    for &c in chars {
+        bytes.extend_from_slice(&u32::from(c).to_le_bytes());
+    }
+
+    for font in fonts {
+        assert_eq!(font.rasters.len(), chars.len());
+        for raster in &font.rasters {
+            assert_eq!(raster.len(), font.height);
+            for row in raster {
+                assert_eq!(row.len(), font.width);
+                bytes.extend_from_slice(row);
+            }
+        }
+    }
+    assert_eq!(bytes.len(), data_offset);
+
+    let checksum = crc32(&bytes[..BLOB_CHECKSUM.start], &bytes[BLOB_CHECKSUM.end..]);
+    bytes[BLOB_CHECKSUM].copy_from_slice(&checksum.to_le_bytes());
+    bytes
+}
+
+/// Computes the CRC-32 (ISO-HDLC, as used by zlib) of `a` followed by `b`.
+fn crc32(a: &[u8], b: &[u8]) -> u32 {
+    let crc = a.iter().chain(b).fold(!0_u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    });
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"", b""), 0);
+        assert_eq!(crc32(b"1234", b"56789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_encode_blob() {
+        let font = BlobFont {
+            weight: "regular".to_string(),
+            height: 2,
+            width: 3,
+            baseline: 1,
+            decoration_metrics: [1, 1, 0, 1, 0],
+            rasters: vec![
+                vec![vec![1, 2, 3], vec![4, 5, 6]],
+                vec![vec![7, 8, 9], vec![10, 11, 12]],
+            ],
+        };
+        let bytes = encode_blob(&['A', 'ä'], &[font]);
+
+        assert_eq!(bytes[0..4], BLOB_MAGIC);
+        assert_eq!(bytes[4..6], BLOB_VERSION.to_le_bytes());
+        assert_eq!(bytes[6..8], [1, 0]);
+        assert_eq!(bytes[8..12], [2, 0, 0, 0]);
+        let checksum = crc32(&bytes[..12], &bytes[16..]);
+        assert_eq!(bytes[12..16], checksum.to_le_bytes());
+
+        assert_eq!(&bytes[16..23], b"regular");
+        assert_eq!(bytes[23..32], [0; 9]);
+        assert_eq!(bytes[32..38], [2, 0, 3, 0, 1, 0]);
+        assert_eq!(bytes[38..48], [1, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+        assert_eq!(bytes[48..52], [60, 0, 0, 0]);
+        assert_eq!(bytes[52..60], [b'A', 0, 0, 0, 0xe4, 0, 0, 0]);
+        assert_eq!(bytes[60..], [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    }
+}
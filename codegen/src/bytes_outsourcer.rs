@@ -1,87 +1,142 @@
 use crate::font::FontWeight;
-use std::fs::File;
-use std::io::Write;
+use crate::output::Output;
 use std::path::PathBuf;
 
-/// A helper structure that stores bytes into files in a given directory.
+/// A helper structure that stores bytes into files in the directory of the rasterized
+/// characters of an [`Output`].
 ///
-/// These files will contain the rasterized characters. They can be included with `include!`
-/// afterwards. It names all files in a reproducible way. Each filename is influenced by the
-/// character, the font weight, and the raster height. This way, new unicode ranges can be included
-/// without invaliding old files, which would happen in the case of incremental numbers as file
-/// name.
+/// These files will contain the rasterized characters of a unicode range, e.g., their
+/// interned rows and the corresponding index (see [`crate::intern`]). They can be included with
+/// `include_bytes!` afterwards. It names all files in a reproducible way. Each filename is
+/// influenced by the unicode range, the font weight, and the raster height. This way, new unicode
+/// ranges can be included without invaliding old files, which would happen in the case of
+/// incremental numbers as file name.
 #[derive(Debug)]
 pub struct BytesToFileOutsourcer {
-    out_dir: &'static str,
+    output: Output,
 }
 
 impl BytesToFileOutsourcer {
-    pub const fn new(out_dir: &'static str) -> Self {
-        Self { out_dir }
+    pub const fn new(output: Output) -> Self {
+        Self { output }
     }
 
-    /// Creates a file in the given directory with `bytes` as content.
-    /// The `c` is relevant for the naming of the file.
+    /// Creates a file in the directory of the rasterized characters with `bytes` as
+    /// content. The `ctx` is relevant for the naming of the file.
     pub fn outsource_bytes(&mut self, bytes: &[u8], ctx: Context) -> PathBuf {
         let path = self.generate_path(ctx);
-
-        let mut file = File::options()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.as_path())
-            .unwrap();
-        file.write_all(bytes).unwrap();
+        self.output.write(&path, bytes);
         path
     }
 
     fn generate_path(&self, ctx: Context) -> PathBuf {
-        let mut buf = PathBuf::new();
-        buf.push(self.out_dir);
-        buf.push(ctx.generate_filename());
-        buf
+        self.output
+            .rasterized_bytes_dir()
+            .join(ctx.generate_filename())
     }
 }
 
 /// Context object needed to uniquely name files in a reproducible way.
 #[derive(Debug, Copy, Clone)]
 pub struct Context {
-    pub c: char,
+    /// Feature name of the unicode range of the rasters, such as `unicode-basic-latin`.
+    pub unicode_range: &'static str,
     pub weight: FontWeight,
     pub height: u32,
+    /// The format of the rasters in the bytes.
+    pub format: RasterFormat,
+    /// Whether the bytes are the index of interned rasters instead of their rows,
+    /// see [`crate::intern`].
+    pub index: bool,
+}
+
+/// The format of the outsourced rasters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RasterFormat {
+    /// The raw intensities, row by row.
+    Raw,
+    /// The raw intensities of three subpixels per pixel, row by row.
+    Subpixel,
+    /// The run-length encoded intensities, see [`crate::rle`].
+    Compressed,
+}
+
+impl RasterFormat {
+    /// Returns the suffix of the file name.
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Raw => "",
+            Self::Subpixel => "_subpixel",
+            Self::Compressed => "_compressed",
+        }
+    }
 }
 
 impl Context {
     fn generate_filename(&self) -> String {
         format!(
-            "0x{:x}_h{}_w{:?}.txt",
-            self.c as u32,
+            "h{}_w{:?}_{}{}{}.bin",
             self.height,
-            self.weight.name()
+            self.weight.name(),
+            self.unicode_range,
+            self.format.suffix(),
+            if self.index { "_index" } else { "" }
         )
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::bytes_outsourcer::{BytesToFileOutsourcer, Context};
+    use crate::bytes_outsourcer::{BytesToFileOutsourcer, Context, RasterFormat};
     use crate::font::{FontWeight, FontWeightName};
+    use crate::output::Output;
 
     #[test]
     fn test_bytes_outsourcer() {
-        let mut outsourcer = BytesToFileOutsourcer::new("target");
+        let mut outsourcer = BytesToFileOutsourcer::new(Output::new("target").with_dry_run(true));
+        let path = outsourcer.outsource_bytes(
+            b"hello world",
+            Context {
+                unicode_range: "unicode-basic-latin",
+                weight: FontWeight::new(FontWeightName::Regular, false),
+                height: 30,
+                format: RasterFormat::Raw,
+                index: false,
+            },
+        );
+
+        assert_eq!(
+            "target/src/res_rasterized_characters/h30_wRegular_unicode-basic-latin.bin",
+            path.as_os_str().to_str().unwrap()
+        );
+
         let path = outsourcer.outsource_bytes(
             b"hello world",
             Context {
-                c: 'a',
+                unicode_range: "unicode-basic-latin",
                 weight: FontWeight::new(FontWeightName::Regular, false),
                 height: 30,
+                format: RasterFormat::Subpixel,
+                index: true,
             },
         );
+        assert_eq!(
+            "target/src/res_rasterized_characters/h30_wRegular_unicode-basic-latin_subpixel_index.bin",
+            path.as_os_str().to_str().unwrap()
+        );
 
-        // 0x61: ascii of 'a'
+        let path = outsourcer.outsource_bytes(
+            b"hello world",
+            Context {
+                unicode_range: "unicode-basic-latin",
+                weight: FontWeight::new(FontWeightName::Regular, false),
+                height: 30,
+                format: RasterFormat::Compressed,
+                index: false,
+            },
+        );
         assert_eq!(
-            "target/0x61_h30_wRegular.txt",
+            "target/src/res_rasterized_characters/h30_wRegular_unicode-basic-latin_compressed.bin",
             path.as_os_str().to_str().unwrap()
         );
     }
@@ -1,4 +1,8 @@
-use fontdue::{Font, FontSettings};
+use fontdue::{Font, FontSettings, Metrics};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
 
 const NOTO_SANS_MONO_REGULAR: &[u8] = include_bytes!("res/NotoSansMono-Regular.ttf");
 const NOTO_SANS_MONO_BOLD: &[u8] = include_bytes!("res/NotoSansMono-Bold.ttf");
@@ -9,6 +13,65 @@ const NOTO_SANS_MONO_LIGHT: &[u8] = include_bytes!("res/NotoSansMono-Light.ttf")
 /// if multiple characters are displayed side by side.
 const RASTERIZED_FONT_ADDITIONAL_PADDING: usize = 0;
 
+/// Factor by which [`Hinting::GridFit`] oversamples each glyph in each dimension.
+const HINTING_OVERSAMPLING: usize = 4;
+
+/// Minimum length in pixels of a horizontal stem that [`Hinting::GridFit`] snaps to the
+/// pixel grid. Shorter runs are the ends of vertical stems or of curves.
+const HINTING_MIN_STEM_LENGTH: usize = 2;
+
+/// How glyphs are fitted to the pixel grid during the rasterization.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Hinting {
+    /// The outlines are rasterized as they are. Stems that fall between pixels
+    /// look blurry.
+    #[default]
+    None,
+    /// A simple autohinter: The glyph is rasterized with oversampling and then
+    /// resampled to the raster with three adjustments:
+    /// * All glyphs are scaled vertically, so that the x-height is a whole number
+    ///   of pixels. The baseline is already on the pixel grid. This makes the top
+    ///   and bottom edges of lowercase letters crisp.
+    /// * Each glyph is moved horizontally by a fraction of a pixel, so that its
+    ///   vertical stems cover as many pixels fully as possible.
+    /// * Each horizontal stem, such as the bar of 'e' or the arms of 'E', is
+    ///   snapped to the pixel grid: Its thickness is rounded to whole pixels and
+    ///   its edges are moved to pixel boundaries. The glyph between the stems, the
+    ///   baseline, and the x-height is stretched accordingly.
+    GridFit,
+}
+
+impl Hinting {
+    /// All hinting options.
+    pub const ALL: &'static [Self] = &[Self::None, Self::GridFit];
+
+    /// Returns the name of the hinting option on the command line.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::GridFit => "grid-fit",
+        }
+    }
+}
+
+impl FromStr for Hinting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|hinting| hinting.name() == s)
+            .ok_or_else(|| format!("invalid hinting '{s}', expected 'none' or 'grid-fit'"))
+    }
+}
+
+impl Display for Hinting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// All available fonts. Must match the order in [`FontWeight`]!
 const NOTO_SANS_FAMILY: [&[u8]; 3] = [
     // must match order in enum FontWeightName
@@ -126,6 +189,159 @@ pub struct RasterizationInfo {
     /// The widest char/symbol after rasterization. Interesting as debug information which
     /// letter is the widest. Helps me to streamling the width for the centering of all letters.
     widest_char: char,
+    /// Positions of text decorations inside the raster.
+    decoration_metrics: DecorationMetrics,
+    /// How glyphs are fitted to the pixel grid.
+    hinting: Hinting,
+    /// Vertical scale that makes the x-height a whole number of pixels. Only used
+    /// with [`Hinting::GridFit`].
+    x_height_scale: f32,
+    /// Height of lowercase letters, such as 'x', in pixels.
+    x_height: f32,
+    /// Height of capital letters in pixels.
+    capital_height: f32,
+    /// Rasters that replace the rasterization of the font for some characters,
+    /// e.g., hand-tuned glyphs from a BDF font.
+    overrides: HashMap<char, Vec<Vec<u8>>>,
+}
+
+/// The adjustments of [`Hinting::GridFit`] for a glyph, in pixels of the raster.
+#[derive(Debug, Default)]
+struct GridFitting {
+    /// Horizontal shift of the glyph.
+    shift_x: f32,
+    /// Rows of the raster that show fixed rows of the glyph, sorted by row. Between two
+    /// of them, the rows of the glyph are interpolated linearly. Above the first and below
+    /// the last one, the glyph is shifted like at that row.
+    fixed_rows: Vec<FixedRow>,
+}
+
+/// A row of the raster that [`Hinting::GridFit`] maps to a fixed row of the glyph.
+#[derive(Debug, Copy, Clone)]
+struct FixedRow {
+    /// The row of the raster.
+    row: f32,
+    /// The row of the glyph, scaled by the x-height scale but not moved.
+    glyph_row: f32,
+    /// Whether the row is the baseline or the x-height. A horizontal stem close to
+    /// them is snapped on its own instead.
+    anchor: bool,
+}
+
+impl GridFitting {
+    /// Returns the row of the glyph that is shown at a row of the raster.
+    fn glyph_row(&self, y: f32) -> f32 {
+        let rows = &self.fixed_rows;
+        match rows.iter().position(|fixed| fixed.row > y) {
+            None => rows.last().map_or(y, |last| y - last.row + last.glyph_row),
+            Some(0) => y - rows[0].row + rows[0].glyph_row,
+            Some(i) => {
+                let (above, below) = (rows[i - 1], rows[i]);
+                ((y - above.row) / (below.row - above.row))
+                    .mul_add(below.glyph_row - above.glyph_row, above.glyph_row)
+            }
+        }
+    }
+
+    /// Snaps a horizontal stem with the rows `stem` of the glyph to the pixel grid. Its
+    /// thickness is rounded to whole pixels, but at least one pixel, and its edges are
+    /// moved to the closest pixel boundaries. The stem stays where it is if it would
+    /// change the order of the rows of the glyph.
+    fn snap(&mut self, stem: &Range<f32>) {
+        let thickness = (stem.end - stem.start).round().max(1.0);
+        let top = ((stem.start + stem.end - thickness) / 2.0).round();
+
+        let mut fixed_rows = self
+            .fixed_rows
+            .iter()
+            .copied()
+            .filter(|fixed| {
+                !fixed.anchor
+                    || fixed.glyph_row <= stem.start - 1.0
+                    || fixed.glyph_row >= stem.end + 1.0
+            })
+            .chain([
+                FixedRow {
+                    row: top,
+                    glyph_row: stem.start,
+                    anchor: false,
+                },
+                FixedRow {
+                    row: top + thickness,
+                    glyph_row: stem.end,
+                    anchor: false,
+                },
+            ])
+            .collect::<Vec<_>>();
+        fixed_rows.sort_by(|a, b| a.row.total_cmp(&b.row));
+
+        let keeps_order = fixed_rows
+            .windows(2)
+            .all(|pair| pair[0].row < pair[1].row && pair[0].glyph_row < pair[1].glyph_row);
+        if keeps_order {
+            self.fixed_rows = fixed_rows;
+        }
+    }
+}
+
+/// Vertical positions and thicknesses of text decorations inside the raster of a
+/// character. All values are in pixels, positions refer to the first row of the
+/// line.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecorationMetrics {
+    /// Computed from the `post` table.
+    pub underline_position: usize,
+    /// Computed from the `post` table.
+    pub underline_thickness: usize,
+    /// Computed from the `OS/2` table.
+    pub strikeout_position: usize,
+    /// Computed from the `OS/2` table.
+    pub strikeout_thickness: usize,
+    /// The font has no metrics for this. The line is placed right above the
+    /// capital letters and has the thickness of the underline.
+    pub overline_position: usize,
+}
+
+impl DecorationMetrics {
+    /// Computes the metrics from the font tables.
+    ///
+    /// # Parameters
+    /// * `font_face` The parsed font.
+    /// * `font_size` The font size, which is also the row of the baseline in the raster.
+    /// * `raster_height` The height of the raster. All lines are kept inside.
+    fn new(font_face: &ttf_parser::Face, font_size: f32, raster_height: usize) -> Self {
+        let scale = font_size / font_face.units_per_em() as f32;
+        let baseline = font_size;
+
+        // Returns the first row and the thickness of a line whose top edge is at `position`
+        // font units above the baseline.
+        let line = |metrics: ttf_parser::LineMetrics| {
+            let thickness = (metrics.thickness as f32 * scale).round().max(1.0);
+            // center of the line in font units relative to the baseline
+            let center = metrics.position as f32 - metrics.thickness as f32 / 2.0;
+            let center = center.mul_add(-scale, baseline);
+            let position = (center - thickness / 2.0).round().max(0.0) as usize;
+            let thickness = thickness as usize;
+            (position.min(raster_height - thickness), thickness)
+        };
+
+        let (underline_position, underline_thickness) =
+            line(font_face.underline_metrics().unwrap());
+        let (strikeout_position, strikeout_thickness) =
+            line(font_face.strikeout_metrics().unwrap());
+
+        let capital_height = font_face.capital_height().unwrap() as f32 * scale;
+        let overline_position =
+            ((baseline - capital_height).floor() as usize).saturating_sub(underline_thickness);
+
+        Self {
+            underline_position,
+            underline_thickness,
+            strikeout_position,
+            strikeout_thickness,
+            overline_position,
+        }
+    }
 }
 
 impl RasterizationInfo {
@@ -137,12 +353,12 @@ impl RasterizationInfo {
     /// * `font_bytes` Raw bytes of a font file that [`fontdue`] can parse.
     pub fn new(raster_height: usize, font_bytes: &[u8]) -> Self {
         let line_height = raster_height as f32;
+        let font_face = ttf_parser::Face::parse(font_bytes, 0).unwrap();
 
         // Work out the ratio between the raster_height (e.g. 16) and the line height
         // with ascenders and descenders (e.g. 23).
         let font_size_to_raster_height_ratio = line_height / {
             // Get font metrics.
-            let font_face = ttf_parser::Face::parse(font_bytes, 0).unwrap();
             let ascender = font_face.ascender() as i32;
             let descender = font_face.descender() as i32;
             let line_gap = font_face.line_gap() as i32;
@@ -166,13 +382,45 @@ impl RasterizationInfo {
 
         let (widest_char, raster_width) = Self::find_max_width(&font, font_size);
 
+        // The vertical alignment in `rasterize` places the baseline at the row `font_size`.
+        let decoration_metrics = DecorationMetrics::new(&font_face, font_size, raster_height);
+
+        let x_height =
+            font_face.x_height().unwrap() as f32 * font_size / font_face.units_per_em() as f32;
+        let x_height_scale = x_height.round() / x_height;
+        let capital_height = font_face.capital_height().unwrap() as f32 * font_size
+            / font_face.units_per_em() as f32;
+
         Self {
             font,
             raster_height,
             raster_width,
             font_size,
             widest_char,
+            decoration_metrics,
+            hinting: Hinting::None,
+            x_height_scale,
+            x_height,
+            capital_height,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets how [`Self::rasterize`] fits glyphs to the pixel grid.
+    pub const fn with_hinting(mut self, hinting: Hinting) -> Self {
+        self.hinting = hinting;
+        self
+    }
+
+    /// Replaces the rasterization of the font for the given characters with the given
+    /// rasters. Each raster must have the dimensions of this font.
+    pub fn with_overrides(mut self, overrides: HashMap<char, Vec<Vec<u8>>>) -> Self {
+        for raster in overrides.values() {
+            assert_eq!(raster.len(), self.raster_height);
+            assert!(raster.iter().all(|row| row.len() == self.raster_width));
         }
+        self.overrides = overrides;
+        self
     }
 
     /// Rasterizes a char for the given [`Self`] object into a raster/box. Every letter in the
@@ -180,12 +428,200 @@ impl RasterizationInfo {
     /// the resulting mono font contains already a vertical line spacing of a few pixels, but
     /// almost no padding to the left and right. This way, letters can be displayed side by side
     /// and appear as mono-space font.
+    ///
+    /// The glyph is fitted to the pixel grid according to the configured [`Hinting`].
+    /// Characters with a raster from [`Self::with_overrides`] aren't rasterized.
     pub fn rasterize(&self, c: char) -> Vec<Vec<u8>> {
+        if let Some(raster) = self.overrides.get(&c) {
+            return raster.clone();
+        }
+
+        match self.hinting {
+            Hinting::None => {
+                let (metrics, fontdue_bitmap) = self.font.rasterize(c, self.font_size);
+                self.align(&metrics, &fontdue_bitmap, 1)
+            }
+            Hinting::GridFit => self.rasterize_grid_fitted(c, 1),
+        }
+    }
+
+    /// Rasterizes a char with [`Hinting::GridFit`] with `channels` horizontal subpixels per
+    /// pixel. Of all horizontal shifts in steps of an oversampled pixel, the one with the
+    /// sharpest raster wins. Then, the horizontal stems are snapped to the pixel grid.
+    fn rasterize_grid_fitted(&self, c: char, channels: usize) -> Vec<Vec<u8>> {
+        let oversampling = HINTING_OVERSAMPLING as f32;
+        let (metrics, fontdue_bitmap) = self.font.rasterize(c, self.font_size * oversampling);
+
+        // The shifts are in the range [-0.5, 0.5). Smaller shifts win ties.
+        let mut shifts = (0..HINTING_OVERSAMPLING)
+            .map(|i| i as f32 / oversampling - 0.5)
+            .collect::<Vec<_>>();
+        shifts.sort_by(|a, b| a.abs().total_cmp(&b.abs()));
+
+        let shift_x = shifts
+            .into_iter()
+            .min_by_key(|&shift_x| {
+                let fitting = GridFitting {
+                    shift_x,
+                    ..GridFitting::default()
+                };
+                Self::blurriness(&self.resample(&metrics, &fontdue_bitmap, &fitting, 1))
+            })
+            .unwrap();
+
+        // the baseline and the x-height are already on the pixel grid
+        let anchor = |row| FixedRow {
+            row,
+            glyph_row: row,
+            anchor: true,
+        };
+        let mut fitting = GridFitting {
+            shift_x,
+            fixed_rows: vec![
+                anchor(self.font_size - self.x_height.round()),
+                anchor(self.font_size),
+            ],
+        };
+        for stem in self.horizontal_stems(&metrics, &fontdue_bitmap) {
+            fitting.snap(&stem);
+        }
+
+        self.resample(&metrics, &fontdue_bitmap, &fitting, channels)
+    }
+
+    /// Returns the horizontal stems of an oversampled bitmap from [`fontdue`], such as the
+    /// bar of 'e', as ranges of rows of the glyph. A stem is a band of bitmap rows that
+    /// each have a run of covered samples of at least [`HINTING_MIN_STEM_LENGTH`] pixels.
+    /// Bands thicker than a quarter of the raster height are no stems but filled areas.
+    fn horizontal_stems(&self, metrics: &Metrics, fontdue_bitmap: &[u8]) -> Vec<Range<f32>> {
+        let oversampling = HINTING_OVERSAMPLING as f32;
+        let min_run = HINTING_MIN_STEM_LENGTH * HINTING_OVERSAMPLING;
+        let max_thickness = self.raster_height * HINTING_OVERSAMPLING / 4;
+
+        let is_stem_row = |row: &[u8]| {
+            let mut run = 0;
+            row.iter().any(|&intensity| {
+                run = if intensity >= 128 { run + 1 } else { 0 };
+                run >= min_run
+            })
+        };
+        // row of the glyph at the top edge of a bitmap row
+        let glyph_row = |bitmap_row: usize| {
+            let y = (metrics.height - bitmap_row) as f32 + metrics.ymin as f32;
+            (y / oversampling).mul_add(-self.x_height_scale, self.font_size)
+        };
+
+        let mut stems = Vec::new();
+        let mut start = None;
+        for bitmap_row in 0..=metrics.height {
+            let is_stem = bitmap_row < metrics.height
+                && is_stem_row(
+                    &fontdue_bitmap[bitmap_row * metrics.width..(bitmap_row + 1) * metrics.width],
+                );
+            match (start, is_stem) {
+                (None, true) => start = Some(bitmap_row),
+                (Some(first_row), false) => {
+                    if bitmap_row - first_row <= max_thickness {
+                        stems.push(glyph_row(first_row)..glyph_row(bitmap_row));
+                    }
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        stems
+    }
+
+    /// Resamples an oversampled bitmap from [`fontdue`] into the raster with `channels`
+    /// horizontal subpixels per pixel. The glyph is centered horizontally, scaled
+    /// vertically around the baseline by the x-height scale, and adjusted by the
+    /// `fitting`.
+    fn resample(
+        &self,
+        metrics: &Metrics,
+        fontdue_bitmap: &[u8],
+        fitting: &GridFitting,
+        channels: usize,
+    ) -> Vec<Vec<u8>> {
+        let oversampling = HINTING_OVERSAMPLING as f32;
+        // position of the glyph origin in the raster
+        let origin_x = (self.raster_width as f32 - metrics.advance_width / oversampling) / 2.0;
+        let baseline = self.font_size;
+
+        // samples the oversampled bitmap at a point of the raster
+        let sample = |x: f32, y: f32| {
+            // point in the coordinate system of the oversampled bitmap, y grows upwards
+            let x = (x - origin_x - fitting.shift_x).mul_add(oversampling, -(metrics.xmin as f32));
+            let y = ((baseline - fitting.glyph_row(y)) / self.x_height_scale)
+                .mul_add(oversampling, -(metrics.ymin as f32));
+            if x < 0.0 || y < 0.0 || x >= metrics.width as f32 || y >= metrics.height as f32 {
+                0
+            } else {
+                let row = metrics.height - 1 - y as usize;
+                fontdue_bitmap[row * metrics.width + x as usize] as u32
+            }
+        };
+
+        let mut letter_bitmap = vec![vec![0_u8; self.raster_width * channels]; self.raster_height];
+        for (y, row) in letter_bitmap.iter_mut().enumerate() {
+            for (x, intensity) in row.iter_mut().enumerate() {
+                // average of a grid of samples in the (sub)pixel
+                let mut sum = 0;
+                for sample_y in 0..HINTING_OVERSAMPLING {
+                    for sample_x in 0..HINTING_OVERSAMPLING {
+                        sum += sample(
+                            (x as f32 + (sample_x as f32 + 0.5) / oversampling) / channels as f32,
+                            y as f32 + (sample_y as f32 + 0.5) / oversampling,
+                        );
+                    }
+                }
+                *intensity = (sum as f32 / (oversampling * oversampling)).round() as u8;
+            }
+        }
+        letter_bitmap
+    }
+
+    /// Returns how blurry a raster is, i.e., how much of its intensity is neither fully
+    /// on nor fully off. Lower values are crisper.
+    pub fn blurriness(raster: &[Vec<u8>]) -> u64 {
+        raster
+            .iter()
+            .flatten()
+            .map(|&i| i as u64 * (255 - i as u64))
+            .sum()
+    }
+
+    /// Like [`Self::rasterize`] but rasterizes each pixel as three horizontal subpixels, as
+    /// used by LCD subpixel rendering. Each row has `3 * raster_width` bytes. Each byte is
+    /// the unfiltered coverage of a subpixel, from left to right.
+    ///
+    /// The glyph is fitted to the pixel grid like in [`Self::rasterize`]. Characters with
+    /// a raster from [`Self::with_overrides`] aren't rasterized. Each of their pixels is
+    /// copied into its three subpixels.
+    pub fn rasterize_subpixel(&self, c: char) -> Vec<Vec<u8>> {
+        if let Some(raster) = self.overrides.get(&c) {
+            return raster
+                .iter()
+                .map(|row| row.iter().flat_map(|&i| [i; 3]).collect())
+                .collect();
+        }
+
+        match self.hinting {
+            Hinting::None => {
+                let (metrics, fontdue_bitmap) = self.font.rasterize_subpixel(c, self.font_size);
+                self.align(&metrics, &fontdue_bitmap, 3)
+            }
+            Hinting::GridFit => self.rasterize_grid_fitted(c, 3),
+        }
+    }
+
+    /// Places a bitmap from [`fontdue`] with `channels` bytes per pixel in the center of
+    /// the raster.
+    fn align(&self, metrics: &Metrics, fontdue_bitmap: &[u8], channels: usize) -> Vec<Vec<u8>> {
         let font_size = self.font_size;
-        let (metrics, fontdue_bitmap) = self.font.rasterize(c, font_size);
 
         // the bitmap that will contain the properly aligned rasterized char
-        let mut letter_bitmap = vec![vec![0_u8; self.raster_width]; self.raster_height];
+        let mut letter_bitmap = vec![vec![0_u8; self.raster_width * channels]; self.raster_height];
 
         // align to horizontal center
         let x_offset = (metrics.xmin as f32
@@ -195,18 +631,22 @@ impl RasterizationInfo {
         // align to vertical center
         let y_offset = ((font_size - metrics.height as f32) - metrics.ymin as f32).round() as isize;
 
+        let bitmap_width = (metrics.width * channels) as isize;
+        let raster_width = self.raster_width * channels;
+        let x_offset = x_offset * channels as isize;
+
         for ((y, x), intensity, skip) in fontdue_bitmap
             .iter()
             .enumerate()
             .map(|(i, p)| (i as isize, p))
             .map(|(i, p)| {
-                let x = x_offset + (i % metrics.width as isize);
-                let y = y_offset + (i / metrics.width as isize);
+                let x = x_offset + (i % bitmap_width);
+                let y = y_offset + (i / bitmap_width);
 
-                let skip = x >= self.raster_width as isize || y >= self.raster_height as isize;
+                let skip = x >= raster_width as isize || y >= self.raster_height as isize;
 
                 // if some letter is "too" big and out of bounds the box: cut and prevent error
-                let x = trim_index_to_bounds!(x, self.raster_width());
+                let x = trim_index_to_bounds!(x, raster_width);
                 let y = trim_index_to_bounds!(y, self.raster_height());
 
                 ((y, x), *p, skip)
@@ -251,9 +691,30 @@ impl RasterizationInfo {
         self.font_size
     }
 
+    /// Row of the baseline in the raster, i.e., the number of rows above the baseline.
+    pub const fn baseline(&self) -> usize {
+        self.font_size as usize
+    }
+
+    pub const fn x_height(&self) -> f32 {
+        self.x_height
+    }
+
+    pub const fn capital_height(&self) -> f32 {
+        self.capital_height
+    }
+
     pub const fn widest_char(&self) -> char {
         self.widest_char
     }
+
+    pub const fn decoration_metrics(&self) -> DecorationMetrics {
+        self.decoration_metrics
+    }
+
+    pub const fn hinting(&self) -> Hinting {
+        self.hinting
+    }
 }
 
 #[cfg(test)]
@@ -268,5 +729,108 @@ mod tests {
         println!("font_size     = {}", props.font_size());
         println!("raster_width  = {}", props.raster_width());
         println!("widest_char   = '{}'", props.widest_char());
+        println!("decorations   = {:?}", props.decoration_metrics());
+    }
+
+    #[test]
+    fn test_rasterize_subpixel() {
+        for &hinting in Hinting::ALL {
+            let props = RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR).with_hinting(hinting);
+            for c in ['A', 'x', 'e'] {
+                let raster = props.rasterize(c);
+                let subpixel_raster = props.rasterize_subpixel(c);
+                assert_eq!(subpixel_raster.len(), props.raster_height());
+
+                for (row, subpixel_row) in raster.iter().zip(subpixel_raster.iter()) {
+                    assert_eq!(subpixel_row.len(), 3 * props.raster_width());
+                    // the glyph covers roughly the same pixels in both rasters
+                    for (intensity, subpixels) in row.iter().zip(subpixel_row.chunks(3)) {
+                        let average = subpixels.iter().map(|&x| x as i32).sum::<i32>() / 3;
+                        assert!((*intensity as i32 - average).abs() < 128);
+                    }
+                }
+
+                // the glyph has the same x-height in both rasters
+                let top = |raster: &[Vec<u8>]| {
+                    raster
+                        .iter()
+                        .position(|row| row.iter().any(|&i| i >= 128))
+                        .unwrap()
+                };
+                assert_eq!(top(&raster), top(&subpixel_raster), "'{c}' {hinting}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_fitting() {
+        let props = RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR);
+        let hinted =
+            RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR).with_hinting(Hinting::GridFit);
+
+        let (mut blurriness, mut blurriness_hinted) = (0, 0);
+        for c in ['m', 'w', 'l', 'n', 'x'] {
+            let raster = props.rasterize(c);
+            let raster_hinted = hinted.rasterize(c);
+            assert_eq!(raster_hinted.len(), props.raster_height());
+            assert_eq!(raster_hinted[0].len(), props.raster_width());
+
+            let sum = |raster: &[Vec<u8>]| raster.iter().flatten().map(|&i| i as u64).sum::<u64>();
+            // the glyph keeps roughly its ink
+            assert!(sum(&raster_hinted).abs_diff(sum(&raster)) < sum(&raster) / 5);
+
+            blurriness += RasterizationInfo::blurriness(&raster);
+            blurriness_hinted += RasterizationInfo::blurriness(&raster_hinted);
+        }
+        println!("blurriness: {blurriness} unhinted, {blurriness_hinted} hinted");
+        assert!(blurriness_hinted < blurriness);
+    }
+
+    #[test]
+    fn test_grid_fitting_horizontal_stems() {
+        let props = RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR);
+        let hinted =
+            RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR).with_hinting(Hinting::GridFit);
+
+        // the middle column crosses only horizontal stems
+        let middle_column = |raster: Vec<Vec<u8>>| {
+            raster
+                .iter()
+                .map(|row| row[props.raster_width() / 2])
+                .collect::<Vec<_>>()
+        };
+        let is_blurry = |i: &u8| (64..192).contains(i);
+        for c in ['H', '=', '\u{2550}'] {
+            assert!(middle_column(props.rasterize(c)).iter().any(is_blurry));
+            assert!(!middle_column(hinted.rasterize(c)).iter().any(is_blurry));
+        }
+    }
+
+    #[test]
+    fn test_hinting_from_str() {
+        for &hinting in Hinting::ALL {
+            assert_eq!(hinting.to_string().parse::<Hinting>(), Ok(hinting));
+        }
+        assert!("gridfit".parse::<Hinting>().is_err());
+    }
+
+    #[test]
+    fn test_decoration_metrics() {
+        for weight in SUPPORTED_FONT_WEIGHTS {
+            for height in crate::SUPPORTED_RASTER_HEIGHTS {
+                let props =
+                    RasterizationInfo::new(height.value() as usize, noto_font_by_weight(weight));
+                let metrics = props.decoration_metrics();
+                let baseline = props.font_size() as usize;
+
+                assert!(metrics.underline_position >= baseline);
+                assert!(
+                    metrics.underline_position + metrics.underline_thickness
+                        <= props.raster_height()
+                );
+                assert!(metrics.strikeout_position < baseline);
+                assert!(metrics.overline_position < metrics.strikeout_position);
+            }
+        }
     }
 }
@@ -0,0 +1,154 @@
+//! A minimal in-memory image to render rasterized characters headless, e.g., for
+//! specimen sheets, and to write it as PGM/PPM or PNG file.
+
+use crate::font::RasterizationInfo;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// An image with one (grayscale) or three (RGB) bytes per pixel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    channels: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Creates a grayscale image filled with `background`.
+    pub fn gray(width: usize, height: usize, background: u8) -> Self {
+        Self {
+            width,
+            height,
+            channels: 1,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Creates an RGB image filled with `background`.
+    pub fn rgb(width: usize, height: usize, background: [u8; 3]) -> Self {
+        Self {
+            width,
+            height,
+            channels: 3,
+            pixels: background.repeat(width * height),
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the bytes of the pixel at `(x, y)`.
+    pub fn pixel(&self, x: usize, y: usize) -> &[u8] {
+        let i = (y * self.width + x) * self.channels;
+        &self.pixels[i..i + self.channels]
+    }
+
+    /// Sets the pixel at `(x, y)` to `color`, which has one byte per channel. For
+    /// grayscale images, only the first byte is used. Pixels outside the image are
+    /// ignored.
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: &[u8]) {
+        if x < self.width && y < self.height {
+            let i = (y * self.width + x) * self.channels;
+            self.pixels[i..i + self.channels].copy_from_slice(&color[..self.channels]);
+        }
+    }
+
+    /// Draws a raster as dark glyph on a bright background, i.e., each intensity `i`
+    /// becomes the gray value `255 - i`. Each raster pixel becomes a square of `zoom`
+    /// x `zoom` pixels.
+    pub fn draw_raster(&mut self, x: usize, y: usize, raster: &[Vec<u8>], zoom: usize) {
+        for (row_i, row) in raster.iter().enumerate() {
+            for (col_i, intensity) in row.iter().enumerate() {
+                let gray = 255 - intensity;
+                for zoom_y in 0..zoom {
+                    for zoom_x in 0..zoom {
+                        self.set_pixel(
+                            x + col_i * zoom + zoom_x,
+                            y + row_i * zoom + zoom_y,
+                            &[gray; 3],
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws `text` in a single line with `font`. Returns the width of the text.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str, font: &RasterizationInfo) -> usize {
+        for (i, c) in text.chars().enumerate() {
+            self.draw_raster(x + i * font.raster_width(), y, &font.rasterize(c), 1);
+        }
+        text.chars().count() * font.raster_width()
+    }
+
+    /// Encodes the image as binary PGM (grayscale) or PPM (RGB) file.
+    pub fn encode_pnm(&self) -> Vec<u8> {
+        let magic = if self.channels == 1 { "P5" } else { "P6" };
+        let mut bytes = format!("{magic}\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend_from_slice(&self.pixels);
+        bytes
+    }
+
+    /// Writes the image as binary PGM (grayscale) or PPM (RGB) file.
+    pub fn write_pnm(&self, path: &Path) {
+        std::fs::write(path, self.encode_pnm()).unwrap();
+    }
+
+    /// Writes the image as PNG file.
+    pub fn write_png(&self, path: &Path) {
+        let file = BufWriter::new(File::create(path).unwrap());
+        self.encode_png(file);
+    }
+
+    fn encode_png(&self, writer: impl Write) {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(if self.channels == 1 {
+            png::ColorType::Grayscale
+        } else {
+            png::ColorType::Rgb
+        });
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_compression(png::Compression::High);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&self.pixels).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_pnm() {
+        let mut image = Image::gray(2, 1, 255);
+        image.set_pixel(1, 0, &[7]);
+        image.set_pixel(2, 0, &[7]);
+        assert_eq!(image.encode_pnm(), b"P5\n2 1\n255\n\xff\x07");
+
+        let image = Image::rgb(1, 1, [1, 2, 3]);
+        assert_eq!(image.encode_pnm(), b"P6\n1 1\n255\n\x01\x02\x03");
+    }
+
+    #[test]
+    fn test_encode_png() {
+        let mut image = Image::rgb(3, 2, [255; 3]);
+        image.draw_raster(0, 0, &[vec![255]], 2);
+        let mut bytes = Vec::new();
+        image.encode_png(&mut bytes);
+
+        let decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, image.pixels);
+        assert_eq!(image.pixel(1, 1), [0, 0, 0]);
+        assert_eq!(image.pixel(2, 1), [255, 255, 255]);
+    }
+}
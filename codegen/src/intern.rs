@@ -0,0 +1,217 @@
+//! Interning of identical rows and identical rasters of the generated glyph data.
+//!
+//! Many characters share rows, such as the all-zero rows above and below each glyph
+//! or the stems of `l`, `I`, and `|`. The codegen stores each distinct row only once,
+//! so that the row slices of the generated statics point into shared bytes. The price
+//! is an index with an `u16` per row of each distinct raster and per raster.
+//!
+//! Identical glyphs across code points would be stored only once as well, but the
+//! rasters of Noto Sans Mono currently have no duplicates, so this saves nothing.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::AddAssign;
+
+/// Rasters, whose identical rows and identical rasters are stored only once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternedRasters {
+    /// The distinct rows in the order of their first occurrence.
+    pub rows: Vec<Vec<u8>>,
+    /// The indices into [`Self::rows`] of the rows of each distinct raster.
+    pub rasters: Vec<Vec<usize>>,
+    /// The index into [`Self::rasters`] of each raster that was interned.
+    pub raster_indices: Vec<usize>,
+}
+
+impl InternedRasters {
+    /// Interns the rows and the rasters of `rasters`.
+    pub fn new(rasters: &[Vec<Vec<u8>>]) -> Self {
+        let mut rows = Vec::new();
+        let mut row_map = HashMap::new();
+        let mut distinct_rasters = Vec::new();
+        let mut raster_map = HashMap::new();
+        let raster_indices = rasters
+            .iter()
+            .map(|raster| {
+                let raster = raster
+                    .iter()
+                    .map(|row| {
+                        *row_map.entry(row.clone()).or_insert_with(|| {
+                            rows.push(row.clone());
+                            rows.len() - 1
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                *raster_map.entry(raster.clone()).or_insert_with(|| {
+                    distinct_rasters.push(raster);
+                    distinct_rasters.len() - 1
+                })
+            })
+            .collect();
+        Self {
+            rows,
+            rasters: distinct_rasters,
+            raster_indices,
+        }
+    }
+
+    /// Returns the distinct rows, one after another.
+    pub fn rows_bytes(&self) -> Vec<u8> {
+        self.rows.concat()
+    }
+
+    /// Returns the index as little-endian `u16` values: first the row indices of all
+    /// distinct rasters, then the raster index of each interned raster.
+    ///
+    /// # Panics
+    /// Panics if an index doesn't fit into an `u16`.
+    pub fn index_bytes(&self) -> Vec<u8> {
+        self.rasters
+            .iter()
+            .flatten()
+            .chain(&self.raster_indices)
+            .flat_map(|&i| u16::try_from(i).unwrap().to_le_bytes())
+            .collect()
+    }
+
+    /// Restores the rasters from [`Self::rows_bytes`] and [`Self::index_bytes`] of
+    /// `count` rasters with `height` rows each.
+    ///
+    /// # Panics
+    /// Panics if the bytes are inconsistent.
+    pub fn rasters_from_bytes(
+        rows: &[u8],
+        index: &[u8],
+        count: usize,
+        height: usize,
+    ) -> Vec<Vec<Vec<u8>>> {
+        let index = index
+            .chunks_exact(2)
+            .map(|i| usize::from(u16::from_le_bytes([i[0], i[1]])))
+            .collect::<Vec<_>>();
+        let (row_indices, raster_indices) = index.split_at(index.len() - count);
+        let row_count = row_indices.iter().max().map_or(0, |max| max + 1);
+        let width = rows.len().checked_div(row_count).unwrap_or(0);
+        assert_eq!(rows.len(), row_count * width);
+        raster_indices
+            .iter()
+            .map(|&raster| {
+                row_indices[raster * height..][..height]
+                    .iter()
+                    .map(|&row| rows[row * width..][..width].to_vec())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the statistics of the interning.
+    pub fn stats(&self) -> InternStats {
+        let width = self.rows.first().map_or(0, Vec::len);
+        let height = self.rasters.first().map_or(0, Vec::len);
+        InternStats {
+            rasters: self.raster_indices.len(),
+            distinct_rasters: self.rasters.len(),
+            raw_bytes: self.raster_indices.len() * height * width,
+            interned_bytes: self.rows.len() * width,
+            index_bytes: self.index_bytes().len(),
+        }
+    }
+}
+
+/// Statistics of [`InternedRasters`], which add up over multiple unicode ranges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// Number of interned rasters.
+    pub rasters: usize,
+    /// Number of distinct rasters.
+    pub distinct_rasters: usize,
+    /// Bytes of the rows of all rasters without interning.
+    pub raw_bytes: usize,
+    /// Bytes of the distinct rows.
+    pub interned_bytes: usize,
+    /// Bytes of the index, see [`InternedRasters::index_bytes`].
+    pub index_bytes: usize,
+}
+
+impl AddAssign for InternStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.rasters += rhs.rasters;
+        self.distinct_rasters += rhs.distinct_rasters;
+        self.raw_bytes += rhs.raw_bytes;
+        self.interned_bytes += rhs.interned_bytes;
+        self.index_bytes += rhs.index_bytes;
+    }
+}
+
+impl Display for InternStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // the index may outweigh the savings of the rows
+        let saved = self.raw_bytes as isize - (self.interned_bytes + self.index_bytes) as isize;
+        write!(
+            f,
+            "{} of {} bytes saved ({:.0}%) with {} bytes of rows and {} bytes of index, ",
+            saved,
+            self.raw_bytes,
+            100.0 * saved as f64 / self.raw_bytes.max(1) as f64,
+            self.interned_bytes,
+            self.index_bytes,
+        )?;
+        match self.rasters - self.distinct_rasters {
+            0 => write!(f, "no duplicate rasters"),
+            duplicates => write!(f, "{duplicates} of {} rasters are duplicates", self.rasters),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let rasters = vec![
+            vec![vec![0, 0], vec![1, 2], vec![0, 0]],
+            vec![vec![0, 0], vec![3, 4], vec![1, 2]],
+            vec![vec![0, 0], vec![1, 2], vec![0, 0]],
+        ];
+        let interned = InternedRasters::new(&rasters);
+        assert_eq!(interned.rows, [vec![0, 0], vec![1, 2], vec![3, 4]]);
+        assert_eq!(interned.rasters, [vec![0, 1, 0], vec![0, 2, 1]]);
+        assert_eq!(interned.raster_indices, [0, 1, 0]);
+
+        assert_eq!(interned.rows_bytes(), [0, 0, 1, 2, 3, 4]);
+        assert_eq!(
+            interned.index_bytes(),
+            [0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 1, 0, 0, 0, 1, 0, 0, 0]
+        );
+        assert_eq!(
+            InternedRasters::rasters_from_bytes(
+                &interned.rows_bytes(),
+                &interned.index_bytes(),
+                3,
+                3
+            ),
+            rasters
+        );
+
+        let stats = interned.stats();
+        assert_eq!(
+            stats,
+            InternStats {
+                rasters: 3,
+                distinct_rasters: 2,
+                raw_bytes: 18,
+                interned_bytes: 6,
+                index_bytes: 18,
+            }
+        );
+        assert_eq!(
+            stats.to_string(),
+            "-6 of 18 bytes saved (-33%) with 6 bytes of rows and 18 bytes of index, \
+             1 of 3 rasters are duplicates"
+        );
+
+        let distinct = InternedRasters::new(&rasters[..2]).stats();
+        assert!(distinct.to_string().ends_with(", no duplicate rasters"));
+    }
+}
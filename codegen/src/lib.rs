@@ -14,8 +14,18 @@
 
 pub use crate::unicode::SUPPORTED_UNICODE_RANGES;
 
+use crate::font::Hinting;
+
+pub mod bdf;
+pub mod blob;
 pub mod bytes_outsourcer;
 pub mod font;
+pub mod image;
+pub mod intern;
+pub mod monochrome;
+pub mod output;
+pub mod psf2;
+pub mod rle;
 pub mod unicode;
 
 // All supported raster heights that will be generated.
@@ -29,6 +39,10 @@ pub const SUPPORTED_RASTER_HEIGHTS: &[RasterHeight] = &[
     // such big pre-rasterized letters.
 ];
 
+/// How the codegen fits the glyphs to the pixel grid, unless the `--hinting` option is
+/// given. Use the `rasterize_chars_in_window` binary to compare the options.
+pub const HINTING: Hinting = Hinting::None;
+
 /// Teight of the rasterization process of certain characters. Like the font
 /// size but this describes the size of the outer box. Hence, the font size
 /// is a little smaller.
@@ -71,11 +85,13 @@ pub const WEIGHT_MOD_TEMPLATE: &str = include_str!("codegen_templates/weight_mod
 pub const CARGO_TOML_TEMPLATE: &str = include_str!("codegen_templates/Cargo.toml.txt");
 /// Used as template to generate the lib.rs.
 pub const CARGO_LIB_RS: &str = include_str!("codegen_templates/lib.rs.template.txt");
+/// Used as template to generate the tests.rs, which checks the invariants of all
+/// generated rasters.
+pub const TESTS_RS_TEMPLATE: &str = include_str!("codegen_templates/tests.rs.template.txt");
 
-/// Path into the main repository, where the codegen manipulates files.
-pub const CODEGEN_BASE_PATH: &str = "../src/";
-/// Contains the rasterized bytes of all characters.
-pub const CODEGEN_RASTERIZED_BYTES_PATH: &str = "../src/res_rasterized_characters";
+/// The root directory of the main repository, which is the default output of the
+/// codegen.
+pub const DEFAULT_OUTPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
 
 /*#[cfg(test)]
 mod tests {
@@ -10,54 +10,319 @@
 #![deny(missing_debug_implementations)]
 #![deny(rustdoc::all)]
 
-use codegen::bytes_outsourcer::{BytesToFileOutsourcer, Context};
-use codegen::font::{FontWeight, RasterizationInfo, SUPPORTED_FONT_WEIGHTS, noto_font_by_weight};
+use codegen::bdf::{BdfFont, bdf_from_font};
+use codegen::blob::blob_from_fonts;
+use codegen::bytes_outsourcer::{BytesToFileOutsourcer, Context, RasterFormat};
+use codegen::font::{
+    FontWeight, Hinting, RasterizationInfo, SUPPORTED_FONT_WEIGHTS, noto_font_by_weight,
+};
+use codegen::intern::{InternStats, InternedRasters};
+use codegen::monochrome::Monochrome;
+use codegen::output::{Output, Step};
+use codegen::psf2::psf2_from_font;
+use codegen::rle::encode_rle;
 use codegen::{
-    CARGO_LIB_RS, CARGO_TOML_TEMPLATE, CODEGEN_BASE_PATH, CODEGEN_RASTERIZED_BYTES_PATH,
-    SIZE_MOD_TEMPLATE, SUPPORTED_RASTER_HEIGHTS, SUPPORTED_UNICODE_RANGES, WEIGHT_MOD_TEMPLATE,
+    CARGO_LIB_RS, CARGO_TOML_TEMPLATE, DEFAULT_OUTPUT_DIR, HINTING, SIZE_MOD_TEMPLATE,
+    SUPPORTED_RASTER_HEIGHTS, SUPPORTED_UNICODE_RANGES, TESTS_RS_TEMPLATE, WEIGHT_MOD_TEMPLATE,
 };
+use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
-use std::fs::{File, create_dir};
-use std::io::Write as IoWrite;
-use std::path::PathBuf;
+use std::path::Path;
+
+/// Usage of the subcommands of the binary.
+const USAGE: &str = "\
+Usage:
+    codegen [options]                         Generates the library.
+    codegen psf2 <weight> <size> <file> [mode] [--hinting <hinting>]
+                                              Exports a PSF2 console font. <mode> is
+                                              'threshold=<0-255>' (default: 128) or
+                                              'dither'.
+    codegen bdf <weight> <size> <file> [mode] [--hinting <hinting>]
+                                              Exports a BDF font. <mode> as above.
+    codegen bdf-import <weight> <size> <file> [options]
+                                              Replaces the glyphs of the font weight
+                                              and size with the glyphs of a BDF font
+                                              for a custom build.
+    codegen blob <file> [<weight>:<size>...] [--hinting <hinting>]
+                                              Exports a font blob for FontBlob::parse()
+                                              with the given fonts (default: all).
+
+Options:
+    --out-dir <dir>                           Root directory of the generated crate
+                                              (default: this repository). The code
+                                              goes into <dir>/src, together with a
+                                              copy of the hand-written modules.
+    --dry-run                                 Only prints the files that would be
+                                              written.
+    --steps <step>[,<step>...]                The steps of the library generation:
+                                              'glyphs', 'lib.rs', 'Cargo.toml'
+                                              (default: all).
+    --hinting <hinting>                       How the glyphs are fitted to the pixel
+                                              grid: 'none' or 'grid-fit'
+                                              (default: none). Pass the same
+                                              hinting to the export subcommands
+                                              to export the glyphs of the crate.";
+
+/// Options of the library generation and the `bdf-import` subcommand.
+#[derive(Debug)]
+struct Options {
+    output: Output,
+    steps: Vec<Step>,
+    hinting: Hinting,
+    /// Whether any option of the output was given, i.e., any option except for
+    /// `--hinting`, which the export subcommands accept as well.
+    output_given: bool,
+}
 
 /// Binary that does all the codegen.
 fn main() {
-    // Shared instance for the font rasterization of all characters. Ensures unique filenames
-    // so that I can `include!` the Rust definitions for the rasterized characters.
-    let mut bytes_outsourcer = BytesToFileOutsourcer::new(CODEGEN_RASTERIZED_BYTES_PATH);
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (options, args) = options_from_args(&args);
+    match args.first().map(String::as_str) {
+        None => codegen_library(&options),
+        Some("bdf-import") => import_bdf(&args[1..], &options),
+        Some(_) if options.output_given => exit_with_usage(),
+        Some("psf2") => export_psf2(&args[1..], options.hinting),
+        Some("bdf") => export_bdf(&args[1..], options.hinting),
+        Some("blob") => export_blob(&args[1..], options.hinting),
+        Some(_) => exit_with_usage(),
+    }
+}
 
-    // debugging info
-    {
-        let font =
-            RasterizationInfo::new(20_usize, noto_font_by_weight(&SUPPORTED_FONT_WEIGHTS[0]));
-        println!("INFO: The widest char is '{}'", font.widest_char());
+/// Splits the [`Options`] from the other arguments.
+fn options_from_args(args: &[String]) -> (Options, Vec<String>) {
+    let mut options = Options {
+        output: Output::new(DEFAULT_OUTPUT_DIR),
+        steps: Step::ALL.to_vec(),
+        hinting: HINTING,
+        output_given: false,
+    };
+    let mut dry_run = false;
+    let mut other = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out-dir" => {
+                let dir = args.next().unwrap_or_else(|| exit_with_usage());
+                options.output = Output::new(dir);
+            }
+            "--dry-run" => dry_run = true,
+            "--steps" => {
+                let steps = args.next().unwrap_or_else(|| exit_with_usage());
+                options.steps = steps
+                    .split(',')
+                    .map(|step| {
+                        step.parse().unwrap_or_else(|e| {
+                            eprintln!("{e}");
+                            exit_with_usage()
+                        })
+                    })
+                    .collect();
+            }
+            "--hinting" => {
+                let hinting = args.next().unwrap_or_else(|| exit_with_usage());
+                options.hinting = hinting.parse().unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    exit_with_usage()
+                });
+                continue;
+            }
+            arg if arg.starts_with("--") => exit_with_usage(),
+            _ => {
+                other.push(arg.clone());
+                continue;
+            }
+        }
+        options.output_given = true;
     }
+    options.output = options.output.with_dry_run(dry_run);
+    (options, other)
+}
+
+/// Prints the usage and exits with an error.
+fn exit_with_usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(1)
+}
+
+/// Returns the [`FontWeight`] for an argument, such as `regular`.
+fn weight_from_arg(weight: &str) -> &'static FontWeight {
+    SUPPORTED_FONT_WEIGHTS
+        .iter()
+        .find(|w| w.mod_name() == weight)
+        .unwrap_or_else(|| exit_with_usage())
+}
+
+/// Returns the [`RasterizationInfo`] for a font weight and a raster height given as
+/// argument, such as `16`.
+fn font_from_args(weight: &FontWeight, size: &str, hinting: Hinting) -> RasterizationInfo {
+    let size = SUPPORTED_RASTER_HEIGHTS
+        .iter()
+        .find(|h| h.value().to_string() == size)
+        .unwrap_or_else(|| exit_with_usage());
+    RasterizationInfo::new(size.value() as usize, noto_font_by_weight(weight)).with_hinting(hinting)
+}
 
-    // create the font weight modules for each supported font weight.
-    for weight in SUPPORTED_FONT_WEIGHTS {
-        let font_bytes = noto_font_by_weight(weight);
-        codegen_font_weight_module(font_bytes, weight, &mut bytes_outsourcer);
+/// Parses the optional [`Monochrome`] mode argument of the export subcommands.
+fn monochrome_from_args(mode: &[String]) -> Monochrome {
+    match mode {
+        [] => Monochrome::default(),
+        [mode] => mode.parse().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            exit_with_usage()
+        }),
+        _ => exit_with_usage(),
     }
+}
 
-    codegen_cargo_toml();
-    codegen_lib_rs();
+/// Implements the `psf2` subcommand.
+fn export_psf2(args: &[String], hinting: Hinting) {
+    let [weight, size, path, mode @ ..] = args else {
+        exit_with_usage()
+    };
+    let mode = monochrome_from_args(mode);
+
+    let font = font_from_args(weight_from_arg(weight), size, hinting);
+    std::fs::write(path, psf2_from_font(&font, mode)).unwrap();
+    println!("INFO: Wrote PSF2 font to '{path}'");
 }
 
-/// Generates the Cargo.toml with all relevant features.
-fn codegen_cargo_toml() {
-    // let mut path = PathBuf::from(CODEGEN_BASE_PATH);
-    let mut path = PathBuf::from("..");
-    path.push("Cargo.toml");
-
-    let mut cargo_toml_file = File::options()
-        .create(true)
-        .write(true)
-        .append(false)
-        .truncate(true)
-        .open(path)
-        .unwrap();
+/// Implements the `bdf` subcommand.
+fn export_bdf(args: &[String], hinting: Hinting) {
+    let [weight, size, path, mode @ ..] = args else {
+        exit_with_usage()
+    };
+    let mode = monochrome_from_args(mode);
+
+    let weight = weight_from_arg(weight);
+    let font = font_from_args(weight, size, hinting);
+    std::fs::write(path, bdf_from_font(&font, weight, mode)).unwrap();
+    println!("INFO: Wrote BDF font to '{path}'");
+}
+
+/// Implements the `bdf-import` subcommand. Regenerates the module of the font weight
+/// and raster height with the glyphs of the BDF font. All other characters are
+/// rasterized from the TTF font as usual.
+fn import_bdf(args: &[String], options: &Options) {
+    let [weight, size, path] = args else {
+        exit_with_usage()
+    };
+
+    let weight = weight_from_arg(weight);
+    let font = font_from_args(weight, size, options.hinting);
+    let bdf = std::fs::read_to_string(path).unwrap();
+    let rasters = BdfFont::parse(&bdf)
+        .and_then(|bdf| bdf.to_rasters(&font))
+        .unwrap_or_else(|e| {
+            eprintln!("ERROR: Can't import '{path}': {e}");
+            std::process::exit(1)
+        });
+
+    println!("INFO: Imported {} glyphs from '{path}'", rasters.len());
+    let output = &options.output;
+    let mut bytes_outsourcer = BytesToFileOutsourcer::new(output.clone());
+    codegen_font_weight_sub_modules(
+        font.with_overrides(rasters),
+        weight,
+        output,
+        &mut bytes_outsourcer,
+    );
+    println!("INFO: Run `cargo fmt` in the root of the repository to format the generated code");
+}
+
+/// Implements the `blob` subcommand.
+fn export_blob(args: &[String], hinting: Hinting) {
+    let [path, specs @ ..] = args else {
+        exit_with_usage()
+    };
+
+    let fonts = if specs.is_empty() {
+        SUPPORTED_FONT_WEIGHTS
+            .iter()
+            .flat_map(|weight| {
+                SUPPORTED_RASTER_HEIGHTS.iter().map(move |size| {
+                    (
+                        weight,
+                        font_from_args(weight, &size.value().to_string(), hinting),
+                    )
+                })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        specs
+            .iter()
+            .map(|spec| {
+                let (weight, size) = spec.split_once(':').unwrap_or_else(|| exit_with_usage());
+                let weight = weight_from_arg(weight);
+                (weight, font_from_args(weight, size, hinting))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let blob = blob_from_fonts(&fonts);
+    std::fs::write(path, &blob).unwrap();
+    println!(
+        "INFO: Wrote font blob with {} fonts ({} bytes) to '{path}'",
+        fonts.len(),
+        blob.len()
+    );
+}
+
+/// Generates the library with the selected steps.
+fn codegen_library(options: &Options) {
+    let output = &options.output;
+    println!(
+        "INFO: Generating {} with hinting '{}' into '{}'",
+        options
+            .steps
+            .iter()
+            .map(|step| step.name())
+            .collect::<Vec<_>>()
+            .join(", "),
+        options.hinting,
+        output.root().display()
+    );
 
+    if options.steps.contains(&Step::Glyphs) {
+        // Shared instance for the font rasterization of all characters. Ensures unique
+        // filenames so that I can `include_bytes!` the rasterized characters.
+        let mut bytes_outsourcer = BytesToFileOutsourcer::new(output.clone());
+
+        // debugging info
+        {
+            let font =
+                RasterizationInfo::new(20_usize, noto_font_by_weight(&SUPPORTED_FONT_WEIGHTS[0]));
+            println!("INFO: The widest char is '{}'", font.widest_char());
+        }
+
+        // create the font weight modules for each supported font weight.
+        for weight in SUPPORTED_FONT_WEIGHTS {
+            let font_bytes = noto_font_by_weight(weight);
+            codegen_font_weight_module(
+                font_bytes,
+                weight,
+                options.hinting,
+                output,
+                &mut bytes_outsourcer,
+            );
+        }
+    }
+
+    if options.steps.contains(&Step::CargoToml) {
+        codegen_cargo_toml(output);
+        // the Cargo.toml lists the examples
+        copy_sources("examples", &[], output);
+    }
+    if options.steps.contains(&Step::LibRs) {
+        codegen_lib_rs(output);
+        codegen_tests_rs(output);
+        // the lib.rs declares the hand-written modules
+        copy_sources("src", &["lib.rs", "tests.rs"], output);
+    }
+}
+
+/// Generates the Cargo.toml with all relevant features.
+fn codegen_cargo_toml(output: &Output) {
     let mut features_font_weights = String::new();
     SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
         writeln!(&mut features_font_weights, "{} = []", w.mod_name()).unwrap();
@@ -156,10 +421,7 @@ fn codegen_cargo_toml() {
 
     // replace placeholders
     #[rustfmt::skip]
-    writeln!(
-        &mut cargo_toml_file,
-        "{}",
-        CARGO_TOML_TEMPLATE
+    let cargo_toml = CARGO_TOML_TEMPLATE
             .replace("# %CODEGEN_FONT_WEIGHTS%", features_font_weights.as_str())
             .replace("# %CODEGEN_FONT_SIZES%", features_font_sizes.as_str())
             .replace("# %CODEGEN_UNICODE_RANGES%", features_unicode_ranges.as_str())
@@ -168,28 +430,22 @@ fn codegen_cargo_toml() {
             .replace("# %CODEGEN_FEATURES_WEIGHTS_DEFAULT%", features_font_styles_default.as_str())
             .replace("# %CODEGEN_FEATURES_WEIGHTS_ALL%", features_font_styles_all.as_str())
             .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_DEFAULT%", features_unicode_default.as_str())
-            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_ALL%", features_unicode_all.as_str())
-    )
-    .unwrap();
+            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_ALL%", features_unicode_all.as_str());
+    output.write(&output.root().join("Cargo.toml"), cargo_toml + "\n");
 }
 
 /// Generates the lib.rs with all relevant features.
-fn codegen_lib_rs() {
-    let mut lib_rs_path = PathBuf::from(CODEGEN_BASE_PATH);
-    lib_rs_path.push("lib.rs");
-
-    let mut cargo_toml_file = File::options()
-        .create(true)
-        .write(true)
-        .append(false)
-        .truncate(true)
-        .open(lib_rs_path)
-        .unwrap();
-
+fn codegen_lib_rs(output: &Output) {
     // codegen font weight modules
     let mut weight_modules = String::new();
     {
         SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            writeln!(
+                &mut weight_modules,
+                "#[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
             writeln!(&mut weight_modules, "mod {};", w.mod_name()).unwrap();
         });
     }
@@ -208,6 +464,42 @@ fn codegen_lib_rs() {
         });
     }
 
+    // codegen FontWeight::ALL and FontWeight::name()
+    let mut weight_all = String::new();
+    let mut weight_names = String::new();
+    {
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            let cfg = format!("#[cfg(feature = \"{}\")]", w.mod_name());
+            writeln!(
+                &mut weight_all,
+                "        {cfg}\n        Self::{:?},",
+                w.name()
+            )
+            .unwrap();
+            writeln!(
+                &mut weight_names,
+                "            {cfg}\n            Self::{:?} => \"{}\",",
+                w.name(),
+                w.mod_name()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen RasterHeight::ALL
+    let mut raster_height_all = String::new();
+    {
+        SUPPORTED_RASTER_HEIGHTS.iter().for_each(|height| {
+            writeln!(
+                &mut raster_height_all,
+                "        #[cfg(feature = \"{}\")]\n        Self::Size{},",
+                height.feature_name(),
+                height.value()
+            )
+            .unwrap();
+        });
+    }
+
     // codegen font size enum variants
     let mut font_size_enum_variants = String::new();
     {
@@ -264,6 +556,78 @@ fn codegen_lib_rs() {
         });
     }
 
+    // codegen get_raster_subpixel match
+    let mut get_raster_subpixel_match = String::new();
+    {
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            writeln!(
+                &mut get_raster_subpixel_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_raster_subpixel_match,
+                "        FontWeight::{:?} => match size {{",
+                w.name()
+            )
+            .unwrap();
+            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+                writeln!(
+                    &mut get_raster_subpixel_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_raster_subpixel_match,
+                    "            RasterHeight::Size{} => crate::{}::size_{}::get_char_subpixel(c),",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_raster_subpixel_match, "        }}").unwrap();
+        });
+    }
+
+    // codegen get_raster_compressed match
+    let mut get_raster_compressed_match = String::new();
+    {
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            writeln!(
+                &mut get_raster_compressed_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_raster_compressed_match,
+                "        FontWeight::{:?} => match size {{",
+                w.name()
+            )
+            .unwrap();
+            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+                writeln!(
+                    &mut get_raster_compressed_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_raster_compressed_match,
+                    "            RasterHeight::Size{} => crate::{}::size_{}::get_char_compressed(c),",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_raster_compressed_match, "        }}").unwrap();
+        });
+    }
+
     // codegen get_raster_width match
     let mut get_raster_width_match = String::new();
     {
@@ -300,28 +664,268 @@ fn codegen_lib_rs() {
         });
     }
 
-    // replace placeholders
+    // codegen the maximum raster width of all enabled font weights and raster heights
+    let mut max_raster_width = String::new();
     {
-        writeln!(
-            &mut cargo_toml_file,
-            "{}",
-            CARGO_LIB_RS
-                .replace("// %CODEGEN_LIB_MODULES%", weight_modules.as_str())
-                .replace(
-                    "// %CODEGEN_FONT_WEIGHT_VARIANTS%",
-                    weight_variants.as_str()
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+                writeln!(
+                    &mut max_raster_width,
+                    "    #[cfg(all(feature = \"{}\", feature = \"{}\"))]",
+                    w.mod_name(),
+                    size.feature_name()
                 )
-                .replace(
-                    "// %CODEGEN_RASTER_SIZE_ENUM_VARIANTS%",
-                    font_size_enum_variants.as_str()
+                .unwrap();
+                let width = format!(
+                    "crate::{}::size_{}::RASTER_WIDTH",
+                    w.mod_name(),
+                    size.value()
+                );
+                writeln!(
+                    &mut max_raster_width,
+                    "    let max = if {width} > max {{ {width} }} else {{ max }};"
                 )
-                .replace("// %CODEGEN_get_raster%", get_raster_match.as_str())
-                .replace(
-                    "// %CODEGEN_get_raster_WIDTH%",
-                    get_raster_width_match.as_str()
+                .unwrap();
+            });
+        });
+    }
+
+    // codegen the maximum raster height of all enabled raster heights
+    let mut max_raster_height = String::new();
+    {
+        SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+            writeln!(
+                &mut max_raster_height,
+                "    #[cfg(feature = \"{}\")]",
+                size.feature_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut max_raster_height,
+                "    let max = if {0} > max {{ {0} }} else {{ max }};",
+                size.value()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the ranges of supported chars of all enabled unicode ranges
+    let mut supported_char_ranges = String::new();
+    {
+        SUPPORTED_UNICODE_RANGES.iter().for_each(|range| {
+            for chars in range.supported_ranges() {
+                writeln!(
+                    &mut supported_char_ranges,
+                    "    #[cfg(feature = \"{}\")]",
+                    range.feature_name
+                )
+                .unwrap();
+                writeln!(
+                    &mut supported_char_ranges,
+                    "    ({:?}, {:?}),",
+                    chars.start(),
+                    chars.end()
                 )
+                .unwrap();
+            }
+        });
+    }
+
+    // codegen get_decoration_metrics match
+    let mut get_decoration_metrics_match = String::new();
+    {
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            writeln!(
+                &mut get_decoration_metrics_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_decoration_metrics_match,
+                "        FontWeight::{:?} => match size {{",
+                w.name()
+            )
+            .unwrap();
+            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+                writeln!(
+                    &mut get_decoration_metrics_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_decoration_metrics_match,
+                    "            RasterHeight::Size{} => crate::{}::size_{}::DECORATION_METRICS,",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_decoration_metrics_match, "        }}").unwrap();
+        });
+    }
+
+    // codegen get_baseline match
+    let mut get_baseline_match = String::new();
+    {
+        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+            writeln!(
+                &mut get_baseline_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_baseline_match,
+                "        FontWeight::{:?} => match size {{",
+                w.name()
+            )
+            .unwrap();
+            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+                writeln!(
+                    &mut get_baseline_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_baseline_match,
+                    "            RasterHeight::Size{} => crate::{}::size_{}::BASELINE,",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_baseline_match, "        }}").unwrap();
+        });
+    }
+
+    // replace placeholders
+    {
+        let lib_rs = CARGO_LIB_RS
+            .replace("// %CODEGEN_LIB_MODULES%", weight_modules.as_str())
+            .replace(
+                "// %CODEGEN_FONT_WEIGHT_VARIANTS%",
+                weight_variants.as_str(),
+            )
+            .replace(
+                "// %CODEGEN_RASTER_SIZE_ENUM_VARIANTS%",
+                font_size_enum_variants.as_str(),
+            )
+            .replace("// %CODEGEN_FONT_WEIGHT_ALL%", weight_all.as_str())
+            .replace("// %CODEGEN_FONT_WEIGHT_NAMES%", weight_names.as_str())
+            .replace("// %CODEGEN_RASTER_HEIGHT_ALL%", raster_height_all.as_str())
+            .replace("// %CODEGEN_get_raster%", get_raster_match.as_str())
+            .replace(
+                "// %CODEGEN_get_raster_subpixel%",
+                get_raster_subpixel_match.as_str(),
+            )
+            .replace(
+                "// %CODEGEN_get_raster_compressed%",
+                get_raster_compressed_match.as_str(),
+            )
+            .replace(
+                "// %CODEGEN_get_raster_WIDTH%",
+                get_raster_width_match.as_str(),
+            )
+            .replace("// %CODEGEN_get_baseline%", get_baseline_match.as_str())
+            .replace(
+                "// %CODEGEN_get_decoration_metrics%",
+                get_decoration_metrics_match.as_str(),
+            )
+            .replace("// %CODEGEN_MAX_RASTER_WIDTH%", max_raster_width.as_str())
+            .replace("// %CODEGEN_MAX_RASTER_HEIGHT%", max_raster_height.as_str())
+            .replace(
+                "// %CODEGEN_SUPPORTED_CHAR_RANGES%",
+                supported_char_ranges.as_str(),
+            );
+        output.write(&output.src_dir().join("lib.rs"), lib_rs + "\n");
+    }
+}
+
+/// Generates the tests.rs, which checks the invariants of the rasters of all enabled
+/// font weights, raster heights, and unicode ranges.
+fn codegen_tests_rs(output: &Output) {
+    let mut font_weights = String::new();
+    SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
+        writeln!(
+            &mut font_weights,
+            "    #[cfg(feature = \"{}\")]",
+            w.mod_name()
+        )
+        .unwrap();
+        writeln!(&mut font_weights, "    FontWeight::{:?},", w.name()).unwrap();
+    });
+
+    let mut raster_heights = String::new();
+    SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
+        writeln!(
+            &mut raster_heights,
+            "    #[cfg(feature = \"{}\")]",
+            size.feature_name()
+        )
+        .unwrap();
+        writeln!(
+            &mut raster_heights,
+            "    RasterHeight::Size{},",
+            size.value()
+        )
+        .unwrap();
+    });
+
+    // all ranges, also the disabled ones, whose chars must not have a raster
+    let mut unicode_ranges = String::new();
+    SUPPORTED_UNICODE_RANGES.iter().for_each(|range| {
+        let ignored = range
+            .ignored_symbols
+            .iter()
+            .map(|r| format!("{:#x}..={:#x}", r.start(), r.end()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            &mut unicode_ranges,
+            "    UnicodeRange {{
+        enabled: cfg!(feature = \"{}\"),
+        code_points: {:#x}..={:#x},
+        ignored: &[{ignored}],
+    }},",
+            range.feature_name, range.begin, range.end,
         )
         .unwrap();
+    });
+
+    output.write(
+        &output.src_dir().join("tests.rs"),
+        TESTS_RS_TEMPLATE
+            .replace("// %CODEGEN_FONT_WEIGHTS%\n", &font_weights)
+            .replace("// %CODEGEN_RASTER_HEIGHTS%\n", &raster_heights)
+            .replace("// %CODEGEN_UNICODE_RANGES%\n", &unicode_ranges),
+    );
+}
+
+/// Copies the Rust files in the directory `dir` of this repository, except for the
+/// `generated` ones, into the same directory of the output. Does nothing if the output
+/// is this repository.
+fn copy_sources(dir: &str, generated: &[&str], output: &Output) {
+    let source = Path::new(DEFAULT_OUTPUT_DIR).join(dir);
+    let target = output.root().join(dir);
+    if source.canonicalize().ok() == target.canonicalize().ok() {
+        return;
+    }
+
+    let mut files = std::fs::read_dir(&source)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "rs"))
+        .filter(|path| !generated.iter().any(|name| path.ends_with(name)))
+        .collect::<Vec<_>>();
+    files.sort();
+    for file in files {
+        let contents = std::fs::read(&file).unwrap();
+        output.write(&target.join(file.file_name().unwrap()), contents);
     }
 }
 
@@ -329,38 +933,24 @@ fn codegen_lib_rs() {
 fn codegen_font_weight_module(
     font_bytes: &[u8],
     weight: &FontWeight,
+    hinting: Hinting,
+    output: &Output,
     outsourcer: &mut BytesToFileOutsourcer,
 ) {
-    let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
-    mod_file_path.push(weight.mod_name());
-    // ignore error; dir might exist
-    let _ = create_dir(&mod_file_path);
-    mod_file_path.push("mod.rs");
-
-    let mut mod_file = File::options()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .append(false)
-        .open(mod_file_path)
-        .unwrap();
-
     // replace placeholders
-    writeln!(
-        &mut mod_file,
-        "{}",
-        WEIGHT_MOD_TEMPLATE.replace("%FONT_WEIGHT%", weight.mod_name())
-    )
-    .unwrap();
+    let mut mod_file = WEIGHT_MOD_TEMPLATE.replace("%FONT_WEIGHT%", weight.mod_name()) + "\n";
 
     for size in SUPPORTED_RASTER_HEIGHTS.iter().map(|x| x.value()) {
         // add font modules for the font sizes
         writeln!(&mut mod_file, "#[cfg(feature = \"size_{}\")]", size).unwrap();
         writeln!(&mut mod_file, "pub mod size_{};", size).unwrap();
 
-        let font = RasterizationInfo::new(size as usize, font_bytes);
-        codegen_font_weight_sub_modules(font, weight, outsourcer);
+        let font = RasterizationInfo::new(size as usize, font_bytes).with_hinting(hinting);
+        codegen_font_weight_sub_modules(font, weight, output, outsourcer);
     }
+
+    let path = output.src_dir().join(weight.mod_name()).join("mod.rs");
+    output.write(&path, mod_file);
 }
 
 /// Creates a `<weight>/size_<size>.rs` file performs all the code generation for the byte look-up of
@@ -368,22 +958,10 @@ fn codegen_font_weight_module(
 fn codegen_font_weight_sub_modules(
     font: RasterizationInfo,
     weight: &FontWeight,
+    output: &Output,
     outsourcer: &mut BytesToFileOutsourcer,
 ) {
-    // this block creates the file <weight>/size_<size>.rs and returns a File object to that file.
-    let mut size_mod_file = {
-        let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
-        mod_file_path.push(weight.mod_name());
-        mod_file_path.push(format!("size_{}.rs", font.raster_height()));
-
-        File::options()
-            .create(true)
-            .write(true)
-            .append(false)
-            .truncate(true)
-            .open(mod_file_path)
-            .unwrap()
-    };
+    let mut size_mod_file = String::new();
 
     // this block prepares the head of the just generated file
     {
@@ -401,16 +979,91 @@ fn codegen_font_weight_sub_modules(
                     "%CODEGEN_RASTER_WIDTH%",
                     &format!("{}", font.raster_width())
                 )
+                .replace("%CODEGEN_BASELINE%", &format!("{}", font.baseline()))
+                .replace(
+                    "%CODEGEN_UNDERLINE_POSITION%",
+                    &format!("{}", font.decoration_metrics().underline_position)
+                )
+                .replace(
+                    "%CODEGEN_UNDERLINE_THICKNESS%",
+                    &format!("{}", font.decoration_metrics().underline_thickness)
+                )
+                .replace(
+                    "%CODEGEN_STRIKEOUT_POSITION%",
+                    &format!("{}", font.decoration_metrics().strikeout_position)
+                )
+                .replace(
+                    "%CODEGEN_STRIKEOUT_THICKNESS%",
+                    &format!("{}", font.decoration_metrics().strikeout_thickness)
+                )
+                .replace(
+                    "%CODEGEN_OVERLINE_POSITION%",
+                    &format!("{}", font.decoration_metrics().overline_position)
+                )
         )
         .unwrap();
     }
 
-    // the rest of the file generates the big match-block that maps characters to the pre-rasterized
-    // bytes.
+    // the rest of the file generates the big match-blocks that map characters to the
+    // pre-rasterized bytes.
+    let (get_char, stats) = codegen_get_char_fn(&font, weight, outsourcer, false);
+    let (get_char_subpixel, stats_subpixel) = codegen_get_char_fn(&font, weight, outsourcer, true);
+    let name = format!("{} size_{}", weight.mod_name(), font.raster_height());
+    println!("INFO: Interned {name}: {stats}");
+    println!("INFO: Interned {name} subpixel: {stats_subpixel}");
+    let get_char_compressed = codegen_get_char_compressed_fn(&font, weight, outsourcer);
+
+    size_mod_file += &get_char;
+    size_mod_file += "\n";
+    size_mod_file += &get_char_subpixel;
+    size_mod_file += "\n";
+    size_mod_file += &get_char_compressed;
+
+    let path = output
+        .src_dir()
+        .join(weight.mod_name())
+        .join(format!("size_{}.rs", font.raster_height()));
+    output.write(&path, size_mod_file);
+}
 
+/// Generates the `get_char` function of a `<weight>/size_<size>.rs` file, or the
+/// `get_char_subpixel` function if `subpixel` is set, and outsources the rasters of all
+/// characters into binary files per unicode range.
+///
+/// Each unicode range gets a static with the rows of all of its distinct rasters,
+/// which is built at compile time from a binary file with the distinct rows and a
+/// binary file with the indices of the rows of each raster. Hence, identical rows
+/// share their bytes. The match arms of the function then refer to the raster of a
+/// character by its constant offset in that static. Identical rasters share the
+/// offset. Returns the function and the statistics of the interning.
+fn codegen_get_char_fn(
+    font: &RasterizationInfo,
+    weight: &FontWeight,
+    outsourcer: &mut BytesToFileOutsourcer,
+    subpixel: bool,
+) -> (String, InternStats) {
+    let mut statics_string = String::new();
+    let mut stats = InternStats::default();
     // prepares the "get_char" function with its match block
     let mut code_range_string = String::new();
-    {
+    if subpixel {
+        writeln!(
+            &mut code_range_string,
+            "/// Returns the subpixel raster of the given character for font weight {} and font size\n\
+            /// {}px. Each row contains three bytes per pixel with the unfiltered coverage of the\n\
+            /// subpixels from left to right.",
+            weight.mod_name(),
+            font.font_size().round()
+        )
+        .unwrap();
+        writeln!(&mut code_range_string, "#[cfg(feature = \"subpixel\")]").unwrap();
+        writeln!(&mut code_range_string, "#[inline]").unwrap();
+        writeln!(
+            &mut code_range_string,
+            "pub const fn get_char_subpixel(c: char) -> Option<&'static [&'static [u8]]> {{"
+        )
+        .unwrap();
+    } else {
         writeln!(
             &mut code_range_string,
             "/// Returns the raster of the given character for font weight {} and font size {}px.\n\
@@ -426,92 +1079,231 @@ fn codegen_font_weight_sub_modules(
             "pub const fn get_char(c: char) -> Option<&'static [&'static [u8]]> {{"
         )
         .unwrap();
-        writeln!(&mut code_range_string, "    match c {{").unwrap();
     }
+    writeln!(&mut code_range_string, "    match c {{").unwrap();
 
     // now we generate all the single match arms per character
-
     // iterates through all ranges and for each range over all visible characters
-    SUPPORTED_UNICODE_RANGES.iter().for_each(|range| {
-        range
+    for range in SUPPORTED_UNICODE_RANGES {
+        let (static_name, cfg, row_width) = if subpixel {
+            (
+                format!("{}_SUBPIXEL", static_name(range.feature_name)),
+                format!(
+                    "#[cfg(all(feature = \"subpixel\", feature = \"{}\"))]",
+                    range.feature_name
+                ),
+                "3 * RASTER_WIDTH",
+            )
+        } else {
+            (
+                static_name(range.feature_name),
+                format!("#[cfg(feature = \"{}\")]", range.feature_name),
+                "RASTER_WIDTH",
+            )
+        };
+
+        let chars = range.iter().collect::<Vec<_>>();
+        let rasters = chars
             .iter()
-            .map(|char| (char, font.rasterize(char)))
-            .for_each(|(char, raster)| {
-                writeln!(
-                    &mut code_range_string,
-                    "        // letter: '{}' / {:?}",
-                    char, char as usize as *const usize
-                )
-                .unwrap();
+            .map(|&char| {
+                if subpixel {
+                    font.rasterize_subpixel(char)
+                } else {
+                    font.rasterize(char)
+                }
+            })
+            .collect::<Vec<_>>();
+        let interned = InternedRasters::new(&rasters);
+        stats += interned.stats();
 
-                // make this character optional by it's unicode range
-                writeln!(
-                    &mut code_range_string,
-                    "#[cfg(feature = \"{}\")]",
-                    range.feature_name
-                )
-                .unwrap();
+        for (&char, index) in chars.iter().zip(&interned.raster_indices) {
+            writeln!(
+                &mut code_range_string,
+                "        // letter: '{}' / {:?}",
+                char, char as usize as *const usize
+            )
+            .unwrap();
 
-                // generate source code representation of raster
-                let rust_raster_source_code = codegen_raster(&raster);
+            // make this character optional by it's unicode range
+            writeln!(
+                &mut code_range_string,
+                "#[cfg(feature = \"{}\")]",
+                range.feature_name
+            )
+            .unwrap();
 
-                let outsourced_path = outsourcer.outsource_bytes(
-                    rust_raster_source_code.as_bytes(),
-                    Context {
-                        c: char,
-                        weight: *weight,
-                        height: font.raster_height() as u32,
-                    },
-                );
+            writeln!(
+                &mut code_range_string,
+                "        {} => Some(crate::glyph_data::raster_at(&{static_name}, {index}, RASTER_HEIGHT)),",
+                codegen_char_pattern(char)
+            )
+            .unwrap();
+        }
 
-                // generate left side of the match arm
-                {
-                    if char == '\\' || char == '\'' {
-                        write!(&mut code_range_string, "        '\\{}'", char,).unwrap();
-                    } else {
-                        write!(&mut code_range_string, "        '{}'", char,).unwrap()
-                    }
-                }
-                // generate right side of the match arm
-                {
-                    // this is (as the rest of the codegen stuff) very ugly.
-                    // need to adapt the path, so that cargo can find it during compilation..
-                    let path = format!(
-                        "../res_rasterized_characters/{}",
-                        outsourced_path.file_name().unwrap().to_str().unwrap()
-                    );
-                    writeln!(&mut code_range_string, "=> Some(include!(\"{}\")),", path).unwrap();
-                }
-            })
-    });
+        let ctx = Context {
+            unicode_range: range.feature_name,
+            weight: *weight,
+            height: font.raster_height() as u32,
+            format: if subpixel {
+                RasterFormat::Subpixel
+            } else {
+                RasterFormat::Raw
+            },
+            index: false,
+        };
+        // need to adapt the paths, so that cargo can find them during compilation..
+        let rows_path = outsourcer.outsource_bytes(&interned.rows_bytes(), ctx);
+        let rows_path = format!(
+            "../res_rasterized_characters/{}",
+            rows_path.file_name().unwrap().to_str().unwrap()
+        );
+        let index_path =
+            outsourcer.outsource_bytes(&interned.index_bytes(), Context { index: true, ..ctx });
+        let index_path = format!(
+            "../res_rasterized_characters/{}",
+            index_path.file_name().unwrap().to_str().unwrap()
+        );
+        let row_count = match interned.rasters.len() {
+            1 => "RASTER_HEIGHT".to_string(),
+            n => format!("{n} * RASTER_HEIGHT"),
+        };
+        writeln!(
+            &mut statics_string,
+            "/// The rows of the distinct rasters of all characters of the unicode range\n\
+            /// `{}`{}.\n\
+            {cfg}\n\
+            static {static_name}: [&[u8]; {row_count}] = crate::glyph_data::intern_rows(\n\
+            \x20   include_bytes!(\"{rows_path}\"),\n\
+            \x20   include_bytes!(\"{index_path}\"),\n\
+            \x20   {row_width},\n\
+            );\n",
+            range.feature_name,
+            if subpixel {
+                " in subpixel resolution"
+            } else {
+                ""
+            },
+        )
+        .unwrap();
+    }
     writeln!(&mut code_range_string, "        _ => None").unwrap();
     // close match
     writeln!(&mut code_range_string, "    }}").unwrap();
     // close function
     writeln!(&mut code_range_string, "}}").unwrap();
 
-    size_mod_file
-        .write_all(code_range_string.as_bytes())
-        .unwrap();
+    (statics_string + &code_range_string, stats)
 }
 
-/// Generates the Rust source code of type `&[&u8]]` from a raster.
-fn codegen_raster(raster: &Vec<Vec<u8>>) -> String {
-    let mut rust_byte_array_str = String::new();
-    writeln!(&mut rust_byte_array_str, "&[").unwrap();
-    for row in raster {
-        write!(&mut rust_byte_array_str, "    &[").unwrap();
-        for (i, byte) in row.iter().enumerate() {
-            if i == row.len() - 1 {
-                write!(&mut rust_byte_array_str, "{}", byte).unwrap();
-            } else {
-                write!(&mut rust_byte_array_str, "{}, ", byte).unwrap();
-            }
+/// Generates the `get_char_compressed` function of a `<weight>/size_<size>.rs` file and
+/// outsources the run-length encoded rasters of all characters into one binary file per
+/// unicode range.
+///
+/// Each unicode range gets a static with the encoded rasters of all of its characters.
+/// The match arms of the function refer to the raster of a character by its constant
+/// start and end offset in that static. Identical rasters share their bytes.
+fn codegen_get_char_compressed_fn(
+    font: &RasterizationInfo,
+    weight: &FontWeight,
+    outsourcer: &mut BytesToFileOutsourcer,
+) -> String {
+    let mut statics_string = String::new();
+    let mut code_range_string = String::new();
+    writeln!(
+        &mut code_range_string,
+        "/// Returns the run-length encoded raster of the given character for font weight {}\n\
+        /// and font size {}px. See [`crate::CompressedChar`] for the encoding.",
+        weight.mod_name(),
+        font.font_size().round()
+    )
+    .unwrap();
+    writeln!(&mut code_range_string, "#[cfg(feature = \"compressed\")]").unwrap();
+    writeln!(&mut code_range_string, "#[inline]").unwrap();
+    writeln!(
+        &mut code_range_string,
+        "pub const fn get_char_compressed(c: char) -> Option<&'static [u8]> {{"
+    )
+    .unwrap();
+    writeln!(&mut code_range_string, "    match c {{").unwrap();
+
+    for range in SUPPORTED_UNICODE_RANGES {
+        let static_name = format!("{}_COMPRESSED", static_name(range.feature_name));
+        let mut bytes = Vec::new();
+        // identical rasters share their bytes
+        let mut offsets = HashMap::new();
+        for char in range.iter() {
+            let encoded = encode_rle(&font.rasterize(char));
+            let (start, end) = *offsets.entry(encoded).or_insert_with_key(|encoded| {
+                bytes.extend_from_slice(encoded);
+                (bytes.len() - encoded.len(), bytes.len())
+            });
+            writeln!(
+                &mut code_range_string,
+                "        // letter: '{}' / {:?}",
+                char, char as usize as *const usize
+            )
+            .unwrap();
+            writeln!(
+                &mut code_range_string,
+                "#[cfg(feature = \"{}\")]",
+                range.feature_name
+            )
+            .unwrap();
+            writeln!(
+                &mut code_range_string,
+                "        {} => Some(crate::glyph_data::bytes_at({static_name}, {start}, {end})),",
+                codegen_char_pattern(char),
+            )
+            .unwrap();
         }
-        writeln!(&mut rust_byte_array_str, "],").unwrap();
+
+        let outsourced_path = outsourcer.outsource_bytes(
+            &bytes,
+            Context {
+                unicode_range: range.feature_name,
+                weight: *weight,
+                height: font.raster_height() as u32,
+                format: RasterFormat::Compressed,
+                index: false,
+            },
+        );
+        // need to adapt the path, so that cargo can find it during compilation..
+        let path = format!(
+            "../res_rasterized_characters/{}",
+            outsourced_path.file_name().unwrap().to_str().unwrap()
+        );
+        writeln!(
+            &mut statics_string,
+            "/// The run-length encoded rasters of all characters of the unicode range\n\
+            /// `{}`.\n\
+            #[cfg(all(feature = \"compressed\", feature = \"{}\"))]\n\
+            static {static_name}: &[u8] = include_bytes!(\"{path}\");\n",
+            range.feature_name, range.feature_name,
+        )
+        .unwrap();
+    }
+    writeln!(&mut code_range_string, "        _ => None").unwrap();
+    // close match
+    writeln!(&mut code_range_string, "    }}").unwrap();
+    // close function
+    writeln!(&mut code_range_string, "}}").unwrap();
+
+    statics_string + &code_range_string
+}
+
+/// Generates the pattern of a match arm for `char`, i.e., the escaped char literal.
+fn codegen_char_pattern(char: char) -> String {
+    if char == '\\' || char == '\'' {
+        format!("'\\{char}'")
+    } else {
+        format!("'{char}'")
     }
-    write!(&mut rust_byte_array_str, "]").unwrap();
-    rust_byte_array_str
+}
+
+/// Returns the name of the static with the rows of the rasters of a unicode range,
+/// such as `UNICODE_BASIC_LATIN` for `unicode-basic-latin`.
+fn static_name(feature_name: &str) -> String {
+    feature_name.to_uppercase().replace('-', "_")
 }
 
 #[cfg(test)]
@@ -520,16 +1312,18 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_codegen_raster() {
-        let raster = [[1, 2, 3].to_vec(), [4, 5, 6].to_vec(), [7, 8, 9].to_vec()].to_vec();
-        let generated_source_code = codegen_raster(&raster);
+    fn test_static_name() {
+        assert_eq!(static_name("unicode-basic-latin"), "UNICODE_BASIC_LATIN");
         assert_eq!(
-            "&[\n\
-            \x20\x20\x20\x20&[1, 2, 3],\n\
-            \x20\x20\x20\x20&[4, 5, 6],\n\
-            \x20\x20\x20\x20&[7, 8, 9],\n\
-            ]",
-            &generated_source_code
+            static_name("unicode-latin-1-supplement"),
+            "UNICODE_LATIN_1_SUPPLEMENT"
         );
     }
+
+    #[test]
+    fn test_codegen_char_pattern() {
+        assert_eq!(codegen_char_pattern('a'), "'a'");
+        assert_eq!(codegen_char_pattern('\\'), "'\\\\'");
+        assert_eq!(codegen_char_pattern('\''), "'\\''");
+    }
 }
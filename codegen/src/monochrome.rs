@@ -0,0 +1,121 @@
+//! Conversion of rasters with 8-bit intensities into 1-bit rasters for bitmap font
+//! formats that only know pixels that are on or off.
+
+use std::str::FromStr;
+
+/// How intensities from 0 to 255 are converted into pixels that are on or off.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Monochrome {
+    /// Pixels with an intensity of at least this value are on.
+    Threshold(u8),
+    /// Floyd-Steinberg dithering. Distributes the error of each pixel to its
+    /// neighbors, which preserves anti-aliased edges better but looks noisy on
+    /// small glyphs.
+    Dither,
+}
+
+impl Default for Monochrome {
+    fn default() -> Self {
+        Self::Threshold(128)
+    }
+}
+
+impl FromStr for Monochrome {
+    type Err = String;
+
+    /// Parses `dither` or `threshold=<0-255>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            None if s == "dither" => Ok(Self::Dither),
+            Some(("threshold", value)) => value
+                .parse()
+                .map(Self::Threshold)
+                .map_err(|e| format!("invalid threshold '{value}': {e}")),
+            _ => Err(format!(
+                "invalid mode '{s}', expected 'dither' or 'threshold=<0-255>'"
+            )),
+        }
+    }
+}
+
+impl Monochrome {
+    /// Converts a raster into rows of pixels that are on (`true`) or off.
+    pub fn convert(self, raster: &[Vec<u8>]) -> Vec<Vec<bool>> {
+        match self {
+            Self::Threshold(threshold) => raster
+                .iter()
+                .map(|row| row.iter().map(|&i| i >= threshold).collect())
+                .collect(),
+            Self::Dither => Self::dither(raster),
+        }
+    }
+
+    fn dither(raster: &[Vec<u8>]) -> Vec<Vec<bool>> {
+        let mut intensities = raster
+            .iter()
+            .map(|row| row.iter().map(|&i| i as i32).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut pixels = raster
+            .iter()
+            .map(|row| vec![false; row.len()])
+            .collect::<Vec<_>>();
+
+        for y in 0..intensities.len() {
+            let width = intensities[y].len();
+            for x in 0..width {
+                let intensity = intensities[y][x];
+                let on = intensity >= 128;
+                pixels[y][x] = on;
+
+                let error = intensity - if on { 255 } else { 0 };
+                let mut spread = |x: Option<usize>, y: usize, weight: i32| {
+                    if let Some(intensity) = x
+                        .filter(|&x| x < width)
+                        .and_then(|x| intensities.get_mut(y).map(|row| &mut row[x]))
+                    {
+                        *intensity += error * weight / 16;
+                    }
+                };
+                spread(Some(x + 1), y, 7);
+                spread(x.checked_sub(1), y + 1, 3);
+                spread(Some(x), y + 1, 5);
+                spread(Some(x + 1), y + 1, 1);
+            }
+        }
+        pixels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold() {
+        let raster = [vec![0, 127, 128, 255]];
+        assert_eq!(
+            Monochrome::Threshold(128).convert(&raster),
+            [vec![false, false, true, true]]
+        );
+    }
+
+    #[test]
+    fn test_dither() {
+        // 50% gray becomes a pattern with half of the pixels on
+        let raster = vec![vec![128; 8]; 8];
+        let pixels = Monochrome::Dither.convert(&raster);
+        let on = pixels.iter().flatten().filter(|&&on| on).count();
+        assert_eq!(on, 32);
+
+        let raster = [vec![0, 255]];
+        assert_eq!(Monochrome::Dither.convert(&raster), [vec![false, true]]);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("dither".parse(), Ok(Monochrome::Dither));
+        assert_eq!("threshold=100".parse(), Ok(Monochrome::Threshold(100)));
+        assert!("threshold=300".parse::<Monochrome>().is_err());
+        assert!("foo".parse::<Monochrome>().is_err());
+    }
+}
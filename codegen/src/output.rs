@@ -0,0 +1,150 @@
+//! Output of the code generation: the root directory of the generated crate, the
+//! dry-run mode, and the selectable steps.
+
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A step of the code generation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Step {
+    /// The font weight and raster height modules with the rasterized characters.
+    Glyphs,
+    /// The `lib.rs`, the `tests.rs` that checks all generated rasters, and a copy of
+    /// the hand-written modules that the `lib.rs` declares.
+    LibRs,
+    /// The `Cargo.toml` with all features and a copy of the examples that it lists.
+    CargoToml,
+}
+
+impl Step {
+    /// All steps in the order of their execution.
+    pub const ALL: &'static [Self] = &[Self::Glyphs, Self::LibRs, Self::CargoToml];
+
+    /// Returns the name of the step on the command line.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Glyphs => "glyphs",
+            Self::LibRs => "lib.rs",
+            Self::CargoToml => "Cargo.toml",
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|step| step.name() == s)
+            .ok_or_else(|| {
+                format!("invalid step '{s}', expected 'glyphs', 'lib.rs', or 'Cargo.toml'")
+            })
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Writes the generated files into the directory of a crate, or only reports them in
+/// the dry-run mode.
+#[derive(Debug, Clone)]
+pub struct Output {
+    root: PathBuf,
+    dry_run: bool,
+}
+
+impl Output {
+    /// Creates an output into the crate in `root`. The generated code goes into
+    /// `<root>/src`, the `Cargo.toml` into `<root>`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            dry_run: false,
+        }
+    }
+
+    /// Only reports the files instead of writing them, if `dry_run` is set.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The root directory of the generated crate.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The directory of the generated source code.
+    pub fn src_dir(&self) -> PathBuf {
+        self.root.join("src")
+    }
+
+    /// The directory with the rasterized bytes of all characters.
+    pub fn rasterized_bytes_dir(&self) -> PathBuf {
+        self.src_dir().join("res_rasterized_characters")
+    }
+
+    /// Whether files are only reported instead of written.
+    pub const fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Writes `contents` into the file at `path` and creates missing parent
+    /// directories. In the dry-run mode, it only prints the path.
+    pub fn write(&self, path: &Path, contents: impl AsRef<[u8]>) {
+        let contents = contents.as_ref();
+        if self.dry_run {
+            println!(
+                "INFO: Would write '{}' ({} bytes)",
+                path.display(),
+                contents.len()
+            );
+            return;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_from_str() {
+        for &step in Step::ALL {
+            assert_eq!(step.to_string().parse::<Step>(), Ok(step));
+        }
+        assert!("lib".parse::<Step>().is_err());
+    }
+
+    #[test]
+    fn test_output() {
+        let dir = std::env::temp_dir().join(format!("codegen-output-{}", std::process::id()));
+        let output = Output::new(&dir);
+        assert_eq!(
+            output.rasterized_bytes_dir(),
+            dir.join("src/res_rasterized_characters")
+        );
+
+        let file = output.src_dir().join("regular/mod.rs");
+        output
+            .clone()
+            .with_dry_run(true)
+            .write(&file, "mod size_16;");
+        assert!(!file.exists());
+
+        output.write(&file, "mod size_16;");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "mod size_16;");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
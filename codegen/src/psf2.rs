@@ -0,0 +1,171 @@
+//! Export of rasterized characters as PSF2 console fonts, as consumed by `setfont`
+//! for the Linux kernel console and by many hobby operating systems.
+//!
+//! Format: <https://www.win.tue.nl/~aeb/linux/kbd/font-formats-1.html>
+
+use crate::SUPPORTED_UNICODE_RANGES;
+use crate::font::RasterizationInfo;
+use crate::monochrome::Monochrome;
+
+/// Magic bytes at the beginning of each PSF2 file.
+pub const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+/// Size of the PSF2 header in bytes.
+const PSF2_HEADER_SIZE: u32 = 32;
+/// Header flag that indicates that the file contains a unicode translation table.
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+/// Terminates the unicode sequences of one glyph in the unicode translation table.
+const PSF2_SEPARATOR: u8 = 0xff;
+
+/// Number of leading glyphs whose index equals their code point. Simple consumers
+/// without support for the unicode translation table can index them directly.
+const IDENTITY_MAPPED_GLYPHS: usize = 256;
+
+/// A glyph of a PSF2 font with all code points that it represents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Psf2Glyph {
+    /// Code points that map to this glyph. Empty for unused glyph slots.
+    pub chars: Vec<char>,
+    /// Rows of pixels that are on (`true`) or off.
+    pub pixels: Vec<Vec<bool>>,
+}
+
+/// Creates a PSF2 font with all characters of [`SUPPORTED_UNICODE_RANGES`]
+/// rasterized by `font`, converted to 1 bit per pixel with `mode`.
+///
+/// The glyphs of the code points `0..256` are at the index of their code point.
+/// Unsupported code points in that range, such as control characters, get an
+/// empty glyph. All other characters follow afterwards.
+pub fn psf2_from_font(font: &RasterizationInfo, mode: Monochrome) -> Vec<u8> {
+    let empty_glyph = || Psf2Glyph {
+        chars: Vec::new(),
+        pixels: vec![vec![false; font.raster_width()]; font.raster_height()],
+    };
+
+    let mut glyphs = (0..IDENTITY_MAPPED_GLYPHS)
+        .map(|_| empty_glyph())
+        .collect::<Vec<_>>();
+    for c in SUPPORTED_UNICODE_RANGES
+        .iter()
+        .flat_map(|range| range.iter())
+    {
+        let glyph = Psf2Glyph {
+            chars: vec![c],
+            pixels: mode.convert(&font.rasterize(c)),
+        };
+        match glyphs.get_mut(c as usize) {
+            Some(slot) => *slot = glyph,
+            None => glyphs.push(glyph),
+        }
+    }
+
+    encode_psf2(font.raster_width(), font.raster_height(), &glyphs)
+}
+
+/// Encodes glyphs of `width` x `height` pixels as a PSF2 font with a unicode
+/// translation table.
+pub fn encode_psf2(width: usize, height: usize, glyphs: &[Psf2Glyph]) -> Vec<u8> {
+    let bytes_per_row = width.div_ceil(8);
+    let bytes_per_glyph = bytes_per_row * height;
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&PSF2_MAGIC);
+    for field in [
+        0, // version
+        PSF2_HEADER_SIZE,
+        PSF2_HAS_UNICODE_TABLE,
+        glyphs.len() as u32,
+        bytes_per_glyph as u32,
+        height as u32,
+        width as u32,
+    ] {
+        bytes.extend_from_slice(&field.to_le_bytes());
+    }
+
+    // bitmaps: one row after another, the most significant bit is the leftmost pixel
+    for glyph in glyphs {
+        assert_eq!(glyph.pixels.len(), height);
+        for row in &glyph.pixels {
+            assert_eq!(row.len(), width);
+            let mut row_bytes = vec![0_u8; bytes_per_row];
+            for (x, _) in row.iter().enumerate().filter(|(_, on)| **on) {
+                row_bytes[x / 8] |= 0x80 >> (x % 8);
+            }
+            bytes.extend_from_slice(&row_bytes);
+        }
+    }
+
+    // unicode translation table: the UTF-8 encoded code points of each glyph
+    for glyph in glyphs {
+        for c in &glyph.chars {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        }
+        bytes.push(PSF2_SEPARATOR);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::{FontWeight, FontWeightName, noto_font_by_weight};
+
+    fn header_field(bytes: &[u8], i: usize) -> u32 {
+        u32::from_le_bytes(bytes[4 + 4 * i..8 + 4 * i].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_encode_psf2() {
+        let glyph = Psf2Glyph {
+            chars: vec!['a', 'ä'],
+            pixels: vec![
+                vec![true, false, false, false, false, false, false, false, true],
+                vec![false; 9],
+            ],
+        };
+        let bytes = encode_psf2(9, 2, &[glyph]);
+
+        assert_eq!(bytes[0..4], PSF2_MAGIC);
+        assert_eq!(header_field(&bytes, 1), PSF2_HEADER_SIZE);
+        assert_eq!(header_field(&bytes, 2), PSF2_HAS_UNICODE_TABLE);
+        assert_eq!(header_field(&bytes, 3), 1);
+        assert_eq!(header_field(&bytes, 4), 4);
+        assert_eq!(header_field(&bytes, 5), 2);
+        assert_eq!(header_field(&bytes, 6), 9);
+        assert_eq!(bytes[32..36], [0x80, 0x80, 0, 0]);
+        assert_eq!(bytes[36..], [b'a', 0xc3, 0xa4, PSF2_SEPARATOR]);
+    }
+
+    #[test]
+    fn test_psf2_from_font() {
+        let weight = FontWeight::new(FontWeightName::Regular, false);
+        let font = RasterizationInfo::new(16, noto_font_by_weight(&weight));
+        let bytes = psf2_from_font(&font, Monochrome::default());
+
+        let glyph_count = header_field(&bytes, 3) as usize;
+        let bytes_per_glyph = header_field(&bytes, 4) as usize;
+        let expected_count = IDENTITY_MAPPED_GLYPHS
+            + SUPPORTED_UNICODE_RANGES
+                .iter()
+                .flat_map(|range| range.iter())
+                .filter(|&c| c as usize >= IDENTITY_MAPPED_GLYPHS)
+                .count();
+        assert_eq!(glyph_count, expected_count);
+        assert_eq!(bytes_per_glyph, 16);
+
+        // the glyph of 'A' is at the index of its code point
+        let a = &bytes[32 + 'A' as usize * bytes_per_glyph..][..bytes_per_glyph];
+        assert!(a.iter().any(|&row| row != 0));
+        let space = &bytes[32 + ' ' as usize * bytes_per_glyph..][..bytes_per_glyph];
+        assert!(space.iter().all(|&row| row == 0));
+
+        // the unicode table has one entry per glyph
+        let table = &bytes[32 + glyph_count * bytes_per_glyph..];
+        let entries = table.split(|&b| b == PSF2_SEPARATOR).collect::<Vec<_>>();
+        // the table ends with a separator, hence, there is an empty last entry
+        assert_eq!(entries.len(), glyph_count + 1);
+        assert_eq!(entries['A' as usize], b"A");
+        assert_eq!(entries[0], b"");
+    }
+}
@@ -89,6 +89,21 @@ impl UnicodeRange {
         self.feature_name
     }
 
+    /// Returns the contiguous ranges of all characters of the range without the
+    /// ignored symbols.
+    pub fn supported_ranges(&self) -> Vec<RangeInclusive<char>> {
+        let mut ranges = Vec::<RangeInclusive<char>>::new();
+        for c in self.iter() {
+            match ranges.last_mut() {
+                Some(range) if *range.end() as u32 + 1 == c as u32 => {
+                    *range = *range.start()..=c;
+                }
+                _ => ranges.push(c..=c),
+            }
+        }
+        ranges
+    }
+
     /// Returns true if the symbol is in one of the specified ranges of ignored symbols.
     fn symbol_is_ignored(&self, symbol: u32) -> bool {
         self.ignored_symbols
@@ -251,6 +266,16 @@ mod tests {
         assert_eq!(iter.last().unwrap(), '�');
     }
 
+    #[test]
+    fn test_supported_ranges() {
+        assert_eq!(ranges::BASIC_LATIN.supported_ranges(), [' '..='~']);
+        assert_eq!(
+            ranges::LATIN_1_SUPPLEMENT.supported_ranges(),
+            ['¡'..='¬', '®'..='ÿ']
+        );
+        assert_eq!(ranges::SPECIALS.supported_ranges(), ['�'..='�']);
+    }
+
     #[test]
     fn test_unicode_range_iter_skip_control_sequences() {
         // ASCII code range
@@ -0,0 +1,135 @@
+//! Tests of the command line interface of the codegen, which generates the crate into
+//! temporary directories.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Runs the codegen binary with `args`.
+fn codegen(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_codegen"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Returns a new temporary directory, which doesn't exist yet.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("codegen-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_steps() {
+    let dir = temp_dir("steps");
+    let output = codegen(&[
+        "--out-dir",
+        dir.to_str().unwrap(),
+        "--steps",
+        "Cargo.toml,lib.rs",
+    ]);
+    assert!(output.status.success(), "{output:?}");
+
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("Cargo.toml")).unwrap(),
+        std::fs::read_to_string(repo.join("Cargo.toml")).unwrap()
+    );
+    assert!(dir.join("src/lib.rs").is_file());
+    assert!(dir.join("src/tests.rs").is_file());
+    // the glyphs step was not selected
+    assert!(!dir.join("src/regular").exists());
+    assert!(!dir.join("src/res_rasterized_characters").exists());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_out_dir_compiles() {
+    let dir = temp_dir("out-dir");
+    let output = codegen(&["--out-dir", dir.to_str().unwrap()]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(dir.join("src/measure.rs").is_file());
+    assert!(dir.join("examples/minimal.rs").is_file());
+
+    // the same versions of the dependencies as in the repository
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    std::fs::copy(repo.join("Cargo.lock"), dir.join("Cargo.lock")).unwrap();
+    let check = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()))
+        .arg("check")
+        .current_dir(&dir)
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("out-dir"),
+        )
+        .output()
+        .unwrap();
+    assert!(check.status.success(), "{check:?}");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_dry_run() {
+    let dir = temp_dir("dry-run");
+    let output = codegen(&["--out-dir", dir.to_str().unwrap(), "--dry-run"]);
+    assert!(output.status.success(), "{output:?}");
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let cargo_toml = dir.join("Cargo.toml");
+    assert!(stdout.contains(&format!("Would write '{}'", cargo_toml.display())));
+    assert!(stdout.contains("regular/size_16.rs"));
+    assert!(stdout.contains("h16_wRegular_unicode-basic-latin.bin"));
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_hinting() {
+    let file = "src/res_rasterized_characters/h16_wRegular_unicode-basic-latin.bin";
+    let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let rasters = ["none", "grid-fit"].map(|hinting| {
+        let dir = temp_dir(&format!("hinting-{hinting}"));
+        let args = ["--out-dir", dir.to_str().unwrap(), "--steps", "glyphs"];
+        let output = codegen(&[&args[..], &["--hinting", hinting]].concat());
+        assert!(output.status.success(), "{output:?}");
+        let rasters = std::fs::read(dir.join(file)).unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+        rasters
+    });
+
+    // the repository is generated without hinting
+    assert_eq!(rasters[0], std::fs::read(repo.join(file)).unwrap());
+    assert_ne!(rasters[0], rasters[1]);
+}
+
+#[test]
+fn test_export_hinting() {
+    let fonts = ["none", "grid-fit"].map(|hinting| {
+        let dir = temp_dir(&format!("export-hinting-{hinting}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("font.psf");
+        let args = ["psf2", "regular", "16", file.to_str().unwrap()];
+        let output = codegen(&[&args[..], &["--hinting", hinting]].concat());
+        assert!(output.status.success(), "{output:?}");
+        let font = std::fs::read(&file).unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+        font
+    });
+
+    // the exported glyphs match the hinting of the generated crate
+    assert_ne!(fonts[0], fonts[1]);
+}
+
+#[test]
+fn test_invalid_args() {
+    assert!(!codegen(&["--steps", "glyph"]).status.success());
+    assert!(!codegen(&["--hinting", "gridfit"]).status.success());
+    assert!(!codegen(&["--out-dir"]).status.success());
+    assert!(!codegen(&["--unknown"]).status.success());
+    // the options of the output are only available for the generation of the library
+    assert!(
+        !codegen(&["psf2", "regular", "16", "font.psf", "--dry-run"])
+            .status
+            .success()
+    );
+}
@@ -0,0 +1,63 @@
+//! Exports a font blob of all font weights and raster heights with the codegen and
+//! checks that `FontBlob` of the library returns the same rasters and metrics as
+//! the generated library.
+
+use codegen::blob::blob_from_fonts;
+use codegen::font::{RasterizationInfo, SUPPORTED_FONT_WEIGHTS, noto_font_by_weight};
+use codegen::{HINTING, SUPPORTED_RASTER_HEIGHTS};
+use noto_sans_mono_bitmap::{
+    FontBlob, FontWeight, ParseBlobError, RasterHeight, get_baseline, get_decoration_metrics,
+    get_raster, get_raster_width, supported_chars,
+};
+
+#[test]
+fn test_blob_matches_library() {
+    let fonts = SUPPORTED_FONT_WEIGHTS
+        .iter()
+        .flat_map(|weight| {
+            SUPPORTED_RASTER_HEIGHTS.iter().map(move |size| {
+                let font =
+                    RasterizationInfo::new(size.value() as usize, noto_font_by_weight(weight))
+                        .with_hinting(HINTING);
+                (weight, font)
+            })
+        })
+        .collect::<Vec<_>>();
+    let bytes = blob_from_fonts(&fonts);
+    let blob = FontBlob::parse(&bytes).unwrap();
+
+    assert!(blob.chars().eq(supported_chars()));
+    assert_eq!(
+        blob.fonts().len(),
+        FontWeight::ALL.len() * RasterHeight::ALL.len()
+    );
+    for &style in FontWeight::ALL {
+        for &size in RasterHeight::ALL {
+            let font = blob.font(style, size).unwrap();
+            assert_eq!(font.width(), get_raster_width(style, size));
+            assert_eq!(font.baseline(), get_baseline(style, size));
+            assert_eq!(
+                font.decoration_metrics(),
+                get_decoration_metrics(style, size)
+            );
+
+            for c in supported_chars() {
+                let expected = get_raster(c, style, size).unwrap();
+                let raster = font.get_raster(c).unwrap();
+                assert!(
+                    raster.rows().eq(expected.raster().iter().copied()),
+                    "{c:?} {style:?} {size:?}"
+                );
+            }
+            assert!(font.get_raster('\u{E000}').is_none());
+        }
+    }
+
+    let mut corrupt = bytes;
+    let last = corrupt.len() - 1;
+    corrupt[last] ^= 1;
+    assert_eq!(
+        FontBlob::parse(&corrupt).unwrap_err(),
+        ParseBlobError::ChecksumMismatch
+    );
+}
@@ -0,0 +1,292 @@
+//! Golden snapshot test for the rasterization of all characters.
+//!
+//! The checked-in manifest `tests/raster_snapshots.txt` contains a hash of each
+//! raster per weight, raster height, and character. The test rasterizes all
+//! characters freshly, as the codegen does. When a raster changes, e.g., because
+//! a dependency bump of fontdue or ttf-parser silently changes the glyph output,
+//! this test fails with a per-character diff and an ASCII-art rendering of the
+//! old raster in `src/res_rasterized_characters` and the new raster.
+//!
+//! If a change is intended, regenerate the crate and update the manifest with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test raster_snapshots`.
+
+use codegen::font::{RasterizationInfo, SUPPORTED_FONT_WEIGHTS, noto_font_by_weight};
+use codegen::intern::InternedRasters;
+use codegen::output::Output;
+use codegen::unicode::UnicodeRange;
+use codegen::{DEFAULT_OUTPUT_DIR, HINTING, SUPPORTED_RASTER_HEIGHTS, SUPPORTED_UNICODE_RANGES};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+const MANIFEST_PATH: &str = "tests/raster_snapshots.txt";
+/// Changed rasters beyond this number are only listed but not rendered.
+const MAX_RENDERED_DIFFS: usize = 10;
+/// Characters for the ASCII-art rendering, from low to high intensity.
+const ASCII_RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Identifies a generated raster.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    weight: String,
+    height: u32,
+    subpixel: bool,
+    c: u32,
+}
+
+impl Key {
+    /// Parses a line of the manifest without the hash.
+    fn from_manifest(line: &str) -> Option<Self> {
+        let mut parts = line.split_whitespace();
+        let weight = parts.next()?.to_string();
+        let height = parts.next()?.parse().ok()?;
+        let c = u32::from_str_radix(parts.next()?.strip_prefix("U+")?, 16).ok()?;
+        let subpixel = match parts.next() {
+            Some("subpixel") => true,
+            None => false,
+            Some(_) => return None,
+        };
+        Some(Self {
+            weight,
+            height,
+            subpixel,
+            c,
+        })
+    }
+}
+
+/// Identifies a generated file with the rasters of a unicode range.
+#[derive(Debug)]
+struct RangeFile {
+    weight: String,
+    height: u32,
+    range: &'static UnicodeRange,
+    subpixel: bool,
+}
+
+impl RangeFile {
+    /// Parses file names such as `h16_wRegular_unicode-basic-latin.bin` or
+    /// `h16_wRegular_unicode-basic-latin_subpixel.bin`. Returns `None` for other files,
+    /// such as index files or the run-length encoded rasters of the `compressed`
+    /// feature.
+    fn from_file_name(name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(".bin")?;
+        let (stem, subpixel) = match stem.strip_suffix("_subpixel") {
+            Some(stem) => (stem, true),
+            None => (stem, false),
+        };
+        let mut parts = stem.splitn(3, '_');
+        let height = parts.next()?.strip_prefix('h')?.parse().ok()?;
+        let weight = parts.next()?.strip_prefix('w')?.to_string();
+        let feature_name = parts.next()?;
+        let range = SUPPORTED_UNICODE_RANGES
+            .iter()
+            .find(|r| r.feature_name == feature_name)?;
+        Some(Self {
+            weight,
+            height,
+            range,
+            subpixel,
+        })
+    }
+
+    fn file_name(&self) -> String {
+        let suffix = if self.subpixel { "_subpixel" } else { "" };
+        format!(
+            "h{}_w{}_{}{suffix}.bin",
+            self.height, self.weight, self.range.feature_name
+        )
+    }
+
+    fn key(&self, c: char) -> Key {
+        Key {
+            weight: self.weight.clone(),
+            height: self.height,
+            subpixel: self.subpixel,
+            c: u32::from(c),
+        }
+    }
+
+    fn index_file_name(&self) -> String {
+        self.file_name().replace(".bin", "_index.bin")
+    }
+
+    /// Restores the rasters of the characters of the unicode range from the content
+    /// of the file and of its index file.
+    fn parse_rasters(&self, rows: &[u8], index: &[u8]) -> Vec<(char, Vec<Vec<u8>>)> {
+        let chars = self.range.iter().collect::<Vec<_>>();
+        let rasters =
+            InternedRasters::rasters_from_bytes(rows, index, chars.len(), self.height as usize);
+        chars.into_iter().zip(rasters).collect()
+    }
+}
+
+/// The format of a manifest line without the hash.
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} U+{:04X}", self.weight, self.height, self.c)?;
+        if self.subpixel {
+            write!(f, " subpixel")?;
+        }
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a hash of the dimensions and intensities of a raster.
+fn hash_raster(raster: &[Vec<u8>]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    let dimensions = [raster.len(), raster.first().map_or(0, Vec::len)];
+    let bytes = dimensions
+        .iter()
+        .flat_map(|d| (*d as u32).to_le_bytes())
+        .chain(raster.iter().flatten().copied());
+    for byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Rasterizes all characters of all weights and raster heights like the codegen.
+fn rasterize_all() -> BTreeMap<Key, Vec<Vec<u8>>> {
+    let mut rasters = BTreeMap::new();
+    for weight in SUPPORTED_FONT_WEIGHTS {
+        for height in SUPPORTED_RASTER_HEIGHTS {
+            let font = RasterizationInfo::new(height.value() as usize, noto_font_by_weight(weight))
+                .with_hinting(HINTING);
+            for c in SUPPORTED_UNICODE_RANGES.iter().flat_map(UnicodeRange::iter) {
+                for subpixel in [false, true] {
+                    let key = Key {
+                        weight: format!("{:?}", weight.name()),
+                        height: height.value(),
+                        subpixel,
+                        c: u32::from(c),
+                    };
+                    let raster = if subpixel {
+                        font.rasterize_subpixel(c)
+                    } else {
+                        font.rasterize(c)
+                    };
+                    rasters.insert(key, raster);
+                }
+            }
+        }
+    }
+    rasters
+}
+
+/// Reads the checked-in rasters in `src/res_rasterized_characters`.
+fn read_checked_in_rasters() -> BTreeMap<Key, Vec<Vec<u8>>> {
+    let dir = Output::new(DEFAULT_OUTPUT_DIR).rasterized_bytes_dir();
+    std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter_map(|name| RangeFile::from_file_name(&name))
+        .flat_map(|file| {
+            let rows = std::fs::read(dir.join(file.file_name())).unwrap();
+            let index = std::fs::read(dir.join(file.index_file_name())).unwrap();
+            let rasters = file.parse_rasters(&rows, &index);
+            rasters
+                .into_iter()
+                .map(move |(c, raster)| (file.key(c), raster))
+        })
+        .collect()
+}
+
+fn read_manifest() -> BTreeMap<Key, u64> {
+    let manifest = std::fs::read_to_string(MANIFEST_PATH).unwrap_or_default();
+    manifest
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (key, hash) = line.rsplit_once(' ').unwrap();
+            let key = Key::from_manifest(key).unwrap_or_else(|| panic!("invalid line: {line}"));
+            (key, u64::from_str_radix(hash, 16).unwrap())
+        })
+        .collect()
+}
+
+fn write_manifest(rasters: &BTreeMap<Key, Vec<Vec<u8>>>) {
+    let mut manifest = String::from(
+        "# Generated by `UPDATE_SNAPSHOTS=1 cargo test --test raster_snapshots`.\n\
+         # weight, raster height, code point, [subpixel], FNV-1a hash of the raster\n",
+    );
+    for (key, raster) in rasters {
+        manifest += &format!("{key} {:016x}\n", hash_raster(raster));
+    }
+    std::fs::write(MANIFEST_PATH, manifest).unwrap();
+}
+
+fn ascii_art(raster: &[Vec<u8>]) -> Vec<String> {
+    raster
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&i| ASCII_RAMP[i as usize * ASCII_RAMP.len() / 256] as char)
+                .collect()
+        })
+        .collect()
+}
+
+/// Renders the old and the new raster side by side.
+fn render_diff(old: Option<&[Vec<u8>]>, new: &[Vec<u8>]) -> String {
+    let old = old.map(ascii_art).unwrap_or_default();
+    let new = ascii_art(new);
+    let width = old.iter().map(String::len).max().unwrap_or(3).max(3);
+    let mut out = format!("    {:width$}   new\n", "old");
+    for i in 0..old.len().max(new.len()) {
+        let old_row = old.get(i).map_or("", String::as_str);
+        let new_row = new.get(i).map_or("", String::as_str);
+        out += &format!("    {old_row:width$} | {new_row}\n");
+    }
+    out
+}
+
+#[test]
+fn raster_snapshots() {
+    let rasters = rasterize_all();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        write_manifest(&rasters);
+        return;
+    }
+
+    let manifest = read_manifest();
+    let checked_in = read_checked_in_rasters();
+    let mut report = String::new();
+    let mut changed = 0;
+    for (key, raster) in &rasters {
+        match manifest.get(key) {
+            None => report += &format!("+ {key}: new raster\n"),
+            Some(&hash) if hash != hash_raster(raster) => {
+                changed += 1;
+                let old = checked_in.get(key);
+                report += &format!("~ {key}: changed");
+                if let Some(old) = old {
+                    let differing = old
+                        .iter()
+                        .flatten()
+                        .zip(raster.iter().flatten())
+                        .filter(|(a, b)| a != b)
+                        .count();
+                    report +=
+                        &format!(" ({differing} pixels differ from src/res_rasterized_characters)");
+                }
+                report += "\n";
+                if changed <= MAX_RENDERED_DIFFS {
+                    report += &render_diff(old.map(Vec::as_slice), raster);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    for key in manifest.keys().filter(|key| !rasters.contains_key(key)) {
+        report += &format!("- {key}: removed raster\n");
+    }
+
+    assert!(
+        report.is_empty(),
+        "generated rasters differ from the snapshots in {MANIFEST_PATH}:\n{report}\n\
+         If this is intended, update the snapshots with \
+         `UPDATE_SNAPSHOTS=1 cargo test --test raster_snapshots`."
+    );
+}
@@ -0,0 +1,82 @@
+//! Compares the synthetic font weights of the library, i.e., `Raster::embolden` and
+//! `Raster::thin` applied to the regular font weight, with the real bold and light
+//! font weights. This documents the quality tradeoff of the synthetic font weights.
+//!
+//! Run with `cargo test --test synthetic_weights -- --nocapture` to see the numbers.
+
+use noto_sans_mono_bitmap::{FontWeight, Raster, RasterHeight, get_raster};
+
+/// A full pixel of emboldening overshoots the bold font weight at small raster
+/// heights, hence, a fraction is closer on average.
+const EMBOLDEN_AMOUNT: u8 = 96;
+const THIN_AMOUNT: u8 = 128;
+
+const RASTER_HEIGHTS: [RasterHeight; 4] = [
+    RasterHeight::Size16,
+    RasterHeight::Size20,
+    RasterHeight::Size24,
+    RasterHeight::Size32,
+];
+
+/// Returns the mean absolute difference per pixel between two rasters of all
+/// printable ASCII characters.
+fn mean_difference(
+    size: RasterHeight,
+    synthetic: impl Fn(char) -> Option<Box<dyn Raster>>,
+    reference: FontWeight,
+) -> f64 {
+    let (mut sum, mut pixels) = (0_u64, 0_u64);
+    for c in (' '..='~').filter(|c| !c.is_whitespace()) {
+        let synthetic = synthetic(c).unwrap();
+        let reference = get_raster(c, reference, size).unwrap();
+        for y in 0..reference.height() {
+            for x in 0..reference.width() {
+                sum += (synthetic.pixel(x, y) as i64 - reference.pixel(x, y) as i64).unsigned_abs();
+                pixels += 1;
+            }
+        }
+    }
+    sum as f64 / pixels as f64
+}
+
+fn regular(c: char, size: RasterHeight) -> Option<noto_sans_mono_bitmap::RasterizedChar> {
+    get_raster(c, FontWeight::Regular, size)
+}
+
+#[test]
+fn compare_synthetic_bold_with_bold() {
+    println!("raster height | regular vs bold | synthetic bold vs bold");
+    for size in RASTER_HEIGHTS {
+        let plain = mean_difference(
+            size,
+            |c| regular(c, size).map(|r| Box::new(r) as Box<dyn Raster>),
+            FontWeight::Bold,
+        );
+        let synthetic = mean_difference(
+            size,
+            |c| regular(c, size).map(|r| Box::new(r.embolden(EMBOLDEN_AMOUNT)) as Box<dyn Raster>),
+            FontWeight::Bold,
+        );
+        println!("{:>13} | {plain:>15.2} | {synthetic:>22.2}", size.val());
+        assert!(synthetic < plain);
+    }
+}
+
+#[test]
+fn compare_synthetic_light_with_light() {
+    println!("raster height | regular vs light | synthetic light vs light");
+    for size in RASTER_HEIGHTS {
+        let plain = mean_difference(
+            size,
+            |c| regular(c, size).map(|r| Box::new(r) as Box<dyn Raster>),
+            FontWeight::Light,
+        );
+        let synthetic = mean_difference(
+            size,
+            |c| regular(c, size).map(|r| Box::new(r.thin(THIN_AMOUNT)) as Box<dyn Raster>),
+            FontWeight::Light,
+        );
+        println!("{:>13} | {plain:>16.2} | {synthetic:>24.2}", size.val());
+        assert!(synthetic < plain);
+    }
+}
@@ -13,7 +13,7 @@ cd "$DIR" || exit
 echo "This script generates the crate 'noto-sans-mono-bitmap', verifies the build, and applies Rustfmt and clippy afterwards."
 
 # Delete all generated raster files from previous run
-find src/res_rasterized_characters -type f -name "*.txt" -exec rm {} +
+find src/res_rasterized_characters -type f -name "*.bin" -exec rm {} +
 
 cd "codegen" || exit
 if [ "$1" != "--ci" ]; then
@@ -24,6 +24,8 @@ fi
 cargo fmt
 cargo test
 RUSTFLAGS="-C target-cpu=native" cargo run --release --bin codegen
+# Detects rasters that changed, e.g., by a dependency update, with a readable diff.
+cargo test --test raster_snapshots
 cd ..
 
 # For simplifications, the generated code doesn't care too much about
@@ -0,0 +1,41 @@
+use noto_sans_mono_bitmap::{RasterHeight, FontWeight};
+
+// Same as `check_size_all_features` but with the run-length encoded rasters of
+// the "compressed" feature. Thus, the compiler can discard the raw rasters.
+fn main() {
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+    let char = line.chars().next().unwrap();
+
+    let raster = noto_sans_mono_bitmap::get_raster_compressed(char, select_font_weight(), select_font_size()).unwrap();
+    for row in raster.rows() {
+        println!("{:?}", &*row);
+    }
+}
+
+fn select_font_weight() -> FontWeight {
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+    let char = line.chars().next().unwrap();
+
+    match char {
+        'a' => FontWeight::Light,
+        'b' => FontWeight::Regular,
+        'c' => FontWeight::Bold,
+        _ => panic!()
+    }
+}
+
+fn select_font_size() -> RasterHeight {
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+    let char = line.chars().next().unwrap();
+
+    match char {
+        'a' => RasterHeight::Size16,
+        'c' => RasterHeight::Size20,
+        'e' => RasterHeight::Size24,
+        'f' => RasterHeight::Size32,
+        _ => panic!()
+    }
+}
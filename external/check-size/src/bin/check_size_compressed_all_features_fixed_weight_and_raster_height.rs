@@ -0,0 +1,14 @@
+use noto_sans_mono_bitmap::{RasterHeight, FontWeight};
+
+// Same as `check_size_all_features_fixed_weight_and_raster_height` but with the
+// run-length encoded rasters of the "compressed" feature.
+fn main() {
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+    let char = line.chars().next().unwrap();
+
+    let raster = noto_sans_mono_bitmap::get_raster_compressed(char, FontWeight::Regular, RasterHeight::Size16).unwrap();
+    for row in raster.rows() {
+        println!("{:?}", &*row);
+    }
+}
@@ -0,0 +1,61 @@
+use noto_sans_mono_bitmap::{
+    FontWeight, RasterHeight, get_raster, get_raster_compressed, supported_chars,
+    MAX_RASTER_HEIGHT, MAX_RASTER_WIDTH,
+};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 200;
+
+// Compares how fast whole rasters can be read from the raw format of `get_raster()`
+// and decoded from the run-length encoded format of `get_raster_compressed()`.
+// Run it with `cargo run --release --bin decode_throughput`.
+fn main() {
+    for &size in RasterHeight::ALL {
+        let weight = FontWeight::Regular;
+        let chars = supported_chars().collect::<Vec<_>>();
+        let mut buf = [0; MAX_RASTER_WIDTH * MAX_RASTER_HEIGHT];
+
+        let raw = measure(|| {
+            for &c in &chars {
+                let raster = get_raster(black_box(c), weight, size).unwrap();
+                for (y, row) in raster.raster().iter().enumerate() {
+                    buf[y * row.len()..][..row.len()].copy_from_slice(row);
+                }
+                black_box(&buf);
+            }
+        });
+        let rows = measure(|| {
+            for &c in &chars {
+                let raster = get_raster_compressed(black_box(c), weight, size).unwrap();
+                for row in raster.rows() {
+                    black_box(&*row);
+                }
+            }
+        });
+        let decode_into = measure(|| {
+            for &c in &chars {
+                let raster = get_raster_compressed(black_box(c), weight, size).unwrap();
+                raster.decode_into(&mut buf);
+                black_box(&buf);
+            }
+        });
+
+        let glyphs = (ROUNDS * chars.len()) as f64;
+        println!("{size:?}:");
+        for (name, duration) in [("raw", raw), ("compressed rows()", rows), ("compressed decode_into()", decode_into)] {
+            println!(
+                "  {name:<25} {:>6.1} M glyphs/s",
+                glyphs / duration.as_secs_f64() / 1e6
+            );
+        }
+    }
+}
+
+fn measure(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed()
+}
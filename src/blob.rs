@@ -0,0 +1,581 @@
+//! Module for font blobs, a binary format that contains the rasters of multiple
+//! font weights and raster heights.
+//!
+//! Bootloaders and kernels can load a blob from disk at runtime instead of linking
+//! the glyphs into the binary. Blobs are created with `codegen blob <file>`, see
+//! `codegen/src/blob.rs` in the repository for the format.
+
+use crate::{DecorationMetrics, FontWeight, Raster, RasterHeight};
+use core::fmt::{Display, Formatter};
+
+/// Magic bytes at the beginning of each font blob.
+const MAGIC: [u8; 4] = *b"NSMB";
+/// Version of the format that [`FontBlob::parse`] reads.
+const VERSION: u16 = 1;
+/// Size of the header in bytes.
+const HEADER_SIZE: usize = 16;
+/// Offset of the checksum in the header.
+const CHECKSUM_OFFSET: usize = 12;
+/// Size of an entry of the font table in bytes.
+const FONT_ENTRY_SIZE: usize = 36;
+/// Size of the name of the font weight in a font table entry.
+const WEIGHT_NAME_SIZE: usize = 16;
+
+/// Lookup table of the CRC-32 (ISO-HDLC, as used by zlib).
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < table.len() {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Continues the CRC-32 `crc` with `bytes`. Start with `!0` and invert the result.
+fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, &byte| {
+        CRC32_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Reads a little-endian `u16` at `offset`. The caller checks the bounds.
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+/// Reads a little-endian `u32` at `offset`. The caller checks the bounds.
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+/// Errors of [`FontBlob::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBlobError {
+    /// The data doesn't start with the magic bytes of a font blob.
+    InvalidMagic,
+    /// The blob has a version of the format that is not supported.
+    UnsupportedVersion(u16),
+    /// The checksum doesn't match, i.e., the blob is corrupt.
+    ChecksumMismatch,
+    /// The blob is shorter than its header and tables claim.
+    Truncated,
+    /// An entry of the font table is invalid.
+    InvalidFont,
+    /// The code points in the index are invalid or not sorted.
+    InvalidIndex,
+}
+
+impl Display for ParseBlobError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("not a font blob"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported font blob version {version}")
+            }
+            Self::ChecksumMismatch => f.write_str("checksum mismatch"),
+            Self::Truncated => f.write_str("truncated font blob"),
+            Self::InvalidFont => f.write_str("invalid font table entry"),
+            Self::InvalidIndex => f.write_str("invalid or unsorted code points"),
+        }
+    }
+}
+
+impl core::error::Error for ParseBlobError {}
+
+/// A validated font blob that references the rasters in the underlying bytes
+/// without copying them.
+///
+/// [`FontBlob::parse`] checks the checksum and all offsets and sizes once. All
+/// lookups afterwards can't fail due to corrupt data and need no allocations.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{FontBlob, FontWeight, RasterHeight};
+///
+/// fn print_a(bytes: &[u8]) {
+///     let blob = FontBlob::parse(bytes).unwrap();
+///     let raster = blob.get_raster('A', FontWeight::Regular, RasterHeight::Size16);
+///     for row in raster.unwrap().rows() {
+///         // ...
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FontBlob<'a> {
+    fonts: &'a [u8],
+    index: &'a [u8],
+    bytes: &'a [u8],
+}
+
+impl<'a> FontBlob<'a> {
+    /// Parses and validates a font blob.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ParseBlobError> {
+        if !bytes.starts_with(&MAGIC) {
+            return Err(ParseBlobError::InvalidMagic);
+        }
+        if bytes.len() < HEADER_SIZE {
+            return Err(ParseBlobError::Truncated);
+        }
+        let version = read_u16(bytes, 4);
+        if version != VERSION {
+            return Err(ParseBlobError::UnsupportedVersion(version));
+        }
+        let crc = crc32_update(!0, &bytes[..CHECKSUM_OFFSET]);
+        let crc = !crc32_update(crc, &bytes[CHECKSUM_OFFSET + 4..]);
+        if crc != read_u32(bytes, CHECKSUM_OFFSET) {
+            return Err(ParseBlobError::ChecksumMismatch);
+        }
+
+        let font_count = usize::from(read_u16(bytes, 6));
+        let char_count = read_u32(bytes, 8) as usize;
+        let fonts_end = HEADER_SIZE + FONT_ENTRY_SIZE * font_count;
+        let index_end = char_count
+            .checked_mul(4)
+            .and_then(|size| size.checked_add(fonts_end))
+            .filter(|&end| end <= bytes.len())
+            .ok_or(ParseBlobError::Truncated)?;
+
+        let blob = Self {
+            fonts: &bytes[HEADER_SIZE..fonts_end],
+            index: &bytes[fonts_end..index_end],
+            bytes,
+        };
+        blob.validate_index()?;
+        for i in 0..font_count {
+            blob.parse_font(i, index_end)?;
+        }
+        Ok(blob)
+    }
+
+    /// Checks that the index contains valid chars in strictly ascending order,
+    /// which is needed for the binary search.
+    fn validate_index(&self) -> Result<(), ParseBlobError> {
+        let mut previous = None;
+        for i in 0..self.char_count() {
+            let code_point = read_u32(self.index, 4 * i);
+            if char::from_u32(code_point).is_none() || previous >= Some(code_point) {
+                return Err(ParseBlobError::InvalidIndex);
+            }
+            previous = Some(code_point);
+        }
+        Ok(())
+    }
+
+    /// Parses entry `i` of the font table. The pixel data must start at or after
+    /// `data_start`.
+    fn parse_font(&self, i: usize, data_start: usize) -> Result<BlobFont<'a>, ParseBlobError> {
+        let entry = self.font_entry(i);
+        let weight = core::str::from_utf8(Self::weight_name(entry))
+            .map_err(|_| ParseBlobError::InvalidFont)?;
+
+        let field = |j: usize| usize::from(read_u16(entry, WEIGHT_NAME_SIZE + 2 * j));
+        let (height, width, baseline) = (field(0), field(1), field(2));
+        let metrics = DecorationMetrics {
+            underline_position: field(3),
+            underline_thickness: field(4),
+            strikeout_position: field(5),
+            strikeout_thickness: field(6),
+            overline_position: field(7),
+        };
+        let lines = [
+            (metrics.underline_position, metrics.underline_thickness),
+            (metrics.strikeout_position, metrics.strikeout_thickness),
+            (metrics.overline_position, metrics.underline_thickness),
+        ];
+        if width == 0
+            || height == 0
+            || baseline > height
+            || lines
+                .iter()
+                .any(|&(pos, thickness)| pos + thickness > height)
+        {
+            return Err(ParseBlobError::InvalidFont);
+        }
+
+        let offset = read_u32(entry, 32) as usize;
+        if offset < data_start {
+            return Err(ParseBlobError::InvalidFont);
+        }
+        let data = (width * height)
+            .checked_mul(self.char_count())
+            .and_then(|size| self.bytes.get(offset..offset.checked_add(size)?))
+            .ok_or(ParseBlobError::Truncated)?;
+
+        Ok(BlobFont {
+            weight,
+            height,
+            width,
+            baseline,
+            metrics,
+            index: self.index,
+            data,
+        })
+    }
+
+    /// Returns entry `i` of the font table.
+    fn font_entry(&self, i: usize) -> &'a [u8] {
+        &self.fonts[FONT_ENTRY_SIZE * i..][..FONT_ENTRY_SIZE]
+    }
+
+    /// Returns the name of the font weight of a font table entry without the
+    /// trailing zeros.
+    fn weight_name(entry: &[u8]) -> &[u8] {
+        let name = &entry[..WEIGHT_NAME_SIZE];
+        let name_len = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+        &name[..name_len]
+    }
+
+    /// Returns the number of chars in each font.
+    #[inline]
+    pub const fn char_count(&self) -> usize {
+        self.index.len() / 4
+    }
+
+    /// Returns all chars of the blob in ascending order.
+    pub fn chars(&self) -> impl ExactSizeIterator<Item = char> + 'a {
+        let index = self.index;
+        (0..index.len() / 4).map(move |i| {
+            // validated by `FontBlob::parse`
+            char::from_u32(read_u32(index, 4 * i)).unwrap()
+        })
+    }
+
+    /// Returns all fonts of the blob.
+    pub fn fonts(&self) -> impl ExactSizeIterator<Item = BlobFont<'a>> + 'a {
+        let blob = *self;
+        (0..self.fonts.len() / FONT_ENTRY_SIZE).map(move |i| {
+            // validated by `FontBlob::parse`
+            blob.parse_font(i, 0).unwrap()
+        })
+    }
+
+    /// Returns the font with the name of a font weight, such as `"bold"`, and a
+    /// raster height in pixels. This also works for font weights and raster
+    /// heights that are not enabled by Cargo features.
+    pub fn font_by_name(&self, weight: &str, height: usize) -> Option<BlobFont<'a>> {
+        // only the matching entry is parsed
+        let i = (0..self.fonts.len() / FONT_ENTRY_SIZE).find(|&i| {
+            let entry = self.font_entry(i);
+            usize::from(read_u16(entry, WEIGHT_NAME_SIZE)) == height
+                && Self::weight_name(entry) == weight.as_bytes()
+        })?;
+        // validated by `FontBlob::parse`
+        Some(self.parse_font(i, 0).unwrap())
+    }
+
+    /// Returns the font of the given [`FontWeight`] and [`RasterHeight`].
+    #[inline]
+    pub fn font(&self, style: FontWeight, size: RasterHeight) -> Option<BlobFont<'a>> {
+        self.font_by_name(style.name(), size.val())
+    }
+
+    /// Returns the raster of `c` in the given [`FontWeight`] and [`RasterHeight`],
+    /// like [`crate::get_raster`]. Returns `None`, if the blob doesn't contain the
+    /// font or the char.
+    pub fn get_raster(
+        &self,
+        c: char,
+        style: FontWeight,
+        size: RasterHeight,
+    ) -> Option<BlobRaster<'a>> {
+        self.font(style, size)?.get_raster(c)
+    }
+}
+
+/// A font of a [`FontBlob`], i.e., one font weight in one raster height.
+#[derive(Debug, Clone, Copy)]
+pub struct BlobFont<'a> {
+    weight: &'a str,
+    height: usize,
+    width: usize,
+    baseline: usize,
+    metrics: DecorationMetrics,
+    index: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> BlobFont<'a> {
+    /// Returns the name of the font weight, such as `"regular"`. See
+    /// [`FontWeight::name`].
+    #[inline]
+    pub const fn weight(&self) -> &'a str {
+        self.weight
+    }
+
+    /// Returns the raster height in pixels.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the raster width in pixels. See [`crate::get_raster_width`].
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the row of the baseline. See [`crate::get_baseline`].
+    #[inline]
+    pub const fn baseline(&self) -> usize {
+        self.baseline
+    }
+
+    /// Returns the metrics of text decorations. See
+    /// [`crate::get_decoration_metrics`].
+    #[inline]
+    pub const fn decoration_metrics(&self) -> DecorationMetrics {
+        self.metrics
+    }
+
+    /// Returns the raster of `c` or `None`, if the blob doesn't contain it.
+    pub fn get_raster(&self, c: char) -> Option<BlobRaster<'a>> {
+        let (mut low, mut high) = (0, self.index.len() / 4);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match read_u32(self.index, 4 * mid).cmp(&u32::from(c)) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => {
+                    let size = self.width * self.height;
+                    return Some(BlobRaster {
+                        pixels: &self.data[mid * size..][..size],
+                        width: self.width,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The raster of a char in a [`FontBlob`], which references the bytes of the blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlobRaster<'a> {
+    pixels: &'a [u8],
+    width: usize,
+}
+
+impl<'a> BlobRaster<'a> {
+    /// Returns all pixels, row by row.
+    #[inline]
+    pub const fn pixels(&self) -> &'a [u8] {
+        self.pixels
+    }
+
+    /// Returns the rows of the raster.
+    #[inline]
+    pub fn rows(&self) -> core::slice::ChunksExact<'a, u8> {
+        self.pixels.chunks_exact(self.width)
+    }
+}
+
+impl Raster for BlobRaster<'_> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    #[inline]
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width {
+            return 0;
+        }
+        self.pixels.get(y * self.width + x).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{get_baseline, get_decoration_metrics, get_raster, get_raster_width};
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+    const CHARS: [char; 2] = ['A', 'B'];
+    const INDEX_OFFSET: usize = HEADER_SIZE + FONT_ENTRY_SIZE;
+    const DATA_OFFSET: usize = INDEX_OFFSET + 4 * CHARS.len();
+    const BLOB_SIZE: usize = DATA_OFFSET + CHARS.len() * 7 * 16;
+
+    fn write(bytes: &mut [u8], offset: usize, value: &[u8]) {
+        bytes[offset..offset + value.len()].copy_from_slice(value);
+    }
+
+    fn update_checksum(bytes: &mut [u8]) {
+        let crc = crc32_update(!0, &bytes[..CHECKSUM_OFFSET]);
+        let crc = !crc32_update(crc, &bytes[CHECKSUM_OFFSET + 4..]);
+        write(bytes, CHECKSUM_OFFSET, &crc.to_le_bytes());
+    }
+
+    /// Creates a blob with the regular font of size 16 and the chars `CHARS`.
+    fn blob() -> [u8; BLOB_SIZE] {
+        let mut bytes = [0; BLOB_SIZE];
+        write(&mut bytes, 0, &MAGIC);
+        write(&mut bytes, 4, &VERSION.to_le_bytes());
+        write(&mut bytes, 6, &1_u16.to_le_bytes());
+        write(&mut bytes, 8, &(CHARS.len() as u32).to_le_bytes());
+
+        let metrics = get_decoration_metrics(WEIGHT, SIZE);
+        write(&mut bytes, HEADER_SIZE, b"regular");
+        let fields = [
+            SIZE.val(),
+            get_raster_width(WEIGHT, SIZE),
+            get_baseline(WEIGHT, SIZE),
+            metrics.underline_position(),
+            metrics.underline_thickness(),
+            metrics.strikeout_position(),
+            metrics.strikeout_thickness(),
+            metrics.overline_position(),
+        ];
+        for (i, field) in fields.into_iter().enumerate() {
+            write(
+                &mut bytes,
+                HEADER_SIZE + 16 + 2 * i,
+                &(field as u16).to_le_bytes(),
+            );
+        }
+        write(
+            &mut bytes,
+            HEADER_SIZE + 32,
+            &(DATA_OFFSET as u32).to_le_bytes(),
+        );
+
+        for (i, c) in CHARS.into_iter().enumerate() {
+            write(
+                &mut bytes,
+                INDEX_OFFSET + 4 * i,
+                &u32::from(c).to_le_bytes(),
+            );
+            let raster = get_raster(c, WEIGHT, SIZE).unwrap();
+            for (y, row) in raster.raster().iter().enumerate() {
+                write(&mut bytes, DATA_OFFSET + i * 7 * 16 + y * 7, row);
+            }
+        }
+        update_checksum(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(!crc32_update(!0, b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_parse() {
+        let bytes = blob();
+        let blob = FontBlob::parse(&bytes).unwrap();
+        assert_eq!(blob.char_count(), 2);
+        assert!(blob.chars().eq(CHARS));
+        assert_eq!(blob.fonts().len(), 1);
+
+        let font = blob.font(WEIGHT, SIZE).unwrap();
+        assert_eq!(
+            (font.weight(), font.height(), font.width()),
+            ("regular", 16, 7)
+        );
+        assert_eq!(font.baseline(), get_baseline(WEIGHT, SIZE));
+        assert_eq!(
+            font.decoration_metrics(),
+            get_decoration_metrics(WEIGHT, SIZE)
+        );
+        assert!(blob.font_by_name("bold", 16).is_none());
+        assert!(blob.font_by_name("regular", 24).is_none());
+
+        for c in CHARS {
+            let expected = get_raster(c, WEIGHT, SIZE).unwrap();
+            let raster = blob.get_raster(c, WEIGHT, SIZE).unwrap();
+            assert!(raster.rows().eq(expected.raster().iter().copied()));
+            assert_eq!((raster.width(), raster.height()), (7, 16));
+            assert_eq!(raster.pixel(3, 5), expected.pixel(3, 5));
+            assert_eq!(raster.pixel(7, 5), 0);
+        }
+        assert!(blob.get_raster('@', WEIGHT, SIZE).is_none());
+        assert!(blob.get_raster('C', WEIGHT, SIZE).is_none());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let bytes = blob();
+        assert_eq!(
+            FontBlob::parse(&[]).unwrap_err(),
+            ParseBlobError::InvalidMagic
+        );
+        assert_eq!(
+            FontBlob::parse(&bytes[..10]).unwrap_err(),
+            ParseBlobError::Truncated
+        );
+
+        let mut corrupt = bytes;
+        corrupt[4] = 2;
+        assert_eq!(
+            FontBlob::parse(&corrupt).unwrap_err(),
+            ParseBlobError::UnsupportedVersion(2)
+        );
+
+        // every flipped bit is detected
+        for i in 0..BLOB_SIZE {
+            for bit in 0..8 {
+                let mut corrupt = bytes;
+                corrupt[i] ^= 1 << bit;
+                assert!(FontBlob::parse(&corrupt).is_err(), "byte {i}, bit {bit}");
+            }
+        }
+        assert_eq!(
+            FontBlob::parse(&bytes[..BLOB_SIZE - 1]).unwrap_err(),
+            ParseBlobError::ChecksumMismatch
+        );
+    }
+
+    /// Checks blobs with a valid checksum but invalid tables.
+    #[test]
+    fn test_parse_invalid_tables() {
+        let check = |offset: usize, value: &[u8], expected: ParseBlobError| {
+            let mut bytes = blob();
+            write(&mut bytes, offset, value);
+            update_checksum(&mut bytes);
+            assert_eq!(FontBlob::parse(&bytes).unwrap_err(), expected);
+        };
+
+        // more chars or fonts than the blob contains
+        check(8, &u32::MAX.to_le_bytes(), ParseBlobError::Truncated);
+        check(6, &1000_u16.to_le_bytes(), ParseBlobError::Truncated);
+        // unsorted or invalid code points
+        check(
+            INDEX_OFFSET,
+            &u32::from('C').to_le_bytes(),
+            ParseBlobError::InvalidIndex,
+        );
+        check(
+            INDEX_OFFSET,
+            &0xd800_u32.to_le_bytes(),
+            ParseBlobError::InvalidIndex,
+        );
+        // invalid name, dimensions, and metrics
+        check(HEADER_SIZE, &[0xff], ParseBlobError::InvalidFont);
+        check(HEADER_SIZE + 16, &[0, 0], ParseBlobError::InvalidFont);
+        check(HEADER_SIZE + 20, &[17, 0], ParseBlobError::InvalidFont);
+        check(HEADER_SIZE + 22, &[16, 0], ParseBlobError::InvalidFont);
+        // pixel data that overlaps the tables or exceeds the blob
+        check(HEADER_SIZE + 32, &[0; 4], ParseBlobError::InvalidFont);
+        check(
+            HEADER_SIZE + 32,
+            &[DATA_OFFSET as u8 + 1, 0, 0, 0],
+            ParseBlobError::Truncated,
+        );
+    }
+}
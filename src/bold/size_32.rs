@@ -4,6 +4,8 @@
 //! * Downloaded from: <https://fonts.google.com/noto/specimen/Noto+Sans+Mono>
 //! * License: SIL Open Font License (OFL) <https://scripts.sil.org/cms/scripts/page.php?site_id=nrsi&id=OFL>
 
+use crate::DecorationMetrics;
+
 /// The constant height of each rasterized character for the given font weight
 /// and size. This size corresponds to the size of the module name. The font
 /// size will be a few percent smaller, as each raster contains a small vertical
@@ -17,6 +19,56 @@ pub const RASTER_HEIGHT: usize = 32;
 /// have to perform additional alignment of characters or fill in padding.
 pub const RASTER_WIDTH: usize = 14;
 
+/// The row of the baseline in the raster, i.e., the number of rows above the
+/// baseline. Characters without descenders end in the row above it.
+pub const BASELINE: usize = 23;
+
+/// The positions of text decorations inside the raster of each character. They
+/// are computed from the `post` and `OS/2` tables of the font.
+pub const DECORATION_METRICS: DecorationMetrics = DecorationMetrics {
+    underline_position: 25,
+    underline_thickness: 1,
+    strikeout_position: 16,
+    strikeout_thickness: 1,
+    overline_position: 5,
+};
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-basic-latin`.
+#[cfg(feature = "unicode-basic-latin")]
+static UNICODE_BASIC_LATIN: [&[u8]; 95 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-basic-latin.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-basic-latin_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement`.
+#[cfg(feature = "unicode-latin-1-supplement")]
+static UNICODE_LATIN_1_SUPPLEMENT: [&[u8]; 94 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-latin-1-supplement.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-latin-1-supplement_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-extended-a`.
+#[cfg(feature = "unicode-latin-extended-a")]
+static UNICODE_LATIN_EXTENDED_A: [&[u8]; 128 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-latin-extended-a.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-latin-extended-a_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-specials`.
+#[cfg(feature = "unicode-specials")]
+static UNICODE_SPECIALS: [&[u8]; RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-specials.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-specials_index.bin"),
+    RASTER_WIDTH,
+);
+
 /// Returns the raster of the given character for font weight bold and font size 23px.
 /// Wide characters, such as '�', will be truncated in their width in order to fulfill
 /// the mono font guarantee. All characters are centered in their raster.
@@ -25,959 +77,6774 @@ pub const fn get_char(c: char) -> Option<&'static [&'static [u8]]> {
     match c {
         // letter: ' ' / 0x20
         #[cfg(feature = "unicode-basic-latin")]
-        ' ' => Some(include!("../res_rasterized_characters/0x20_h32_wBold.txt")),
+        ' ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            0,
+            RASTER_HEIGHT,
+        )),
         // letter: '!' / 0x21
         #[cfg(feature = "unicode-basic-latin")]
-        '!' => Some(include!("../res_rasterized_characters/0x21_h32_wBold.txt")),
+        '!' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            1,
+            RASTER_HEIGHT,
+        )),
         // letter: '"' / 0x22
         #[cfg(feature = "unicode-basic-latin")]
-        '"' => Some(include!("../res_rasterized_characters/0x22_h32_wBold.txt")),
+        '"' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            2,
+            RASTER_HEIGHT,
+        )),
         // letter: '#' / 0x23
         #[cfg(feature = "unicode-basic-latin")]
-        '#' => Some(include!("../res_rasterized_characters/0x23_h32_wBold.txt")),
+        '#' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            3,
+            RASTER_HEIGHT,
+        )),
         // letter: '$' / 0x24
         #[cfg(feature = "unicode-basic-latin")]
-        '$' => Some(include!("../res_rasterized_characters/0x24_h32_wBold.txt")),
+        '$' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            4,
+            RASTER_HEIGHT,
+        )),
         // letter: '%' / 0x25
         #[cfg(feature = "unicode-basic-latin")]
-        '%' => Some(include!("../res_rasterized_characters/0x25_h32_wBold.txt")),
+        '%' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            5,
+            RASTER_HEIGHT,
+        )),
         // letter: '&' / 0x26
         #[cfg(feature = "unicode-basic-latin")]
-        '&' => Some(include!("../res_rasterized_characters/0x26_h32_wBold.txt")),
+        '&' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            6,
+            RASTER_HEIGHT,
+        )),
         // letter: ''' / 0x27
         #[cfg(feature = "unicode-basic-latin")]
-        '\'' => Some(include!("../res_rasterized_characters/0x27_h32_wBold.txt")),
+        '\'' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            7,
+            RASTER_HEIGHT,
+        )),
         // letter: '(' / 0x28
         #[cfg(feature = "unicode-basic-latin")]
-        '(' => Some(include!("../res_rasterized_characters/0x28_h32_wBold.txt")),
+        '(' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            8,
+            RASTER_HEIGHT,
+        )),
         // letter: ')' / 0x29
         #[cfg(feature = "unicode-basic-latin")]
-        ')' => Some(include!("../res_rasterized_characters/0x29_h32_wBold.txt")),
+        ')' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            9,
+            RASTER_HEIGHT,
+        )),
         // letter: '*' / 0x2a
         #[cfg(feature = "unicode-basic-latin")]
-        '*' => Some(include!("../res_rasterized_characters/0x2a_h32_wBold.txt")),
+        '*' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            10,
+            RASTER_HEIGHT,
+        )),
         // letter: '+' / 0x2b
         #[cfg(feature = "unicode-basic-latin")]
-        '+' => Some(include!("../res_rasterized_characters/0x2b_h32_wBold.txt")),
+        '+' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            11,
+            RASTER_HEIGHT,
+        )),
         // letter: ',' / 0x2c
         #[cfg(feature = "unicode-basic-latin")]
-        ',' => Some(include!("../res_rasterized_characters/0x2c_h32_wBold.txt")),
+        ',' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            12,
+            RASTER_HEIGHT,
+        )),
         // letter: '-' / 0x2d
         #[cfg(feature = "unicode-basic-latin")]
-        '-' => Some(include!("../res_rasterized_characters/0x2d_h32_wBold.txt")),
+        '-' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            13,
+            RASTER_HEIGHT,
+        )),
         // letter: '.' / 0x2e
         #[cfg(feature = "unicode-basic-latin")]
-        '.' => Some(include!("../res_rasterized_characters/0x2e_h32_wBold.txt")),
+        '.' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            14,
+            RASTER_HEIGHT,
+        )),
         // letter: '/' / 0x2f
         #[cfg(feature = "unicode-basic-latin")]
-        '/' => Some(include!("../res_rasterized_characters/0x2f_h32_wBold.txt")),
+        '/' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            15,
+            RASTER_HEIGHT,
+        )),
         // letter: '0' / 0x30
         #[cfg(feature = "unicode-basic-latin")]
-        '0' => Some(include!("../res_rasterized_characters/0x30_h32_wBold.txt")),
+        '0' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            16,
+            RASTER_HEIGHT,
+        )),
         // letter: '1' / 0x31
         #[cfg(feature = "unicode-basic-latin")]
-        '1' => Some(include!("../res_rasterized_characters/0x31_h32_wBold.txt")),
+        '1' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            17,
+            RASTER_HEIGHT,
+        )),
         // letter: '2' / 0x32
         #[cfg(feature = "unicode-basic-latin")]
-        '2' => Some(include!("../res_rasterized_characters/0x32_h32_wBold.txt")),
+        '2' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            18,
+            RASTER_HEIGHT,
+        )),
         // letter: '3' / 0x33
         #[cfg(feature = "unicode-basic-latin")]
-        '3' => Some(include!("../res_rasterized_characters/0x33_h32_wBold.txt")),
+        '3' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            19,
+            RASTER_HEIGHT,
+        )),
         // letter: '4' / 0x34
         #[cfg(feature = "unicode-basic-latin")]
-        '4' => Some(include!("../res_rasterized_characters/0x34_h32_wBold.txt")),
+        '4' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            20,
+            RASTER_HEIGHT,
+        )),
         // letter: '5' / 0x35
         #[cfg(feature = "unicode-basic-latin")]
-        '5' => Some(include!("../res_rasterized_characters/0x35_h32_wBold.txt")),
+        '5' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            21,
+            RASTER_HEIGHT,
+        )),
         // letter: '6' / 0x36
         #[cfg(feature = "unicode-basic-latin")]
-        '6' => Some(include!("../res_rasterized_characters/0x36_h32_wBold.txt")),
+        '6' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            22,
+            RASTER_HEIGHT,
+        )),
         // letter: '7' / 0x37
         #[cfg(feature = "unicode-basic-latin")]
-        '7' => Some(include!("../res_rasterized_characters/0x37_h32_wBold.txt")),
+        '7' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            23,
+            RASTER_HEIGHT,
+        )),
         // letter: '8' / 0x38
         #[cfg(feature = "unicode-basic-latin")]
-        '8' => Some(include!("../res_rasterized_characters/0x38_h32_wBold.txt")),
+        '8' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            24,
+            RASTER_HEIGHT,
+        )),
         // letter: '9' / 0x39
         #[cfg(feature = "unicode-basic-latin")]
-        '9' => Some(include!("../res_rasterized_characters/0x39_h32_wBold.txt")),
+        '9' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            25,
+            RASTER_HEIGHT,
+        )),
         // letter: ':' / 0x3a
         #[cfg(feature = "unicode-basic-latin")]
-        ':' => Some(include!("../res_rasterized_characters/0x3a_h32_wBold.txt")),
+        ':' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            26,
+            RASTER_HEIGHT,
+        )),
         // letter: ';' / 0x3b
         #[cfg(feature = "unicode-basic-latin")]
-        ';' => Some(include!("../res_rasterized_characters/0x3b_h32_wBold.txt")),
+        ';' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            27,
+            RASTER_HEIGHT,
+        )),
         // letter: '<' / 0x3c
         #[cfg(feature = "unicode-basic-latin")]
-        '<' => Some(include!("../res_rasterized_characters/0x3c_h32_wBold.txt")),
+        '<' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            28,
+            RASTER_HEIGHT,
+        )),
         // letter: '=' / 0x3d
         #[cfg(feature = "unicode-basic-latin")]
-        '=' => Some(include!("../res_rasterized_characters/0x3d_h32_wBold.txt")),
+        '=' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            29,
+            RASTER_HEIGHT,
+        )),
         // letter: '>' / 0x3e
         #[cfg(feature = "unicode-basic-latin")]
-        '>' => Some(include!("../res_rasterized_characters/0x3e_h32_wBold.txt")),
+        '>' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            30,
+            RASTER_HEIGHT,
+        )),
         // letter: '?' / 0x3f
         #[cfg(feature = "unicode-basic-latin")]
-        '?' => Some(include!("../res_rasterized_characters/0x3f_h32_wBold.txt")),
+        '?' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            31,
+            RASTER_HEIGHT,
+        )),
         // letter: '@' / 0x40
         #[cfg(feature = "unicode-basic-latin")]
-        '@' => Some(include!("../res_rasterized_characters/0x40_h32_wBold.txt")),
+        '@' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            32,
+            RASTER_HEIGHT,
+        )),
         // letter: 'A' / 0x41
         #[cfg(feature = "unicode-basic-latin")]
-        'A' => Some(include!("../res_rasterized_characters/0x41_h32_wBold.txt")),
+        'A' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            33,
+            RASTER_HEIGHT,
+        )),
         // letter: 'B' / 0x42
         #[cfg(feature = "unicode-basic-latin")]
-        'B' => Some(include!("../res_rasterized_characters/0x42_h32_wBold.txt")),
+        'B' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            34,
+            RASTER_HEIGHT,
+        )),
         // letter: 'C' / 0x43
         #[cfg(feature = "unicode-basic-latin")]
-        'C' => Some(include!("../res_rasterized_characters/0x43_h32_wBold.txt")),
+        'C' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            35,
+            RASTER_HEIGHT,
+        )),
         // letter: 'D' / 0x44
         #[cfg(feature = "unicode-basic-latin")]
-        'D' => Some(include!("../res_rasterized_characters/0x44_h32_wBold.txt")),
+        'D' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            36,
+            RASTER_HEIGHT,
+        )),
         // letter: 'E' / 0x45
         #[cfg(feature = "unicode-basic-latin")]
-        'E' => Some(include!("../res_rasterized_characters/0x45_h32_wBold.txt")),
+        'E' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            37,
+            RASTER_HEIGHT,
+        )),
         // letter: 'F' / 0x46
         #[cfg(feature = "unicode-basic-latin")]
-        'F' => Some(include!("../res_rasterized_characters/0x46_h32_wBold.txt")),
+        'F' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            38,
+            RASTER_HEIGHT,
+        )),
         // letter: 'G' / 0x47
         #[cfg(feature = "unicode-basic-latin")]
-        'G' => Some(include!("../res_rasterized_characters/0x47_h32_wBold.txt")),
+        'G' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            39,
+            RASTER_HEIGHT,
+        )),
         // letter: 'H' / 0x48
         #[cfg(feature = "unicode-basic-latin")]
-        'H' => Some(include!("../res_rasterized_characters/0x48_h32_wBold.txt")),
+        'H' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            40,
+            RASTER_HEIGHT,
+        )),
         // letter: 'I' / 0x49
         #[cfg(feature = "unicode-basic-latin")]
-        'I' => Some(include!("../res_rasterized_characters/0x49_h32_wBold.txt")),
+        'I' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            41,
+            RASTER_HEIGHT,
+        )),
         // letter: 'J' / 0x4a
         #[cfg(feature = "unicode-basic-latin")]
-        'J' => Some(include!("../res_rasterized_characters/0x4a_h32_wBold.txt")),
+        'J' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            42,
+            RASTER_HEIGHT,
+        )),
         // letter: 'K' / 0x4b
         #[cfg(feature = "unicode-basic-latin")]
-        'K' => Some(include!("../res_rasterized_characters/0x4b_h32_wBold.txt")),
+        'K' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            43,
+            RASTER_HEIGHT,
+        )),
         // letter: 'L' / 0x4c
         #[cfg(feature = "unicode-basic-latin")]
-        'L' => Some(include!("../res_rasterized_characters/0x4c_h32_wBold.txt")),
+        'L' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            44,
+            RASTER_HEIGHT,
+        )),
         // letter: 'M' / 0x4d
         #[cfg(feature = "unicode-basic-latin")]
-        'M' => Some(include!("../res_rasterized_characters/0x4d_h32_wBold.txt")),
+        'M' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            45,
+            RASTER_HEIGHT,
+        )),
         // letter: 'N' / 0x4e
         #[cfg(feature = "unicode-basic-latin")]
-        'N' => Some(include!("../res_rasterized_characters/0x4e_h32_wBold.txt")),
+        'N' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            46,
+            RASTER_HEIGHT,
+        )),
         // letter: 'O' / 0x4f
         #[cfg(feature = "unicode-basic-latin")]
-        'O' => Some(include!("../res_rasterized_characters/0x4f_h32_wBold.txt")),
+        'O' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            47,
+            RASTER_HEIGHT,
+        )),
         // letter: 'P' / 0x50
         #[cfg(feature = "unicode-basic-latin")]
-        'P' => Some(include!("../res_rasterized_characters/0x50_h32_wBold.txt")),
+        'P' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            48,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Q' / 0x51
         #[cfg(feature = "unicode-basic-latin")]
-        'Q' => Some(include!("../res_rasterized_characters/0x51_h32_wBold.txt")),
+        'Q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            49,
+            RASTER_HEIGHT,
+        )),
         // letter: 'R' / 0x52
         #[cfg(feature = "unicode-basic-latin")]
-        'R' => Some(include!("../res_rasterized_characters/0x52_h32_wBold.txt")),
+        'R' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            50,
+            RASTER_HEIGHT,
+        )),
         // letter: 'S' / 0x53
         #[cfg(feature = "unicode-basic-latin")]
-        'S' => Some(include!("../res_rasterized_characters/0x53_h32_wBold.txt")),
+        'S' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            51,
+            RASTER_HEIGHT,
+        )),
         // letter: 'T' / 0x54
         #[cfg(feature = "unicode-basic-latin")]
-        'T' => Some(include!("../res_rasterized_characters/0x54_h32_wBold.txt")),
+        'T' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            52,
+            RASTER_HEIGHT,
+        )),
         // letter: 'U' / 0x55
         #[cfg(feature = "unicode-basic-latin")]
-        'U' => Some(include!("../res_rasterized_characters/0x55_h32_wBold.txt")),
+        'U' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            53,
+            RASTER_HEIGHT,
+        )),
         // letter: 'V' / 0x56
         #[cfg(feature = "unicode-basic-latin")]
-        'V' => Some(include!("../res_rasterized_characters/0x56_h32_wBold.txt")),
+        'V' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            54,
+            RASTER_HEIGHT,
+        )),
         // letter: 'W' / 0x57
         #[cfg(feature = "unicode-basic-latin")]
-        'W' => Some(include!("../res_rasterized_characters/0x57_h32_wBold.txt")),
+        'W' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            55,
+            RASTER_HEIGHT,
+        )),
         // letter: 'X' / 0x58
         #[cfg(feature = "unicode-basic-latin")]
-        'X' => Some(include!("../res_rasterized_characters/0x58_h32_wBold.txt")),
+        'X' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            56,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Y' / 0x59
         #[cfg(feature = "unicode-basic-latin")]
-        'Y' => Some(include!("../res_rasterized_characters/0x59_h32_wBold.txt")),
+        'Y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            57,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Z' / 0x5a
         #[cfg(feature = "unicode-basic-latin")]
-        'Z' => Some(include!("../res_rasterized_characters/0x5a_h32_wBold.txt")),
+        'Z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            58,
+            RASTER_HEIGHT,
+        )),
         // letter: '[' / 0x5b
         #[cfg(feature = "unicode-basic-latin")]
-        '[' => Some(include!("../res_rasterized_characters/0x5b_h32_wBold.txt")),
+        '[' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            59,
+            RASTER_HEIGHT,
+        )),
         // letter: '\' / 0x5c
         #[cfg(feature = "unicode-basic-latin")]
-        '\\' => Some(include!("../res_rasterized_characters/0x5c_h32_wBold.txt")),
+        '\\' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            60,
+            RASTER_HEIGHT,
+        )),
         // letter: ']' / 0x5d
         #[cfg(feature = "unicode-basic-latin")]
-        ']' => Some(include!("../res_rasterized_characters/0x5d_h32_wBold.txt")),
+        ']' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            61,
+            RASTER_HEIGHT,
+        )),
         // letter: '^' / 0x5e
         #[cfg(feature = "unicode-basic-latin")]
-        '^' => Some(include!("../res_rasterized_characters/0x5e_h32_wBold.txt")),
+        '^' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            62,
+            RASTER_HEIGHT,
+        )),
         // letter: '_' / 0x5f
         #[cfg(feature = "unicode-basic-latin")]
-        '_' => Some(include!("../res_rasterized_characters/0x5f_h32_wBold.txt")),
+        '_' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            63,
+            RASTER_HEIGHT,
+        )),
         // letter: '`' / 0x60
         #[cfg(feature = "unicode-basic-latin")]
-        '`' => Some(include!("../res_rasterized_characters/0x60_h32_wBold.txt")),
+        '`' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            64,
+            RASTER_HEIGHT,
+        )),
         // letter: 'a' / 0x61
         #[cfg(feature = "unicode-basic-latin")]
-        'a' => Some(include!("../res_rasterized_characters/0x61_h32_wBold.txt")),
+        'a' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            65,
+            RASTER_HEIGHT,
+        )),
         // letter: 'b' / 0x62
         #[cfg(feature = "unicode-basic-latin")]
-        'b' => Some(include!("../res_rasterized_characters/0x62_h32_wBold.txt")),
+        'b' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            66,
+            RASTER_HEIGHT,
+        )),
         // letter: 'c' / 0x63
         #[cfg(feature = "unicode-basic-latin")]
-        'c' => Some(include!("../res_rasterized_characters/0x63_h32_wBold.txt")),
+        'c' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            67,
+            RASTER_HEIGHT,
+        )),
         // letter: 'd' / 0x64
         #[cfg(feature = "unicode-basic-latin")]
-        'd' => Some(include!("../res_rasterized_characters/0x64_h32_wBold.txt")),
+        'd' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            68,
+            RASTER_HEIGHT,
+        )),
         // letter: 'e' / 0x65
         #[cfg(feature = "unicode-basic-latin")]
-        'e' => Some(include!("../res_rasterized_characters/0x65_h32_wBold.txt")),
+        'e' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            69,
+            RASTER_HEIGHT,
+        )),
         // letter: 'f' / 0x66
         #[cfg(feature = "unicode-basic-latin")]
-        'f' => Some(include!("../res_rasterized_characters/0x66_h32_wBold.txt")),
+        'f' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            70,
+            RASTER_HEIGHT,
+        )),
         // letter: 'g' / 0x67
         #[cfg(feature = "unicode-basic-latin")]
-        'g' => Some(include!("../res_rasterized_characters/0x67_h32_wBold.txt")),
+        'g' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            71,
+            RASTER_HEIGHT,
+        )),
         // letter: 'h' / 0x68
         #[cfg(feature = "unicode-basic-latin")]
-        'h' => Some(include!("../res_rasterized_characters/0x68_h32_wBold.txt")),
+        'h' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            72,
+            RASTER_HEIGHT,
+        )),
         // letter: 'i' / 0x69
         #[cfg(feature = "unicode-basic-latin")]
-        'i' => Some(include!("../res_rasterized_characters/0x69_h32_wBold.txt")),
+        'i' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            73,
+            RASTER_HEIGHT,
+        )),
         // letter: 'j' / 0x6a
         #[cfg(feature = "unicode-basic-latin")]
-        'j' => Some(include!("../res_rasterized_characters/0x6a_h32_wBold.txt")),
+        'j' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            74,
+            RASTER_HEIGHT,
+        )),
         // letter: 'k' / 0x6b
         #[cfg(feature = "unicode-basic-latin")]
-        'k' => Some(include!("../res_rasterized_characters/0x6b_h32_wBold.txt")),
+        'k' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            75,
+            RASTER_HEIGHT,
+        )),
         // letter: 'l' / 0x6c
         #[cfg(feature = "unicode-basic-latin")]
-        'l' => Some(include!("../res_rasterized_characters/0x6c_h32_wBold.txt")),
+        'l' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            76,
+            RASTER_HEIGHT,
+        )),
         // letter: 'm' / 0x6d
         #[cfg(feature = "unicode-basic-latin")]
-        'm' => Some(include!("../res_rasterized_characters/0x6d_h32_wBold.txt")),
+        'm' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            77,
+            RASTER_HEIGHT,
+        )),
         // letter: 'n' / 0x6e
         #[cfg(feature = "unicode-basic-latin")]
-        'n' => Some(include!("../res_rasterized_characters/0x6e_h32_wBold.txt")),
+        'n' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            78,
+            RASTER_HEIGHT,
+        )),
         // letter: 'o' / 0x6f
         #[cfg(feature = "unicode-basic-latin")]
-        'o' => Some(include!("../res_rasterized_characters/0x6f_h32_wBold.txt")),
+        'o' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            79,
+            RASTER_HEIGHT,
+        )),
         // letter: 'p' / 0x70
         #[cfg(feature = "unicode-basic-latin")]
-        'p' => Some(include!("../res_rasterized_characters/0x70_h32_wBold.txt")),
+        'p' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            80,
+            RASTER_HEIGHT,
+        )),
         // letter: 'q' / 0x71
         #[cfg(feature = "unicode-basic-latin")]
-        'q' => Some(include!("../res_rasterized_characters/0x71_h32_wBold.txt")),
+        'q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            81,
+            RASTER_HEIGHT,
+        )),
         // letter: 'r' / 0x72
         #[cfg(feature = "unicode-basic-latin")]
-        'r' => Some(include!("../res_rasterized_characters/0x72_h32_wBold.txt")),
+        'r' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            82,
+            RASTER_HEIGHT,
+        )),
         // letter: 's' / 0x73
         #[cfg(feature = "unicode-basic-latin")]
-        's' => Some(include!("../res_rasterized_characters/0x73_h32_wBold.txt")),
+        's' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            83,
+            RASTER_HEIGHT,
+        )),
         // letter: 't' / 0x74
         #[cfg(feature = "unicode-basic-latin")]
-        't' => Some(include!("../res_rasterized_characters/0x74_h32_wBold.txt")),
+        't' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            84,
+            RASTER_HEIGHT,
+        )),
         // letter: 'u' / 0x75
         #[cfg(feature = "unicode-basic-latin")]
-        'u' => Some(include!("../res_rasterized_characters/0x75_h32_wBold.txt")),
+        'u' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            85,
+            RASTER_HEIGHT,
+        )),
         // letter: 'v' / 0x76
         #[cfg(feature = "unicode-basic-latin")]
-        'v' => Some(include!("../res_rasterized_characters/0x76_h32_wBold.txt")),
+        'v' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            86,
+            RASTER_HEIGHT,
+        )),
         // letter: 'w' / 0x77
         #[cfg(feature = "unicode-basic-latin")]
-        'w' => Some(include!("../res_rasterized_characters/0x77_h32_wBold.txt")),
+        'w' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            87,
+            RASTER_HEIGHT,
+        )),
         // letter: 'x' / 0x78
         #[cfg(feature = "unicode-basic-latin")]
-        'x' => Some(include!("../res_rasterized_characters/0x78_h32_wBold.txt")),
+        'x' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            88,
+            RASTER_HEIGHT,
+        )),
         // letter: 'y' / 0x79
         #[cfg(feature = "unicode-basic-latin")]
-        'y' => Some(include!("../res_rasterized_characters/0x79_h32_wBold.txt")),
+        'y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            89,
+            RASTER_HEIGHT,
+        )),
         // letter: 'z' / 0x7a
         #[cfg(feature = "unicode-basic-latin")]
-        'z' => Some(include!("../res_rasterized_characters/0x7a_h32_wBold.txt")),
+        'z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            90,
+            RASTER_HEIGHT,
+        )),
         // letter: '{' / 0x7b
         #[cfg(feature = "unicode-basic-latin")]
-        '{' => Some(include!("../res_rasterized_characters/0x7b_h32_wBold.txt")),
+        '{' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            91,
+            RASTER_HEIGHT,
+        )),
         // letter: '|' / 0x7c
         #[cfg(feature = "unicode-basic-latin")]
-        '|' => Some(include!("../res_rasterized_characters/0x7c_h32_wBold.txt")),
+        '|' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            92,
+            RASTER_HEIGHT,
+        )),
         // letter: '}' / 0x7d
         #[cfg(feature = "unicode-basic-latin")]
-        '}' => Some(include!("../res_rasterized_characters/0x7d_h32_wBold.txt")),
+        '}' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            93,
+            RASTER_HEIGHT,
+        )),
         // letter: '~' / 0x7e
         #[cfg(feature = "unicode-basic-latin")]
-        '~' => Some(include!("../res_rasterized_characters/0x7e_h32_wBold.txt")),
+        '~' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            94,
+            RASTER_HEIGHT,
+        )),
         // letter: '¡' / 0xa1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¡' => Some(include!("../res_rasterized_characters/0xa1_h32_wBold.txt")),
+        '¡' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            0,
+            RASTER_HEIGHT,
+        )),
         // letter: '¢' / 0xa2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¢' => Some(include!("../res_rasterized_characters/0xa2_h32_wBold.txt")),
+        '¢' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            1,
+            RASTER_HEIGHT,
+        )),
         // letter: '£' / 0xa3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '£' => Some(include!("../res_rasterized_characters/0xa3_h32_wBold.txt")),
+        '£' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            2,
+            RASTER_HEIGHT,
+        )),
         // letter: '¤' / 0xa4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¤' => Some(include!("../res_rasterized_characters/0xa4_h32_wBold.txt")),
+        '¤' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            3,
+            RASTER_HEIGHT,
+        )),
         // letter: '¥' / 0xa5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¥' => Some(include!("../res_rasterized_characters/0xa5_h32_wBold.txt")),
+        '¥' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            4,
+            RASTER_HEIGHT,
+        )),
         // letter: '¦' / 0xa6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¦' => Some(include!("../res_rasterized_characters/0xa6_h32_wBold.txt")),
+        '¦' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            5,
+            RASTER_HEIGHT,
+        )),
         // letter: '§' / 0xa7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '§' => Some(include!("../res_rasterized_characters/0xa7_h32_wBold.txt")),
+        '§' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            6,
+            RASTER_HEIGHT,
+        )),
         // letter: '¨' / 0xa8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¨' => Some(include!("../res_rasterized_characters/0xa8_h32_wBold.txt")),
+        '¨' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            7,
+            RASTER_HEIGHT,
+        )),
         // letter: '©' / 0xa9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '©' => Some(include!("../res_rasterized_characters/0xa9_h32_wBold.txt")),
+        '©' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            8,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ª' / 0xaa
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ª' => Some(include!("../res_rasterized_characters/0xaa_h32_wBold.txt")),
+        'ª' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            9,
+            RASTER_HEIGHT,
+        )),
         // letter: '«' / 0xab
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '«' => Some(include!("../res_rasterized_characters/0xab_h32_wBold.txt")),
+        '«' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            10,
+            RASTER_HEIGHT,
+        )),
         // letter: '¬' / 0xac
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¬' => Some(include!("../res_rasterized_characters/0xac_h32_wBold.txt")),
+        '¬' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            11,
+            RASTER_HEIGHT,
+        )),
         // letter: '®' / 0xae
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '®' => Some(include!("../res_rasterized_characters/0xae_h32_wBold.txt")),
+        '®' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            12,
+            RASTER_HEIGHT,
+        )),
         // letter: '¯' / 0xaf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¯' => Some(include!("../res_rasterized_characters/0xaf_h32_wBold.txt")),
+        '¯' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            13,
+            RASTER_HEIGHT,
+        )),
         // letter: '°' / 0xb0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '°' => Some(include!("../res_rasterized_characters/0xb0_h32_wBold.txt")),
+        '°' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            14,
+            RASTER_HEIGHT,
+        )),
         // letter: '±' / 0xb1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '±' => Some(include!("../res_rasterized_characters/0xb1_h32_wBold.txt")),
+        '±' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            15,
+            RASTER_HEIGHT,
+        )),
         // letter: '²' / 0xb2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '²' => Some(include!("../res_rasterized_characters/0xb2_h32_wBold.txt")),
+        '²' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            16,
+            RASTER_HEIGHT,
+        )),
         // letter: '³' / 0xb3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '³' => Some(include!("../res_rasterized_characters/0xb3_h32_wBold.txt")),
+        '³' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            17,
+            RASTER_HEIGHT,
+        )),
         // letter: '´' / 0xb4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '´' => Some(include!("../res_rasterized_characters/0xb4_h32_wBold.txt")),
+        '´' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            18,
+            RASTER_HEIGHT,
+        )),
         // letter: 'µ' / 0xb5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'µ' => Some(include!("../res_rasterized_characters/0xb5_h32_wBold.txt")),
+        'µ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            19,
+            RASTER_HEIGHT,
+        )),
         // letter: '¶' / 0xb6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¶' => Some(include!("../res_rasterized_characters/0xb6_h32_wBold.txt")),
+        '¶' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            20,
+            RASTER_HEIGHT,
+        )),
         // letter: '·' / 0xb7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '·' => Some(include!("../res_rasterized_characters/0xb7_h32_wBold.txt")),
+        '·' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            21,
+            RASTER_HEIGHT,
+        )),
         // letter: '¸' / 0xb8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¸' => Some(include!("../res_rasterized_characters/0xb8_h32_wBold.txt")),
+        '¸' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            22,
+            RASTER_HEIGHT,
+        )),
         // letter: '¹' / 0xb9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¹' => Some(include!("../res_rasterized_characters/0xb9_h32_wBold.txt")),
+        '¹' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            23,
+            RASTER_HEIGHT,
+        )),
         // letter: 'º' / 0xba
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'º' => Some(include!("../res_rasterized_characters/0xba_h32_wBold.txt")),
+        'º' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            24,
+            RASTER_HEIGHT,
+        )),
         // letter: '»' / 0xbb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '»' => Some(include!("../res_rasterized_characters/0xbb_h32_wBold.txt")),
+        '»' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            25,
+            RASTER_HEIGHT,
+        )),
         // letter: '¼' / 0xbc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¼' => Some(include!("../res_rasterized_characters/0xbc_h32_wBold.txt")),
+        '¼' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            26,
+            RASTER_HEIGHT,
+        )),
         // letter: '½' / 0xbd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '½' => Some(include!("../res_rasterized_characters/0xbd_h32_wBold.txt")),
+        '½' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            27,
+            RASTER_HEIGHT,
+        )),
         // letter: '¾' / 0xbe
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¾' => Some(include!("../res_rasterized_characters/0xbe_h32_wBold.txt")),
+        '¾' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            28,
+            RASTER_HEIGHT,
+        )),
         // letter: '¿' / 0xbf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¿' => Some(include!("../res_rasterized_characters/0xbf_h32_wBold.txt")),
+        '¿' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            29,
+            RASTER_HEIGHT,
+        )),
         // letter: 'À' / 0xc0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'À' => Some(include!("../res_rasterized_characters/0xc0_h32_wBold.txt")),
+        'À' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            30,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Á' / 0xc1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Á' => Some(include!("../res_rasterized_characters/0xc1_h32_wBold.txt")),
+        'Á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            31,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Â' / 0xc2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Â' => Some(include!("../res_rasterized_characters/0xc2_h32_wBold.txt")),
+        'Â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            32,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ã' / 0xc3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ã' => Some(include!("../res_rasterized_characters/0xc3_h32_wBold.txt")),
+        'Ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            33,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ä' / 0xc4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ä' => Some(include!("../res_rasterized_characters/0xc4_h32_wBold.txt")),
+        'Ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            34,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Å' / 0xc5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Å' => Some(include!("../res_rasterized_characters/0xc5_h32_wBold.txt")),
+        'Å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            35,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Æ' / 0xc6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Æ' => Some(include!("../res_rasterized_characters/0xc6_h32_wBold.txt")),
+        'Æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            36,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ç' / 0xc7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ç' => Some(include!("../res_rasterized_characters/0xc7_h32_wBold.txt")),
+        'Ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            37,
+            RASTER_HEIGHT,
+        )),
         // letter: 'È' / 0xc8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'È' => Some(include!("../res_rasterized_characters/0xc8_h32_wBold.txt")),
+        'È' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            38,
+            RASTER_HEIGHT,
+        )),
         // letter: 'É' / 0xc9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'É' => Some(include!("../res_rasterized_characters/0xc9_h32_wBold.txt")),
+        'É' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            39,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ê' / 0xca
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ê' => Some(include!("../res_rasterized_characters/0xca_h32_wBold.txt")),
+        'Ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            40,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ë' / 0xcb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ë' => Some(include!("../res_rasterized_characters/0xcb_h32_wBold.txt")),
+        'Ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            41,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ì' / 0xcc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ì' => Some(include!("../res_rasterized_characters/0xcc_h32_wBold.txt")),
+        'Ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            42,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Í' / 0xcd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Í' => Some(include!("../res_rasterized_characters/0xcd_h32_wBold.txt")),
+        'Í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            43,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Î' / 0xce
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Î' => Some(include!("../res_rasterized_characters/0xce_h32_wBold.txt")),
+        'Î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            44,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ï' / 0xcf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ï' => Some(include!("../res_rasterized_characters/0xcf_h32_wBold.txt")),
+        'Ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            45,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ð' / 0xd0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ð' => Some(include!("../res_rasterized_characters/0xd0_h32_wBold.txt")),
+        'Ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            46,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ñ' / 0xd1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ñ' => Some(include!("../res_rasterized_characters/0xd1_h32_wBold.txt")),
+        'Ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            47,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ò' / 0xd2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ò' => Some(include!("../res_rasterized_characters/0xd2_h32_wBold.txt")),
+        'Ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            48,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ó' / 0xd3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ó' => Some(include!("../res_rasterized_characters/0xd3_h32_wBold.txt")),
+        'Ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            49,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ô' / 0xd4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ô' => Some(include!("../res_rasterized_characters/0xd4_h32_wBold.txt")),
+        'Ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            50,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Õ' / 0xd5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Õ' => Some(include!("../res_rasterized_characters/0xd5_h32_wBold.txt")),
+        'Õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            51,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ö' / 0xd6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ö' => Some(include!("../res_rasterized_characters/0xd6_h32_wBold.txt")),
+        'Ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            52,
+            RASTER_HEIGHT,
+        )),
         // letter: '×' / 0xd7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '×' => Some(include!("../res_rasterized_characters/0xd7_h32_wBold.txt")),
+        '×' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            53,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ø' / 0xd8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ø' => Some(include!("../res_rasterized_characters/0xd8_h32_wBold.txt")),
+        'Ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            54,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ù' / 0xd9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ù' => Some(include!("../res_rasterized_characters/0xd9_h32_wBold.txt")),
+        'Ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            55,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ú' / 0xda
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ú' => Some(include!("../res_rasterized_characters/0xda_h32_wBold.txt")),
+        'Ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            56,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Û' / 0xdb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Û' => Some(include!("../res_rasterized_characters/0xdb_h32_wBold.txt")),
+        'Û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            57,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ü' / 0xdc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ü' => Some(include!("../res_rasterized_characters/0xdc_h32_wBold.txt")),
+        'Ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            58,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ý' / 0xdd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ý' => Some(include!("../res_rasterized_characters/0xdd_h32_wBold.txt")),
+        'Ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            59,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Þ' / 0xde
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Þ' => Some(include!("../res_rasterized_characters/0xde_h32_wBold.txt")),
+        'Þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            60,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ß' / 0xdf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ß' => Some(include!("../res_rasterized_characters/0xdf_h32_wBold.txt")),
+        'ß' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            61,
+            RASTER_HEIGHT,
+        )),
         // letter: 'à' / 0xe0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'à' => Some(include!("../res_rasterized_characters/0xe0_h32_wBold.txt")),
+        'à' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            62,
+            RASTER_HEIGHT,
+        )),
         // letter: 'á' / 0xe1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'á' => Some(include!("../res_rasterized_characters/0xe1_h32_wBold.txt")),
+        'á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            63,
+            RASTER_HEIGHT,
+        )),
         // letter: 'â' / 0xe2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'â' => Some(include!("../res_rasterized_characters/0xe2_h32_wBold.txt")),
+        'â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            64,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ã' / 0xe3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ã' => Some(include!("../res_rasterized_characters/0xe3_h32_wBold.txt")),
+        'ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            65,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ä' / 0xe4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ä' => Some(include!("../res_rasterized_characters/0xe4_h32_wBold.txt")),
+        'ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            66,
+            RASTER_HEIGHT,
+        )),
         // letter: 'å' / 0xe5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'å' => Some(include!("../res_rasterized_characters/0xe5_h32_wBold.txt")),
+        'å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            67,
+            RASTER_HEIGHT,
+        )),
         // letter: 'æ' / 0xe6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'æ' => Some(include!("../res_rasterized_characters/0xe6_h32_wBold.txt")),
+        'æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            68,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ç' / 0xe7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ç' => Some(include!("../res_rasterized_characters/0xe7_h32_wBold.txt")),
+        'ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            69,
+            RASTER_HEIGHT,
+        )),
         // letter: 'è' / 0xe8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'è' => Some(include!("../res_rasterized_characters/0xe8_h32_wBold.txt")),
+        'è' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            70,
+            RASTER_HEIGHT,
+        )),
         // letter: 'é' / 0xe9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'é' => Some(include!("../res_rasterized_characters/0xe9_h32_wBold.txt")),
+        'é' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            71,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ê' / 0xea
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ê' => Some(include!("../res_rasterized_characters/0xea_h32_wBold.txt")),
+        'ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            72,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ë' / 0xeb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ë' => Some(include!("../res_rasterized_characters/0xeb_h32_wBold.txt")),
+        'ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            73,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ì' / 0xec
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ì' => Some(include!("../res_rasterized_characters/0xec_h32_wBold.txt")),
+        'ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            74,
+            RASTER_HEIGHT,
+        )),
         // letter: 'í' / 0xed
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'í' => Some(include!("../res_rasterized_characters/0xed_h32_wBold.txt")),
+        'í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            75,
+            RASTER_HEIGHT,
+        )),
         // letter: 'î' / 0xee
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'î' => Some(include!("../res_rasterized_characters/0xee_h32_wBold.txt")),
+        'î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            76,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ï' / 0xef
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ï' => Some(include!("../res_rasterized_characters/0xef_h32_wBold.txt")),
+        'ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            77,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ð' / 0xf0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ð' => Some(include!("../res_rasterized_characters/0xf0_h32_wBold.txt")),
+        'ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            78,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ñ' / 0xf1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ñ' => Some(include!("../res_rasterized_characters/0xf1_h32_wBold.txt")),
+        'ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            79,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ò' / 0xf2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ò' => Some(include!("../res_rasterized_characters/0xf2_h32_wBold.txt")),
+        'ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            80,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ó' / 0xf3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ó' => Some(include!("../res_rasterized_characters/0xf3_h32_wBold.txt")),
+        'ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            81,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ô' / 0xf4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ô' => Some(include!("../res_rasterized_characters/0xf4_h32_wBold.txt")),
+        'ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            82,
+            RASTER_HEIGHT,
+        )),
         // letter: 'õ' / 0xf5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'õ' => Some(include!("../res_rasterized_characters/0xf5_h32_wBold.txt")),
+        'õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            83,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ö' / 0xf6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ö' => Some(include!("../res_rasterized_characters/0xf6_h32_wBold.txt")),
+        'ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            84,
+            RASTER_HEIGHT,
+        )),
         // letter: '÷' / 0xf7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '÷' => Some(include!("../res_rasterized_characters/0xf7_h32_wBold.txt")),
+        '÷' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            85,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ø' / 0xf8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ø' => Some(include!("../res_rasterized_characters/0xf8_h32_wBold.txt")),
+        'ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            86,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ù' / 0xf9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ù' => Some(include!("../res_rasterized_characters/0xf9_h32_wBold.txt")),
+        'ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            87,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ú' / 0xfa
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ú' => Some(include!("../res_rasterized_characters/0xfa_h32_wBold.txt")),
+        'ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            88,
+            RASTER_HEIGHT,
+        )),
         // letter: 'û' / 0xfb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'û' => Some(include!("../res_rasterized_characters/0xfb_h32_wBold.txt")),
+        'û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            89,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ü' / 0xfc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ü' => Some(include!("../res_rasterized_characters/0xfc_h32_wBold.txt")),
+        'ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            90,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ý' / 0xfd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ý' => Some(include!("../res_rasterized_characters/0xfd_h32_wBold.txt")),
+        'ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            91,
+            RASTER_HEIGHT,
+        )),
         // letter: 'þ' / 0xfe
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'þ' => Some(include!("../res_rasterized_characters/0xfe_h32_wBold.txt")),
+        'þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            92,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ÿ' / 0xff
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ÿ' => Some(include!("../res_rasterized_characters/0xff_h32_wBold.txt")),
+        'ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            93,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ā' / 0x100
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ā' => Some(include!("../res_rasterized_characters/0x100_h32_wBold.txt")),
+        'Ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            0,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ā' / 0x101
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ā' => Some(include!("../res_rasterized_characters/0x101_h32_wBold.txt")),
+        'ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            1,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ă' / 0x102
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ă' => Some(include!("../res_rasterized_characters/0x102_h32_wBold.txt")),
+        'Ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            2,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ă' / 0x103
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ă' => Some(include!("../res_rasterized_characters/0x103_h32_wBold.txt")),
+        'ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            3,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ą' / 0x104
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ą' => Some(include!("../res_rasterized_characters/0x104_h32_wBold.txt")),
+        'Ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            4,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ą' / 0x105
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ą' => Some(include!("../res_rasterized_characters/0x105_h32_wBold.txt")),
+        'ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            5,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ć' / 0x106
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ć' => Some(include!("../res_rasterized_characters/0x106_h32_wBold.txt")),
+        'Ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            6,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ć' / 0x107
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ć' => Some(include!("../res_rasterized_characters/0x107_h32_wBold.txt")),
+        'ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            7,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĉ' / 0x108
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĉ' => Some(include!("../res_rasterized_characters/0x108_h32_wBold.txt")),
+        'Ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            8,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĉ' / 0x109
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĉ' => Some(include!("../res_rasterized_characters/0x109_h32_wBold.txt")),
+        'ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            9,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ċ' / 0x10a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ċ' => Some(include!("../res_rasterized_characters/0x10a_h32_wBold.txt")),
+        'Ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            10,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ċ' / 0x10b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ċ' => Some(include!("../res_rasterized_characters/0x10b_h32_wBold.txt")),
+        'ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            11,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Č' / 0x10c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Č' => Some(include!("../res_rasterized_characters/0x10c_h32_wBold.txt")),
+        'Č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            12,
+            RASTER_HEIGHT,
+        )),
         // letter: 'č' / 0x10d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'č' => Some(include!("../res_rasterized_characters/0x10d_h32_wBold.txt")),
+        'č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            13,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ď' / 0x10e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ď' => Some(include!("../res_rasterized_characters/0x10e_h32_wBold.txt")),
+        'Ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            14,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ď' / 0x10f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ď' => Some(include!("../res_rasterized_characters/0x10f_h32_wBold.txt")),
+        'ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            15,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Đ' / 0x110
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Đ' => Some(include!("../res_rasterized_characters/0x110_h32_wBold.txt")),
+        'Đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            16,
+            RASTER_HEIGHT,
+        )),
         // letter: 'đ' / 0x111
         #[cfg(feature = "unicode-latin-extended-a")]
-        'đ' => Some(include!("../res_rasterized_characters/0x111_h32_wBold.txt")),
+        'đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            17,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ē' / 0x112
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ē' => Some(include!("../res_rasterized_characters/0x112_h32_wBold.txt")),
+        'Ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            18,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ē' / 0x113
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ē' => Some(include!("../res_rasterized_characters/0x113_h32_wBold.txt")),
+        'ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            19,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĕ' / 0x114
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĕ' => Some(include!("../res_rasterized_characters/0x114_h32_wBold.txt")),
+        'Ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            20,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĕ' / 0x115
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĕ' => Some(include!("../res_rasterized_characters/0x115_h32_wBold.txt")),
+        'ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            21,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ė' / 0x116
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ė' => Some(include!("../res_rasterized_characters/0x116_h32_wBold.txt")),
+        'Ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            22,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ė' / 0x117
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ė' => Some(include!("../res_rasterized_characters/0x117_h32_wBold.txt")),
+        'ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            23,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ę' / 0x118
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ę' => Some(include!("../res_rasterized_characters/0x118_h32_wBold.txt")),
+        'Ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            24,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ę' / 0x119
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ę' => Some(include!("../res_rasterized_characters/0x119_h32_wBold.txt")),
+        'ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            25,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ě' / 0x11a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ě' => Some(include!("../res_rasterized_characters/0x11a_h32_wBold.txt")),
+        'Ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            26,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ě' / 0x11b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ě' => Some(include!("../res_rasterized_characters/0x11b_h32_wBold.txt")),
+        'ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            27,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĝ' / 0x11c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĝ' => Some(include!("../res_rasterized_characters/0x11c_h32_wBold.txt")),
+        'Ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            28,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĝ' / 0x11d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĝ' => Some(include!("../res_rasterized_characters/0x11d_h32_wBold.txt")),
+        'ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            29,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ğ' / 0x11e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ğ' => Some(include!("../res_rasterized_characters/0x11e_h32_wBold.txt")),
+        'Ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            30,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ğ' / 0x11f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ğ' => Some(include!("../res_rasterized_characters/0x11f_h32_wBold.txt")),
+        'ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            31,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ġ' / 0x120
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ġ' => Some(include!("../res_rasterized_characters/0x120_h32_wBold.txt")),
+        'Ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            32,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ġ' / 0x121
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ġ' => Some(include!("../res_rasterized_characters/0x121_h32_wBold.txt")),
+        'ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            33,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ģ' / 0x122
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ģ' => Some(include!("../res_rasterized_characters/0x122_h32_wBold.txt")),
+        'Ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            34,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ģ' / 0x123
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ģ' => Some(include!("../res_rasterized_characters/0x123_h32_wBold.txt")),
+        'ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            35,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĥ' / 0x124
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĥ' => Some(include!("../res_rasterized_characters/0x124_h32_wBold.txt")),
+        'Ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            36,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĥ' / 0x125
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĥ' => Some(include!("../res_rasterized_characters/0x125_h32_wBold.txt")),
+        'ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            37,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ħ' / 0x126
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ħ' => Some(include!("../res_rasterized_characters/0x126_h32_wBold.txt")),
+        'Ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            38,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ħ' / 0x127
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ħ' => Some(include!("../res_rasterized_characters/0x127_h32_wBold.txt")),
+        'ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            39,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĩ' / 0x128
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĩ' => Some(include!("../res_rasterized_characters/0x128_h32_wBold.txt")),
+        'Ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            40,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĩ' / 0x129
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĩ' => Some(include!("../res_rasterized_characters/0x129_h32_wBold.txt")),
+        'ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            41,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ī' / 0x12a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ī' => Some(include!("../res_rasterized_characters/0x12a_h32_wBold.txt")),
+        'Ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            42,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ī' / 0x12b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ī' => Some(include!("../res_rasterized_characters/0x12b_h32_wBold.txt")),
+        'ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            43,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĭ' / 0x12c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĭ' => Some(include!("../res_rasterized_characters/0x12c_h32_wBold.txt")),
+        'Ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            44,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĭ' / 0x12d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĭ' => Some(include!("../res_rasterized_characters/0x12d_h32_wBold.txt")),
+        'ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            45,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Į' / 0x12e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Į' => Some(include!("../res_rasterized_characters/0x12e_h32_wBold.txt")),
+        'Į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            46,
+            RASTER_HEIGHT,
+        )),
         // letter: 'į' / 0x12f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'į' => Some(include!("../res_rasterized_characters/0x12f_h32_wBold.txt")),
+        'į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            47,
+            RASTER_HEIGHT,
+        )),
         // letter: 'İ' / 0x130
         #[cfg(feature = "unicode-latin-extended-a")]
-        'İ' => Some(include!("../res_rasterized_characters/0x130_h32_wBold.txt")),
+        'İ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            48,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ı' / 0x131
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ı' => Some(include!("../res_rasterized_characters/0x131_h32_wBold.txt")),
+        'ı' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            49,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĳ' / 0x132
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĳ' => Some(include!("../res_rasterized_characters/0x132_h32_wBold.txt")),
+        'Ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            50,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĳ' / 0x133
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĳ' => Some(include!("../res_rasterized_characters/0x133_h32_wBold.txt")),
+        'ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            51,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĵ' / 0x134
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĵ' => Some(include!("../res_rasterized_characters/0x134_h32_wBold.txt")),
+        'Ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            52,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĵ' / 0x135
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĵ' => Some(include!("../res_rasterized_characters/0x135_h32_wBold.txt")),
+        'ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            53,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ķ' / 0x136
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ķ' => Some(include!("../res_rasterized_characters/0x136_h32_wBold.txt")),
+        'Ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            54,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ķ' / 0x137
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ķ' => Some(include!("../res_rasterized_characters/0x137_h32_wBold.txt")),
+        'ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            55,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĸ' / 0x138
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĸ' => Some(include!("../res_rasterized_characters/0x138_h32_wBold.txt")),
+        'ĸ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            56,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ĺ' / 0x139
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĺ' => Some(include!("../res_rasterized_characters/0x139_h32_wBold.txt")),
+        'Ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            57,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ĺ' / 0x13a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĺ' => Some(include!("../res_rasterized_characters/0x13a_h32_wBold.txt")),
+        'ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            58,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ļ' / 0x13b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ļ' => Some(include!("../res_rasterized_characters/0x13b_h32_wBold.txt")),
+        'Ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            59,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ļ' / 0x13c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ļ' => Some(include!("../res_rasterized_characters/0x13c_h32_wBold.txt")),
+        'ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ľ' / 0x13d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ľ' / 0x13e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŀ' / 0x13f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŀ' / 0x140
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ł' / 0x141
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ł' / 0x142
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ń' / 0x143
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ń' / 0x144
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ņ' / 0x145
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ņ' / 0x146
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ň' / 0x147
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ň' / 0x148
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŉ' / 0x149
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŋ' / 0x14a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŋ' / 0x14b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ō' / 0x14c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ō' / 0x14d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŏ' / 0x14e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŏ' / 0x14f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ő' / 0x150
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ő' / 0x151
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Œ' / 0x152
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'œ' / 0x153
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŕ' / 0x154
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŕ' / 0x155
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŗ' / 0x156
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŗ' / 0x157
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ř' / 0x158
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ř' / 0x159
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ś' / 0x15a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ś' / 0x15b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŝ' / 0x15c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŝ' / 0x15d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ş' / 0x15e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ş' / 0x15f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            95,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Š' / 0x160
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            96,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'š' / 0x161
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            97,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ţ' / 0x162
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            98,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ţ' / 0x163
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            99,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ť' / 0x164
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            100,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ť' / 0x165
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            101,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŧ' / 0x166
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            102,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŧ' / 0x167
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            103,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ũ' / 0x168
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            104,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ũ' / 0x169
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            105,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ū' / 0x16a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            106,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ū' / 0x16b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            107,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŭ' / 0x16c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            108,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŭ' / 0x16d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            109,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ů' / 0x16e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            110,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ů' / 0x16f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            111,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ű' / 0x170
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            112,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ű' / 0x171
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            113,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ų' / 0x172
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            114,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ų' / 0x173
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            115,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŵ' / 0x174
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            116,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŵ' / 0x175
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            117,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŷ' / 0x176
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            118,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŷ' / 0x177
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            119,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ÿ' / 0x178
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            120,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ź' / 0x179
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            121,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ź' / 0x17a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            122,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ż' / 0x17b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            123,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ż' / 0x17c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            124,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ž' / 0x17d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            125,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ž' / 0x17e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            126,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ſ' / 0x17f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ſ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            127,
+            RASTER_HEIGHT,
+        )),
+        // letter: '�' / 0xfffd
+        #[cfg(feature = "unicode-specials")]
+        '�' => Some(crate::glyph_data::raster_at(
+            &UNICODE_SPECIALS,
+            0,
+            RASTER_HEIGHT,
+        )),
+        _ => None,
+    }
+}
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-basic-latin` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-basic-latin"))]
+static UNICODE_BASIC_LATIN_SUBPIXEL: [&[u8]; 95 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-basic-latin_subpixel.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-basic-latin_subpixel_index.bin"),
+    3 * RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-latin-1-supplement"))]
+static UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL: [&[u8]; 94 * RASTER_HEIGHT] =
+    crate::glyph_data::intern_rows(
+        include_bytes!(
+            "../res_rasterized_characters/h32_wBold_unicode-latin-1-supplement_subpixel.bin"
+        ),
+        include_bytes!(
+            "../res_rasterized_characters/h32_wBold_unicode-latin-1-supplement_subpixel_index.bin"
+        ),
+        3 * RASTER_WIDTH,
+    );
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-extended-a` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-latin-extended-a"))]
+static UNICODE_LATIN_EXTENDED_A_SUBPIXEL: [&[u8]; 128 * RASTER_HEIGHT] =
+    crate::glyph_data::intern_rows(
+        include_bytes!(
+            "../res_rasterized_characters/h32_wBold_unicode-latin-extended-a_subpixel.bin"
+        ),
+        include_bytes!(
+            "../res_rasterized_characters/h32_wBold_unicode-latin-extended-a_subpixel_index.bin"
+        ),
+        3 * RASTER_WIDTH,
+    );
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-specials` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-specials"))]
+static UNICODE_SPECIALS_SUBPIXEL: [&[u8]; RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-specials_subpixel.bin"),
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-specials_subpixel_index.bin"),
+    3 * RASTER_WIDTH,
+);
+
+/// Returns the subpixel raster of the given character for font weight bold and font size
+/// 23px. Each row contains three bytes per pixel with the unfiltered coverage of the
+/// subpixels from left to right.
+#[cfg(feature = "subpixel")]
+#[inline]
+pub const fn get_char_subpixel(c: char) -> Option<&'static [&'static [u8]]> {
+    match c {
+        // letter: ' ' / 0x20
+        #[cfg(feature = "unicode-basic-latin")]
+        ' ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: '!' / 0x21
+        #[cfg(feature = "unicode-basic-latin")]
+        '!' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: '"' / 0x22
+        #[cfg(feature = "unicode-basic-latin")]
+        '"' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: '#' / 0x23
+        #[cfg(feature = "unicode-basic-latin")]
+        '#' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: '$' / 0x24
+        #[cfg(feature = "unicode-basic-latin")]
+        '$' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: '%' / 0x25
+        #[cfg(feature = "unicode-basic-latin")]
+        '%' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: '&' / 0x26
+        #[cfg(feature = "unicode-basic-latin")]
+        '&' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: ''' / 0x27
+        #[cfg(feature = "unicode-basic-latin")]
+        '\'' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: '(' / 0x28
+        #[cfg(feature = "unicode-basic-latin")]
+        '(' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: ')' / 0x29
+        #[cfg(feature = "unicode-basic-latin")]
+        ')' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: '*' / 0x2a
+        #[cfg(feature = "unicode-basic-latin")]
+        '*' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: '+' / 0x2b
+        #[cfg(feature = "unicode-basic-latin")]
+        '+' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: ',' / 0x2c
+        #[cfg(feature = "unicode-basic-latin")]
+        ',' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: '-' / 0x2d
+        #[cfg(feature = "unicode-basic-latin")]
+        '-' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: '.' / 0x2e
+        #[cfg(feature = "unicode-basic-latin")]
+        '.' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: '/' / 0x2f
+        #[cfg(feature = "unicode-basic-latin")]
+        '/' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: '0' / 0x30
+        #[cfg(feature = "unicode-basic-latin")]
+        '0' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: '1' / 0x31
+        #[cfg(feature = "unicode-basic-latin")]
+        '1' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: '2' / 0x32
+        #[cfg(feature = "unicode-basic-latin")]
+        '2' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: '3' / 0x33
+        #[cfg(feature = "unicode-basic-latin")]
+        '3' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: '4' / 0x34
+        #[cfg(feature = "unicode-basic-latin")]
+        '4' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: '5' / 0x35
+        #[cfg(feature = "unicode-basic-latin")]
+        '5' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: '6' / 0x36
+        #[cfg(feature = "unicode-basic-latin")]
+        '6' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: '7' / 0x37
+        #[cfg(feature = "unicode-basic-latin")]
+        '7' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: '8' / 0x38
+        #[cfg(feature = "unicode-basic-latin")]
+        '8' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: '9' / 0x39
+        #[cfg(feature = "unicode-basic-latin")]
+        '9' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: ':' / 0x3a
+        #[cfg(feature = "unicode-basic-latin")]
+        ':' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: ';' / 0x3b
+        #[cfg(feature = "unicode-basic-latin")]
+        ';' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: '<' / 0x3c
+        #[cfg(feature = "unicode-basic-latin")]
+        '<' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: '=' / 0x3d
+        #[cfg(feature = "unicode-basic-latin")]
+        '=' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: '>' / 0x3e
+        #[cfg(feature = "unicode-basic-latin")]
+        '>' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: '?' / 0x3f
+        #[cfg(feature = "unicode-basic-latin")]
+        '?' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: '@' / 0x40
+        #[cfg(feature = "unicode-basic-latin")]
+        '@' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'A' / 0x41
+        #[cfg(feature = "unicode-basic-latin")]
+        'A' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'B' / 0x42
+        #[cfg(feature = "unicode-basic-latin")]
+        'B' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'C' / 0x43
+        #[cfg(feature = "unicode-basic-latin")]
+        'C' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'D' / 0x44
+        #[cfg(feature = "unicode-basic-latin")]
+        'D' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'E' / 0x45
+        #[cfg(feature = "unicode-basic-latin")]
+        'E' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'F' / 0x46
+        #[cfg(feature = "unicode-basic-latin")]
+        'F' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'G' / 0x47
+        #[cfg(feature = "unicode-basic-latin")]
+        'G' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'H' / 0x48
+        #[cfg(feature = "unicode-basic-latin")]
+        'H' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'I' / 0x49
+        #[cfg(feature = "unicode-basic-latin")]
+        'I' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'J' / 0x4a
+        #[cfg(feature = "unicode-basic-latin")]
+        'J' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'K' / 0x4b
+        #[cfg(feature = "unicode-basic-latin")]
+        'K' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'L' / 0x4c
+        #[cfg(feature = "unicode-basic-latin")]
+        'L' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'M' / 0x4d
+        #[cfg(feature = "unicode-basic-latin")]
+        'M' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'N' / 0x4e
+        #[cfg(feature = "unicode-basic-latin")]
+        'N' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'O' / 0x4f
+        #[cfg(feature = "unicode-basic-latin")]
+        'O' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'P' / 0x50
+        #[cfg(feature = "unicode-basic-latin")]
+        'P' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Q' / 0x51
+        #[cfg(feature = "unicode-basic-latin")]
+        'Q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'R' / 0x52
+        #[cfg(feature = "unicode-basic-latin")]
+        'R' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'S' / 0x53
+        #[cfg(feature = "unicode-basic-latin")]
+        'S' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'T' / 0x54
+        #[cfg(feature = "unicode-basic-latin")]
+        'T' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'U' / 0x55
+        #[cfg(feature = "unicode-basic-latin")]
+        'U' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'V' / 0x56
+        #[cfg(feature = "unicode-basic-latin")]
+        'V' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'W' / 0x57
+        #[cfg(feature = "unicode-basic-latin")]
+        'W' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'X' / 0x58
+        #[cfg(feature = "unicode-basic-latin")]
+        'X' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Y' / 0x59
+        #[cfg(feature = "unicode-basic-latin")]
+        'Y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Z' / 0x5a
+        #[cfg(feature = "unicode-basic-latin")]
+        'Z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: '[' / 0x5b
+        #[cfg(feature = "unicode-basic-latin")]
+        '[' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: '\' / 0x5c
+        #[cfg(feature = "unicode-basic-latin")]
+        '\\' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: ']' / 0x5d
+        #[cfg(feature = "unicode-basic-latin")]
+        ']' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: '^' / 0x5e
+        #[cfg(feature = "unicode-basic-latin")]
+        '^' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: '_' / 0x5f
+        #[cfg(feature = "unicode-basic-latin")]
+        '_' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: '`' / 0x60
+        #[cfg(feature = "unicode-basic-latin")]
+        '`' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'a' / 0x61
+        #[cfg(feature = "unicode-basic-latin")]
+        'a' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'b' / 0x62
+        #[cfg(feature = "unicode-basic-latin")]
+        'b' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'c' / 0x63
+        #[cfg(feature = "unicode-basic-latin")]
+        'c' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'd' / 0x64
+        #[cfg(feature = "unicode-basic-latin")]
+        'd' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'e' / 0x65
+        #[cfg(feature = "unicode-basic-latin")]
+        'e' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'f' / 0x66
+        #[cfg(feature = "unicode-basic-latin")]
+        'f' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'g' / 0x67
+        #[cfg(feature = "unicode-basic-latin")]
+        'g' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'h' / 0x68
+        #[cfg(feature = "unicode-basic-latin")]
+        'h' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'i' / 0x69
+        #[cfg(feature = "unicode-basic-latin")]
+        'i' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'j' / 0x6a
+        #[cfg(feature = "unicode-basic-latin")]
+        'j' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'k' / 0x6b
+        #[cfg(feature = "unicode-basic-latin")]
+        'k' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'l' / 0x6c
+        #[cfg(feature = "unicode-basic-latin")]
+        'l' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'm' / 0x6d
+        #[cfg(feature = "unicode-basic-latin")]
+        'm' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'n' / 0x6e
+        #[cfg(feature = "unicode-basic-latin")]
+        'n' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'o' / 0x6f
+        #[cfg(feature = "unicode-basic-latin")]
+        'o' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'p' / 0x70
+        #[cfg(feature = "unicode-basic-latin")]
+        'p' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'q' / 0x71
+        #[cfg(feature = "unicode-basic-latin")]
+        'q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'r' / 0x72
+        #[cfg(feature = "unicode-basic-latin")]
+        'r' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 's' / 0x73
+        #[cfg(feature = "unicode-basic-latin")]
+        's' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 't' / 0x74
+        #[cfg(feature = "unicode-basic-latin")]
+        't' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'u' / 0x75
+        #[cfg(feature = "unicode-basic-latin")]
+        'u' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'v' / 0x76
+        #[cfg(feature = "unicode-basic-latin")]
+        'v' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'w' / 0x77
+        #[cfg(feature = "unicode-basic-latin")]
+        'w' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'x' / 0x78
+        #[cfg(feature = "unicode-basic-latin")]
+        'x' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'y' / 0x79
+        #[cfg(feature = "unicode-basic-latin")]
+        'y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'z' / 0x7a
+        #[cfg(feature = "unicode-basic-latin")]
+        'z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: '{' / 0x7b
+        #[cfg(feature = "unicode-basic-latin")]
+        '{' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: '|' / 0x7c
+        #[cfg(feature = "unicode-basic-latin")]
+        '|' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: '}' / 0x7d
+        #[cfg(feature = "unicode-basic-latin")]
+        '}' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: '~' / 0x7e
+        #[cfg(feature = "unicode-basic-latin")]
+        '~' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¡' / 0xa1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¡' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¢' / 0xa2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¢' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: '£' / 0xa3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '£' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¤' / 0xa4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¤' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¥' / 0xa5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¥' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¦' / 0xa6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¦' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: '§' / 0xa7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '§' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¨' / 0xa8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¨' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: '©' / 0xa9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '©' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ª' / 0xaa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ª' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: '«' / 0xab
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '«' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¬' / 0xac
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¬' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: '®' / 0xae
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '®' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¯' / 0xaf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¯' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: '°' / 0xb0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '°' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: '±' / 0xb1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '±' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: '²' / 0xb2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '²' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: '³' / 0xb3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '³' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: '´' / 0xb4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '´' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'µ' / 0xb5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'µ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¶' / 0xb6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¶' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: '·' / 0xb7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '·' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¸' / 0xb8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¸' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¹' / 0xb9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¹' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'º' / 0xba
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'º' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: '»' / 0xbb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '»' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¼' / 0xbc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¼' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: '½' / 0xbd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '½' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¾' / 0xbe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¾' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¿' / 0xbf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¿' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'À' / 0xc0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'À' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Á' / 0xc1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Â' / 0xc2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ã' / 0xc3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ä' / 0xc4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Å' / 0xc5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Æ' / 0xc6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ç' / 0xc7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'È' / 0xc8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'È' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'É' / 0xc9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'É' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ê' / 0xca
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ë' / 0xcb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ì' / 0xcc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Í' / 0xcd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Î' / 0xce
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ï' / 0xcf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ð' / 0xd0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ñ' / 0xd1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ò' / 0xd2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ó' / 0xd3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ô' / 0xd4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Õ' / 0xd5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ö' / 0xd6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: '×' / 0xd7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '×' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ø' / 0xd8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ù' / 0xd9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ú' / 0xda
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Û' / 0xdb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ü' / 0xdc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ý' / 0xdd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Þ' / 0xde
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ß' / 0xdf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ß' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'à' / 0xe0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'à' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'á' / 0xe1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'â' / 0xe2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ã' / 0xe3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ä' / 0xe4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'å' / 0xe5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'æ' / 0xe6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ç' / 0xe7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'è' / 0xe8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'è' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'é' / 0xe9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'é' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ê' / 0xea
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ë' / 0xeb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ì' / 0xec
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'í' / 0xed
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'î' / 0xee
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ï' / 0xef
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ð' / 0xf0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ñ' / 0xf1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ò' / 0xf2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ó' / 0xf3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ô' / 0xf4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'õ' / 0xf5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ö' / 0xf6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: '÷' / 0xf7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '÷' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ø' / 0xf8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ù' / 0xf9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ú' / 0xfa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'û' / 0xfb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ü' / 0xfc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ý' / 0xfd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'þ' / 0xfe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ÿ' / 0xff
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ā' / 0x100
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ā' / 0x101
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ă' / 0x102
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ă' / 0x103
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ą' / 0x104
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ą' / 0x105
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ć' / 0x106
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ć' / 0x107
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĉ' / 0x108
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĉ' / 0x109
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ċ' / 0x10a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ċ' / 0x10b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Č' / 0x10c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'č' / 0x10d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ď' / 0x10e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ď' / 0x10f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Đ' / 0x110
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'đ' / 0x111
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ē' / 0x112
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ē' / 0x113
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĕ' / 0x114
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĕ' / 0x115
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ė' / 0x116
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ė' / 0x117
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ę' / 0x118
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ę' / 0x119
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ě' / 0x11a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ě' / 0x11b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĝ' / 0x11c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĝ' / 0x11d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ğ' / 0x11e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ğ' / 0x11f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ġ' / 0x120
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ġ' / 0x121
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ģ' / 0x122
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ģ' / 0x123
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĥ' / 0x124
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĥ' / 0x125
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ħ' / 0x126
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ħ' / 0x127
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĩ' / 0x128
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĩ' / 0x129
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ī' / 0x12a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ī' / 0x12b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĭ' / 0x12c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĭ' / 0x12d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Į' / 0x12e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'į' / 0x12f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'İ' / 0x130
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'İ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ı' / 0x131
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ı' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĳ' / 0x132
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĳ' / 0x133
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĵ' / 0x134
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĵ' / 0x135
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ķ' / 0x136
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ķ' / 0x137
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĸ' / 0x138
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĸ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĺ' / 0x139
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĺ' / 0x13a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ļ' / 0x13b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ļ' / 0x13c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ľ' / 0x13d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ľ' / 0x13e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŀ' / 0x13f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŀ' / 0x140
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ł' / 0x141
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ł' / 0x142
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ń' / 0x143
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ń' / 0x144
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ņ' / 0x145
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ņ' / 0x146
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ň' / 0x147
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ň' / 0x148
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŉ' / 0x149
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŋ' / 0x14a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŋ' / 0x14b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ō' / 0x14c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ō' / 0x14d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŏ' / 0x14e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŏ' / 0x14f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ő' / 0x150
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ő' / 0x151
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Œ' / 0x152
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'œ' / 0x153
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŕ' / 0x154
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŕ' / 0x155
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŗ' / 0x156
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŗ' / 0x157
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ř' / 0x158
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ř' / 0x159
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ś' / 0x15a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ś' / 0x15b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŝ' / 0x15c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŝ' / 0x15d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ş' / 0x15e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ş' / 0x15f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            95,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Š' / 0x160
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            96,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'š' / 0x161
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            97,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ţ' / 0x162
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            98,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ţ' / 0x163
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            99,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ť' / 0x164
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            100,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ť' / 0x165
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            101,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŧ' / 0x166
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            102,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŧ' / 0x167
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            103,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ũ' / 0x168
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            104,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ũ' / 0x169
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            105,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ū' / 0x16a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            106,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ū' / 0x16b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            107,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŭ' / 0x16c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            108,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŭ' / 0x16d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            109,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ů' / 0x16e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            110,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ů' / 0x16f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            111,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ű' / 0x170
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            112,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ű' / 0x171
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            113,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ų' / 0x172
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            114,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ų' / 0x173
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            115,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŵ' / 0x174
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            116,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŵ' / 0x175
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            117,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŷ' / 0x176
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            118,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŷ' / 0x177
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            119,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ÿ' / 0x178
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            120,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ź' / 0x179
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            121,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ź' / 0x17a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            122,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ż' / 0x17b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            123,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ż' / 0x17c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            124,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ž' / 0x17d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            125,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ž' / 0x17e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            126,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ſ' / 0x17f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ſ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            127,
+            RASTER_HEIGHT,
+        )),
+        // letter: '�' / 0xfffd
+        #[cfg(feature = "unicode-specials")]
+        '�' => Some(crate::glyph_data::raster_at(
+            &UNICODE_SPECIALS_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        _ => None,
+    }
+}
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-basic-latin`.
+#[cfg(all(feature = "compressed", feature = "unicode-basic-latin"))]
+static UNICODE_BASIC_LATIN_COMPRESSED: &[u8] =
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-basic-latin_compressed.bin");
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement`.
+#[cfg(all(feature = "compressed", feature = "unicode-latin-1-supplement"))]
+static UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED: &[u8] = include_bytes!(
+    "../res_rasterized_characters/h32_wBold_unicode-latin-1-supplement_compressed.bin"
+);
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-latin-extended-a`.
+#[cfg(all(feature = "compressed", feature = "unicode-latin-extended-a"))]
+static UNICODE_LATIN_EXTENDED_A_COMPRESSED: &[u8] = include_bytes!(
+    "../res_rasterized_characters/h32_wBold_unicode-latin-extended-a_compressed.bin"
+);
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-specials`.
+#[cfg(all(feature = "compressed", feature = "unicode-specials"))]
+static UNICODE_SPECIALS_COMPRESSED: &[u8] =
+    include_bytes!("../res_rasterized_characters/h32_wBold_unicode-specials_compressed.bin");
+
+/// Returns the run-length encoded raster of the given character for font weight bold
+/// and font size 23px. See [`crate::CompressedChar`] for the encoding.
+#[cfg(feature = "compressed")]
+#[inline]
+pub const fn get_char_compressed(c: char) -> Option<&'static [u8]> {
+    match c {
+        // letter: ' ' / 0x20
+        #[cfg(feature = "unicode-basic-latin")]
+        ' ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            0,
+            8,
+        )),
+        // letter: '!' / 0x21
+        #[cfg(feature = "unicode-basic-latin")]
+        '!' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8,
+            132,
+        )),
+        // letter: '"' / 0x22
+        #[cfg(feature = "unicode-basic-latin")]
+        '"' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            132,
+            214,
+        )),
+        // letter: '#' / 0x23
+        #[cfg(feature = "unicode-basic-latin")]
+        '#' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            214,
+            409,
+        )),
+        // letter: '$' / 0x24
+        #[cfg(feature = "unicode-basic-latin")]
+        '$' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            409,
+            605,
+        )),
+        // letter: '%' / 0x25
+        #[cfg(feature = "unicode-basic-latin")]
+        '%' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            605,
+            838,
+        )),
+        // letter: '&' / 0x26
+        #[cfg(feature = "unicode-basic-latin")]
+        '&' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            838,
+            1039,
+        )),
+        // letter: ''' / 0x27
+        #[cfg(feature = "unicode-basic-latin")]
+        '\'' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1039,
+            1094,
+        )),
+        // letter: '(' / 0x28
+        #[cfg(feature = "unicode-basic-latin")]
+        '(' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1094,
+            1246,
+        )),
+        // letter: ')' / 0x29
+        #[cfg(feature = "unicode-basic-latin")]
+        ')' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1246,
+            1398,
+        )),
+        // letter: '*' / 0x2a
+        #[cfg(feature = "unicode-basic-latin")]
+        '*' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1398,
+            1513,
+        )),
+        // letter: '+' / 0x2b
+        #[cfg(feature = "unicode-basic-latin")]
+        '+' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1513,
+            1617,
+        )),
+        // letter: ',' / 0x2c
+        #[cfg(feature = "unicode-basic-latin")]
+        ',' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1617,
+            1679,
+        )),
+        // letter: '-' / 0x2d
+        #[cfg(feature = "unicode-basic-latin")]
+        '-' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1679,
+            1717,
+        )),
+        // letter: '.' / 0x2e
+        #[cfg(feature = "unicode-basic-latin")]
+        '.' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1717,
+            1760,
+        )),
+        // letter: '/' / 0x2f
+        #[cfg(feature = "unicode-basic-latin")]
+        '/' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1760,
+            1890,
+        )),
+        // letter: '0' / 0x30
+        #[cfg(feature = "unicode-basic-latin")]
+        '0' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1890,
+            2112,
+        )),
+        // letter: '1' / 0x31
+        #[cfg(feature = "unicode-basic-latin")]
+        '1' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2112,
+            2266,
+        )),
+        // letter: '2' / 0x32
+        #[cfg(feature = "unicode-basic-latin")]
+        '2' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2266,
+            2430,
+        )),
+        // letter: '3' / 0x33
+        #[cfg(feature = "unicode-basic-latin")]
+        '3' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2430,
+            2616,
+        )),
+        // letter: '4' / 0x34
+        #[cfg(feature = "unicode-basic-latin")]
+        '4' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2616,
+            2776,
+        )),
+        // letter: '5' / 0x35
+        #[cfg(feature = "unicode-basic-latin")]
+        '5' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2776,
+            2948,
+        )),
+        // letter: '6' / 0x36
+        #[cfg(feature = "unicode-basic-latin")]
+        '6' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2948,
+            3156,
+        )),
+        // letter: '7' / 0x37
+        #[cfg(feature = "unicode-basic-latin")]
+        '7' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3156,
+            3296,
+        )),
+        // letter: '8' / 0x38
+        #[cfg(feature = "unicode-basic-latin")]
+        '8' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3296,
+            3511,
+        )),
+        // letter: '9' / 0x39
+        #[cfg(feature = "unicode-basic-latin")]
+        '9' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3511,
+            3721,
+        )),
+        // letter: ':' / 0x3a
+        #[cfg(feature = "unicode-basic-latin")]
+        ':' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3721,
+            3799,
+        )),
+        // letter: ';' / 0x3b
+        #[cfg(feature = "unicode-basic-latin")]
+        ';' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3799,
+            3897,
+        )),
+        // letter: '<' / 0x3c
+        #[cfg(feature = "unicode-basic-latin")]
+        '<' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3897,
+            4010,
+        )),
+        // letter: '=' / 0x3d
+        #[cfg(feature = "unicode-basic-latin")]
+        '=' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4010,
+            4067,
+        )),
+        // letter: '>' / 0x3e
+        #[cfg(feature = "unicode-basic-latin")]
+        '>' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4067,
+            4180,
+        )),
+        // letter: '?' / 0x3f
+        #[cfg(feature = "unicode-basic-latin")]
+        '?' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4180,
+            4327,
+        )),
+        // letter: '@' / 0x40
+        #[cfg(feature = "unicode-basic-latin")]
+        '@' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4327,
+            4577,
+        )),
+        // letter: 'A' / 0x41
+        #[cfg(feature = "unicode-basic-latin")]
+        'A' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4577,
+            4769,
+        )),
+        // letter: 'B' / 0x42
+        #[cfg(feature = "unicode-basic-latin")]
+        'B' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4769,
+            4977,
+        )),
+        // letter: 'C' / 0x43
+        #[cfg(feature = "unicode-basic-latin")]
+        'C' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4977,
+            5143,
+        )),
+        // letter: 'D' / 0x44
+        #[cfg(feature = "unicode-basic-latin")]
+        'D' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            5143,
+            5354,
+        )),
+        // letter: 'E' / 0x45
+        #[cfg(feature = "unicode-basic-latin")]
+        'E' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            5354,
+            5502,
+        )),
+        // letter: 'F' / 0x46
+        #[cfg(feature = "unicode-basic-latin")]
+        'F' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            5502,
+            5650,
+        )),
+        // letter: 'G' / 0x47
+        #[cfg(feature = "unicode-basic-latin")]
+        'G' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            5650,
+            5856,
+        )),
+        // letter: 'H' / 0x48
+        #[cfg(feature = "unicode-basic-latin")]
+        'H' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            5856,
+            6068,
+        )),
+        // letter: 'I' / 0x49
+        #[cfg(feature = "unicode-basic-latin")]
+        'I' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6068,
+            6205,
+        )),
+        // letter: 'J' / 0x4a
+        #[cfg(feature = "unicode-basic-latin")]
+        'J' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6205,
+            6354,
+        )),
+        // letter: 'K' / 0x4b
+        #[cfg(feature = "unicode-basic-latin")]
+        'K' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6354,
+            6573,
+        )),
+        // letter: 'L' / 0x4c
+        #[cfg(feature = "unicode-basic-latin")]
+        'L' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6573,
+            6700,
+        )),
+        // letter: 'M' / 0x4d
+        #[cfg(feature = "unicode-basic-latin")]
+        'M' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6700,
+            6933,
+        )),
+        // letter: 'N' / 0x4e
+        #[cfg(feature = "unicode-basic-latin")]
+        'N' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            6933,
+            7172,
+        )),
+        // letter: 'O' / 0x4f
+        #[cfg(feature = "unicode-basic-latin")]
+        'O' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            7172,
+            7391,
+        )),
+        // letter: 'P' / 0x50
+        #[cfg(feature = "unicode-basic-latin")]
+        'P' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            7391,
+            7574,
+        )),
+        // letter: 'Q' / 0x51
+        #[cfg(feature = "unicode-basic-latin")]
+        'Q' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            7574,
+            7823,
+        )),
+        // letter: 'R' / 0x52
+        #[cfg(feature = "unicode-basic-latin")]
+        'R' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            7823,
+            8038,
+        )),
+        // letter: 'S' / 0x53
+        #[cfg(feature = "unicode-basic-latin")]
+        'S' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8038,
+            8219,
+        )),
+        // letter: 'T' / 0x54
+        #[cfg(feature = "unicode-basic-latin")]
+        'T' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8219,
+            8344,
+        )),
+        // letter: 'U' / 0x55
+        #[cfg(feature = "unicode-basic-latin")]
+        'U' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8344,
+            8572,
+        )),
+        // letter: 'V' / 0x56
+        #[cfg(feature = "unicode-basic-latin")]
+        'V' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8572,
+            8772,
+        )),
+        // letter: 'W' / 0x57
+        #[cfg(feature = "unicode-basic-latin")]
+        'W' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            8772,
+            9003,
+        )),
+        // letter: 'X' / 0x58
+        #[cfg(feature = "unicode-basic-latin")]
+        'X' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9003,
+            9205,
+        )),
+        // letter: 'Y' / 0x59
+        #[cfg(feature = "unicode-basic-latin")]
+        'Y' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9205,
+            9379,
+        )),
+        // letter: 'Z' / 0x5a
+        #[cfg(feature = "unicode-basic-latin")]
+        'Z' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9379,
+            9521,
+        )),
+        // letter: '[' / 0x5b
+        #[cfg(feature = "unicode-basic-latin")]
+        '[' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9521,
+            9678,
+        )),
+        // letter: '\' / 0x5c
+        #[cfg(feature = "unicode-basic-latin")]
+        '\\' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9678,
+            9809,
+        )),
+        // letter: ']' / 0x5d
+        #[cfg(feature = "unicode-basic-latin")]
+        ']' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9809,
+            9968,
+        )),
+        // letter: '^' / 0x5e
+        #[cfg(feature = "unicode-basic-latin")]
+        '^' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            9968,
+            10088,
+        )),
+        // letter: '_' / 0x5f
+        #[cfg(feature = "unicode-basic-latin")]
+        '_' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10088,
+            10112,
+        )),
+        // letter: '`' / 0x60
+        #[cfg(feature = "unicode-basic-latin")]
+        '`' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10112,
+            10156,
+        )),
+        // letter: 'a' / 0x61
+        #[cfg(feature = "unicode-basic-latin")]
+        'a' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10156,
+            10324,
+        )),
+        // letter: 'b' / 0x62
+        #[cfg(feature = "unicode-basic-latin")]
+        'b' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10324,
+            10538,
+        )),
+        // letter: 'c' / 0x63
+        #[cfg(feature = "unicode-basic-latin")]
+        'c' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10538,
+            10680,
+        )),
+        // letter: 'd' / 0x64
+        #[cfg(feature = "unicode-basic-latin")]
+        'd' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10680,
+            10893,
+        )),
+        // letter: 'e' / 0x65
+        #[cfg(feature = "unicode-basic-latin")]
+        'e' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            10893,
+            11050,
+        )),
+        // letter: 'f' / 0x66
+        #[cfg(feature = "unicode-basic-latin")]
+        'f' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11050,
+            11217,
+        )),
+        // letter: 'g' / 0x67
+        #[cfg(feature = "unicode-basic-latin")]
+        'g' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11217,
+            11460,
+        )),
+        // letter: 'h' / 0x68
+        #[cfg(feature = "unicode-basic-latin")]
+        'h' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11460,
+            11668,
+        )),
+        // letter: 'i' / 0x69
+        #[cfg(feature = "unicode-basic-latin")]
+        'i' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11668,
+            11802,
+        )),
+        // letter: 'j' / 0x6a
+        #[cfg(feature = "unicode-basic-latin")]
+        'j' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11802,
+            11988,
+        )),
+        // letter: 'k' / 0x6b
+        #[cfg(feature = "unicode-basic-latin")]
+        'k' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            11988,
+            12198,
+        )),
+        // letter: 'l' / 0x6c
+        #[cfg(feature = "unicode-basic-latin")]
+        'l' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            12198,
+            12339,
+        )),
+        // letter: 'm' / 0x6d
+        #[cfg(feature = "unicode-basic-latin")]
+        'm' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            12339,
+            12525,
+        )),
+        // letter: 'n' / 0x6e
+        #[cfg(feature = "unicode-basic-latin")]
+        'n' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            12525,
+            12700,
+        )),
+        // letter: 'o' / 0x6f
+        #[cfg(feature = "unicode-basic-latin")]
+        'o' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            12700,
+            12875,
+        )),
+        // letter: 'p' / 0x70
+        #[cfg(feature = "unicode-basic-latin")]
+        'p' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            12875,
+            13096,
+        )),
+        // letter: 'q' / 0x71
+        #[cfg(feature = "unicode-basic-latin")]
+        'q' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13096,
+            13321,
+        )),
+        // letter: 'r' / 0x72
+        #[cfg(feature = "unicode-basic-latin")]
+        'r' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13321,
+            13449,
+        )),
+        // letter: 's' / 0x73
+        #[cfg(feature = "unicode-basic-latin")]
+        's' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13449,
+            13608,
+        )),
+        // letter: 't' / 0x74
+        #[cfg(feature = "unicode-basic-latin")]
+        't' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13608,
+            13752,
+        )),
+        // letter: 'u' / 0x75
+        #[cfg(feature = "unicode-basic-latin")]
+        'u' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13752,
+            13935,
+        )),
+        // letter: 'v' / 0x76
+        #[cfg(feature = "unicode-basic-latin")]
+        'v' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            13935,
+            14094,
+        )),
+        // letter: 'w' / 0x77
+        #[cfg(feature = "unicode-basic-latin")]
+        'w' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14094,
+            14276,
+        )),
+        // letter: 'x' / 0x78
+        #[cfg(feature = "unicode-basic-latin")]
+        'x' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14276,
+            14441,
+        )),
+        // letter: 'y' / 0x79
+        #[cfg(feature = "unicode-basic-latin")]
+        'y' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14441,
+            14647,
+        )),
+        // letter: 'z' / 0x7a
+        #[cfg(feature = "unicode-basic-latin")]
+        'z' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14647,
+            14762,
+        )),
+        // letter: '{' / 0x7b
+        #[cfg(feature = "unicode-basic-latin")]
+        '{' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14762,
+            14927,
+        )),
+        // letter: '|' / 0x7c
+        #[cfg(feature = "unicode-basic-latin")]
+        '|' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            14927,
+            15097,
+        )),
+        // letter: '}' / 0x7d
+        #[cfg(feature = "unicode-basic-latin")]
+        '}' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            15097,
+            15259,
+        )),
+        // letter: '~' / 0x7e
+        #[cfg(feature = "unicode-basic-latin")]
+        '~' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            15259,
+            15333,
+        )),
+        // letter: '¡' / 0xa1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¡' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            0,
+            128,
+        )),
+        // letter: '¢' / 0xa2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¢' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            128,
+            292,
+        )),
+        // letter: '£' / 0xa3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '£' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            292,
+            446,
+        )),
+        // letter: '¤' / 0xa4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¤' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            446,
+            588,
+        )),
+        // letter: '¥' / 0xa5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¥' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            588,
+            781,
+        )),
+        // letter: '¦' / 0xa6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¦' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            781,
+            923,
+        )),
+        // letter: '§' / 0xa7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '§' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            923,
+            1130,
+        )),
+        // letter: '¨' / 0xa8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¨' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1130,
+            1186,
+        )),
+        // letter: '©' / 0xa9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '©' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1186,
+            1359,
+        )),
+        // letter: 'ª' / 0xaa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ª' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1359,
+            1455,
+        )),
+        // letter: '«' / 0xab
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '«' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1455,
+            1597,
+        )),
+        // letter: '¬' / 0xac
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¬' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1597,
+            1661,
+        )),
+        // letter: '®' / 0xae
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '®' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1661,
+            1839,
+        )),
+        // letter: '¯' / 0xaf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¯' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1839,
+            1869,
+        )),
+        // letter: '°' / 0xb0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '°' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1869,
+            1951,
+        )),
+        // letter: '±' / 0xb1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '±' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1951,
+            2074,
+        )),
+        // letter: '²' / 0xb2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '²' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2074,
+            2171,
+        )),
+        // letter: '³' / 0xb3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '³' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2171,
+            2277,
+        )),
+        // letter: '´' / 0xb4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '´' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2277,
+            2320,
+        )),
+        // letter: 'µ' / 0xb5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'µ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2320,
+            2532,
+        )),
+        // letter: '¶' / 0xb6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¶' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2532,
+            2740,
+        )),
+        // letter: '·' / 0xb7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '·' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2740,
+            2780,
+        )),
+        // letter: '¸' / 0xb8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¸' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2780,
+            2838,
+        )),
+        // letter: '¹' / 0xb9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¹' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2838,
+            2929,
+        )),
+        // letter: 'º' / 0xba
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'º' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2929,
+            3020,
+        )),
+        // letter: '»' / 0xbb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '»' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3020,
+            3161,
+        )),
+        // letter: '¼' / 0xbc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¼' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3161,
+            3367,
+        )),
+        // letter: '½' / 0xbd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '½' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3367,
+            3576,
+        )),
+        // letter: '¾' / 0xbe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¾' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3576,
+            3787,
+        )),
+        // letter: '¿' / 0xbf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¿' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3787,
+            3939,
+        )),
+        // letter: 'À' / 0xc0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'À' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3939,
+            4167,
+        )),
+        // letter: 'Á' / 0xc1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Á' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4167,
+            4395,
+        )),
+        // letter: 'Â' / 0xc2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Â' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4395,
+            4641,
+        )),
+        // letter: 'Ã' / 0xc3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ã' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4641,
+            4889,
+        )),
+        // letter: 'Ä' / 0xc4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ä' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4889,
+            5140,
+        )),
+        // letter: 'Å' / 0xc5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Å' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5140,
+            5388,
+        )),
+        // letter: 'Æ' / 0xc6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Æ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5388,
+            5586,
+        )),
+        // letter: 'Ç' / 0xc7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ç' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5586,
+            5792,
+        )),
+        // letter: 'È' / 0xc8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'È' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5792,
+            5979,
+        )),
+        // letter: 'É' / 0xc9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'É' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5979,
+            6165,
+        )),
+        // letter: 'Ê' / 0xca
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ê' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            6165,
+            6365,
+        )),
+        // letter: 'Ë' / 0xcb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ë' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            6365,
+            6573,
+        )),
+        // letter: 'Ì' / 0xcc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ì' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            6573,
+            6746,
+        )),
+        // letter: 'Í' / 0xcd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Í' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            6746,
+            6919,
+        )),
+        // letter: 'Î' / 0xce
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Î' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            6919,
+            7110,
+        )),
+        // letter: 'Ï' / 0xcf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ï' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            7110,
+            7306,
+        )),
+        // letter: 'Ð' / 0xd0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ð' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            7306,
+            7515,
+        )),
+        // letter: 'Ñ' / 0xd1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ñ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            7515,
+            7810,
+        )),
+        // letter: 'Ò' / 0xd2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ò' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            7810,
+            8065,
+        )),
+        // letter: 'Ó' / 0xd3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ó' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            8065,
+            8320,
+        )),
+        // letter: 'Ô' / 0xd4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ô' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            8320,
+            8593,
+        )),
+        // letter: 'Õ' / 0xd5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Õ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            8593,
+            8868,
+        )),
+        // letter: 'Ö' / 0xd6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ö' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            8868,
+            9146,
+        )),
+        // letter: '×' / 0xd7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '×' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            9146,
+            9276,
+        )),
+        // letter: 'Ø' / 0xd8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ø' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            9276,
+            9513,
+        )),
+        // letter: 'Ù' / 0xd9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ù' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            9513,
+            9777,
+        )),
+        // letter: 'Ú' / 0xda
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ú' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            9777,
+            10041,
+        )),
+        // letter: 'Û' / 0xdb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Û' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            10041,
+            10323,
+        )),
+        // letter: 'Ü' / 0xdc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ü' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            10323,
+            10610,
+        )),
+        // letter: 'Ý' / 0xdd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ý' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            10610,
+            10820,
+        )),
+        // letter: 'Þ' / 0xde
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Þ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            10820,
+            11008,
+        )),
+        // letter: 'ß' / 0xdf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ß' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            11008,
+            11250,
+        )),
+        // letter: 'à' / 0xe0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'à' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            11250,
+            11455,
+        )),
+        // letter: 'á' / 0xe1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'á' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            11455,
+            11657,
+        )),
+        // letter: 'â' / 0xe2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'â' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            11657,
+            11875,
+        )),
+        // letter: 'ã' / 0xe3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ã' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            11875,
+            12099,
+        )),
+        // letter: 'ä' / 0xe4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ä' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            12099,
+            12315,
+        )),
+        // letter: 'å' / 0xe5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'å' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            12315,
+            12538,
+        )),
+        // letter: 'æ' / 0xe6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'æ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            12538,
+            12717,
+        )),
+        // letter: 'ç' / 0xe7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ç' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            12717,
+            12898,
+        )),
+        // letter: 'è' / 0xe8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'è' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            12898,
+            13092,
+        )),
+        // letter: 'é' / 0xe9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'é' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13092,
+            13283,
+        )),
+        // letter: 'ê' / 0xea
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ê' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13283,
+            13490,
+        )),
+        // letter: 'ë' / 0xeb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ë' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13490,
+            13695,
+        )),
+        // letter: 'ì' / 0xec
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ì' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13695,
+            13834,
+        )),
+        // letter: 'í' / 0xed
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'í' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13834,
+            13974,
+        )),
+        // letter: 'î' / 0xee
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'î' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            13974,
+            14129,
+        )),
+        // letter: 'ï' / 0xef
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ï' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            14129,
+            14282,
+        )),
+        // letter: 'ð' / 0xf0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ð' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            14282,
+            14500,
+        )),
+        // letter: 'ñ' / 0xf1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ñ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            14500,
+            14730,
+        )),
+        // letter: 'ò' / 0xf2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ò' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            14730,
+            14939,
+        )),
+        // letter: 'ó' / 0xf3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ó' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            14939,
+            15149,
+        )),
+        // letter: 'ô' / 0xf4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ô' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            15149,
+            15374,
+        )),
+        // letter: 'õ' / 0xf5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'õ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            15374,
+            15605,
+        )),
+        // letter: 'ö' / 0xf6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ö' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            15605,
+            15828,
+        )),
+        // letter: '÷' / 0xf7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '÷' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            15828,
+            15917,
+        )),
+        // letter: 'ø' / 0xf8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ø' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            15917,
+            16102,
+        )),
+        // letter: 'ù' / 0xf9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ù' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            16102,
+            16319,
+        )),
+        // letter: 'ú' / 0xfa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ú' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            16319,
+            16537,
+        )),
+        // letter: 'û' / 0xfb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'û' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            16537,
+            16770,
+        )),
+        // letter: 'ü' / 0xfc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ü' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            16770,
+            17001,
+        )),
+        // letter: 'ý' / 0xfd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ý' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            17001,
+            17242,
+        )),
+        // letter: 'þ' / 0xfe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'þ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            17242,
+            17496,
+        )),
+        // letter: 'ÿ' / 0xff
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ÿ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            17496,
+            17750,
+        )),
+        // letter: 'Ā' / 0x100
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ā' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            0,
+            216,
+        )),
+        // letter: 'ā' / 0x101
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ā' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            216,
+            406,
+        )),
+        // letter: 'Ă' / 0x102
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ă' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            406,
+            644,
+        )),
+        // letter: 'ă' / 0x103
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ă' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            644,
+            856,
+        )),
+        // letter: 'Ą' / 0x104
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ą' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            856,
+            1089,
+        )),
+        // letter: 'ą' / 0x105
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ą' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1089,
+            1297,
+        )),
+        // letter: 'Ć' / 0x106
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ć' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1297,
+            1502,
+        )),
+        // letter: 'ć' / 0x107
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ć' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1502,
+            1681,
+        )),
+        // letter: 'Ĉ' / 0x108
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1681,
+            1901,
+        )),
+        // letter: 'ĉ' / 0x109
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1901,
+            2094,
+        )),
+        // letter: 'Ċ' / 0x10a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ċ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2094,
+            2295,
+        )),
+        // letter: 'ċ' / 0x10b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ċ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2295,
+            2467,
+        )),
+        // letter: 'Č' / 0x10c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Č' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2467,
+            2684,
+        )),
+        // letter: 'č' / 0x10d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'č' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2684,
+            2874,
+        )),
+        // letter: 'Ď' / 0x10e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ď' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2874,
+            3135,
+        )),
+        // letter: 'ď' / 0x10f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ď' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3135,
+            3364,
+        )),
+        // letter: 'Đ' / 0x110
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Đ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3364,
+            3573,
+        )),
+        // letter: 'đ' / 0x111
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'đ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3573,
+            3788,
+        )),
+        // letter: 'Ē' / 0x112
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ē' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3788,
+            3960,
+        )),
+        // letter: 'ē' / 0x113
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ē' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3960,
+            4139,
+        )),
+        // letter: 'Ĕ' / 0x114
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4139,
+            4334,
+        )),
+        // letter: 'ĕ' / 0x115
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4334,
+            4535,
+        )),
+        // letter: 'Ė' / 0x116
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ė' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4535,
+            4717,
+        )),
+        // letter: 'ė' / 0x117
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ė' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4717,
+            4902,
+        )),
+        // letter: 'Ę' / 0x118
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ę' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4902,
+            5092,
+        )),
+        // letter: 'ę' / 0x119
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ę' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5092,
+            5285,
+        )),
+        // letter: 'Ě' / 0x11a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ě' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5285,
+            5485,
+        )),
+        // letter: 'ě' / 0x11b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ě' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5485,
+            5690,
+        )),
+        // letter: 'Ĝ' / 0x11c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5690,
+            5948,
+        )),
+        // letter: 'ĝ' / 0x11d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5948,
+            6241,
+        )),
+        // letter: 'Ğ' / 0x11e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ğ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6241,
+            6495,
+        )),
+        // letter: 'ğ' / 0x11f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ğ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6495,
+            6782,
+        )),
+        // letter: 'Ġ' / 0x120
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ġ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6782,
+            7026,
+        )),
+        // letter: 'ġ' / 0x121
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ġ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7026,
+            7297,
+        )),
+        // letter: 'Ģ' / 0x122
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ģ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7297,
+            7536,
+        )),
+        // letter: 'ģ' / 0x123
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ģ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7536,
+            7810,
+        )),
+        // letter: 'Ĥ' / 0x124
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĥ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7810,
+            8076,
+        )),
+        // letter: 'ĥ' / 0x125
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĥ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8076,
+            8333,
+        )),
+        // letter: 'Ħ' / 0x126
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ħ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8333,
+            8531,
+        )),
+        // letter: 'ħ' / 0x127
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ħ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8531,
+            8739,
+        )),
+        // letter: 'Ĩ' / 0x128
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĩ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8739,
+            8932,
+        )),
+        // letter: 'ĩ' / 0x129
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĩ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8932,
+            9093,
+        )),
+        // letter: 'Ī' / 0x12a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ī' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9093,
+            9254,
+        )),
+        // letter: 'ī' / 0x12b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ī' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9254,
+            9381,
+        )),
+        // letter: 'Ĭ' / 0x12c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9381,
+            9564,
+        )),
+        // letter: 'ĭ' / 0x12d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9564,
+            9713,
+        )),
+        // letter: 'Į' / 0x12e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Į' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9713,
+            9892,
+        )),
+        // letter: 'į' / 0x12f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'į' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            9892,
+            10068,
+        )),
+        // letter: 'İ' / 0x130
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'İ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            10068,
+            10240,
+        )),
+        // letter: 'ı' / 0x131
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ı' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            10240,
+            10345,
+        )),
+        // letter: 'Ĳ' / 0x132
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĳ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            10345,
+            10579,
+        )),
+        // letter: 'ĳ' / 0x133
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĳ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            10579,
+            10862,
+        )),
+        // letter: 'Ĵ' / 0x134
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            10862,
+            11063,
+        )),
+        // letter: 'ĵ' / 0x135
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            11063,
+            11271,
+        )),
+        // letter: 'Ķ' / 0x136
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ķ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            11271,
+            11523,
+        )),
+        // letter: 'ķ' / 0x137
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ķ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            11523,
+            11765,
+        )),
+        // letter: 'ĸ' / 0x138
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĸ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            11765,
+            11942,
+        )),
+        // letter: 'Ĺ' / 0x139
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĺ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            11942,
+            12106,
+        )),
+        // letter: 'ĺ' / 0x13a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĺ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12106,
+            12284,
+        )),
+        // letter: 'Ļ' / 0x13b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ļ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12284,
+            12445,
+        )),
+        // letter: 'ļ' / 0x13c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ļ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12445,
+            12619,
+        )),
         // letter: 'Ľ' / 0x13d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ľ' => Some(include!("../res_rasterized_characters/0x13d_h32_wBold.txt")),
+        'Ľ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12619,
+            12775,
+        )),
         // letter: 'ľ' / 0x13e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ľ' => Some(include!("../res_rasterized_characters/0x13e_h32_wBold.txt")),
+        'ľ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12775,
+            12936,
+        )),
         // letter: 'Ŀ' / 0x13f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŀ' => Some(include!("../res_rasterized_characters/0x13f_h32_wBold.txt")),
+        'Ŀ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            12936,
+            13095,
+        )),
         // letter: 'ŀ' / 0x140
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŀ' => Some(include!("../res_rasterized_characters/0x140_h32_wBold.txt")),
+        'ŀ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            13095,
+            13261,
+        )),
         // letter: 'Ł' / 0x141
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ł' => Some(include!("../res_rasterized_characters/0x141_h32_wBold.txt")),
+        'Ł' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            13261,
+            13410,
+        )),
         // letter: 'ł' / 0x142
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ł' => Some(include!("../res_rasterized_characters/0x142_h32_wBold.txt")),
+        'ł' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            13410,
+            13569,
+        )),
         // letter: 'Ń' / 0x143
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ń' => Some(include!("../res_rasterized_characters/0x143_h32_wBold.txt")),
+        'Ń' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            13569,
+            13845,
+        )),
         // letter: 'ń' / 0x144
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ń' => Some(include!("../res_rasterized_characters/0x144_h32_wBold.txt")),
+        'ń' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            13845,
+            14054,
+        )),
         // letter: 'Ņ' / 0x145
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ņ' => Some(include!("../res_rasterized_characters/0x145_h32_wBold.txt")),
+        'Ņ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            14054,
+            14327,
+        )),
         // letter: 'ņ' / 0x146
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ņ' => Some(include!("../res_rasterized_characters/0x146_h32_wBold.txt")),
+        'ņ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            14327,
+            14536,
+        )),
         // letter: 'Ň' / 0x147
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ň' => Some(include!("../res_rasterized_characters/0x147_h32_wBold.txt")),
+        'Ň' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            14536,
+            14826,
+        )),
         // letter: 'ň' / 0x148
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ň' => Some(include!("../res_rasterized_characters/0x148_h32_wBold.txt")),
+        'ň' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            14826,
+            15050,
+        )),
         // letter: 'ŉ' / 0x149
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŉ' => Some(include!("../res_rasterized_characters/0x149_h32_wBold.txt")),
+        'ŉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            15050,
+            15250,
+        )),
         // letter: 'Ŋ' / 0x14a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŋ' => Some(include!("../res_rasterized_characters/0x14a_h32_wBold.txt")),
+        'Ŋ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            15250,
+            15528,
+        )),
         // letter: 'ŋ' / 0x14b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŋ' => Some(include!("../res_rasterized_characters/0x14b_h32_wBold.txt")),
+        'ŋ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            15528,
+            15746,
+        )),
         // letter: 'Ō' / 0x14c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ō' => Some(include!("../res_rasterized_characters/0x14c_h32_wBold.txt")),
+        'Ō' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            15746,
+            15989,
+        )),
         // letter: 'ō' / 0x14d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ō' => Some(include!("../res_rasterized_characters/0x14d_h32_wBold.txt")),
+        'ō' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            15989,
+            16186,
+        )),
         // letter: 'Ŏ' / 0x14e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŏ' => Some(include!("../res_rasterized_characters/0x14e_h32_wBold.txt")),
+        'Ŏ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            16186,
+            16451,
+        )),
         // letter: 'ŏ' / 0x14f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŏ' => Some(include!("../res_rasterized_characters/0x14f_h32_wBold.txt")),
+        'ŏ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            16451,
+            16670,
+        )),
         // letter: 'Ő' / 0x150
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ő' => Some(include!("../res_rasterized_characters/0x150_h32_wBold.txt")),
+        'Ő' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            16670,
+            16944,
+        )),
         // letter: 'ő' / 0x151
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ő' => Some(include!("../res_rasterized_characters/0x151_h32_wBold.txt")),
+        'ő' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            16944,
+            17172,
+        )),
         // letter: 'Œ' / 0x152
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Œ' => Some(include!("../res_rasterized_characters/0x152_h32_wBold.txt")),
+        'Œ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            17172,
+            17388,
+        )),
         // letter: 'œ' / 0x153
         #[cfg(feature = "unicode-latin-extended-a")]
-        'œ' => Some(include!("../res_rasterized_characters/0x153_h32_wBold.txt")),
+        'œ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            17388,
+            17573,
+        )),
         // letter: 'Ŕ' / 0x154
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŕ' => Some(include!("../res_rasterized_characters/0x154_h32_wBold.txt")),
+        'Ŕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            17573,
+            17824,
+        )),
         // letter: 'ŕ' / 0x155
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŕ' => Some(include!("../res_rasterized_characters/0x155_h32_wBold.txt")),
+        'ŕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            17824,
+            17987,
+        )),
         // letter: 'Ŗ' / 0x156
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŗ' => Some(include!("../res_rasterized_characters/0x156_h32_wBold.txt")),
+        'Ŗ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            17987,
+            18235,
+        )),
         // letter: 'ŗ' / 0x157
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŗ' => Some(include!("../res_rasterized_characters/0x157_h32_wBold.txt")),
+        'ŗ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            18235,
+            18397,
+        )),
         // letter: 'Ř' / 0x158
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ř' => Some(include!("../res_rasterized_characters/0x158_h32_wBold.txt")),
+        'Ř' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            18397,
+            18664,
+        )),
         // letter: 'ř' / 0x159
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ř' => Some(include!("../res_rasterized_characters/0x159_h32_wBold.txt")),
+        'ř' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            18664,
+            18840,
+        )),
         // letter: 'Ś' / 0x15a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ś' => Some(include!("../res_rasterized_characters/0x15a_h32_wBold.txt")),
+        'Ś' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            18840,
+            19059,
+        )),
         // letter: 'ś' / 0x15b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ś' => Some(include!("../res_rasterized_characters/0x15b_h32_wBold.txt")),
+        'ś' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            19059,
+            19252,
+        )),
         // letter: 'Ŝ' / 0x15c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŝ' => Some(include!("../res_rasterized_characters/0x15c_h32_wBold.txt")),
+        'Ŝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            19252,
+            19485,
+        )),
         // letter: 'ŝ' / 0x15d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŝ' => Some(include!("../res_rasterized_characters/0x15d_h32_wBold.txt")),
+        'ŝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            19485,
+            19694,
+        )),
         // letter: 'Ş' / 0x15e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ş' => Some(include!("../res_rasterized_characters/0x15e_h32_wBold.txt")),
+        'Ş' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            19694,
+            19916,
+        )),
         // letter: 'ş' / 0x15f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ş' => Some(include!("../res_rasterized_characters/0x15f_h32_wBold.txt")),
+        'ş' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            19916,
+            20116,
+        )),
         // letter: 'Š' / 0x160
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Š' => Some(include!("../res_rasterized_characters/0x160_h32_wBold.txt")),
+        'Š' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            20116,
+            20347,
+        )),
         // letter: 'š' / 0x161
         #[cfg(feature = "unicode-latin-extended-a")]
-        'š' => Some(include!("../res_rasterized_characters/0x161_h32_wBold.txt")),
+        'š' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            20347,
+            20555,
+        )),
         // letter: 'Ţ' / 0x162
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ţ' => Some(include!("../res_rasterized_characters/0x162_h32_wBold.txt")),
+        'Ţ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            20555,
+            20725,
+        )),
         // letter: 'ţ' / 0x163
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ţ' => Some(include!("../res_rasterized_characters/0x163_h32_wBold.txt")),
+        'ţ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            20725,
+            20907,
+        )),
         // letter: 'Ť' / 0x164
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ť' => Some(include!("../res_rasterized_characters/0x164_h32_wBold.txt")),
+        'Ť' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            20907,
+            21084,
+        )),
         // letter: 'ť' / 0x165
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ť' => Some(include!("../res_rasterized_characters/0x165_h32_wBold.txt")),
+        'ť' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            21084,
+            21251,
+        )),
         // letter: 'Ŧ' / 0x166
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŧ' => Some(include!("../res_rasterized_characters/0x166_h32_wBold.txt")),
+        'Ŧ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            21251,
+            21389,
+        )),
         // letter: 'ŧ' / 0x167
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŧ' => Some(include!("../res_rasterized_characters/0x167_h32_wBold.txt")),
+        'ŧ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            21389,
+            21544,
+        )),
         // letter: 'Ũ' / 0x168
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ũ' => Some(include!("../res_rasterized_characters/0x168_h32_wBold.txt")),
+        'Ũ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            21544,
+            21828,
+        )),
         // letter: 'ũ' / 0x169
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ũ' => Some(include!("../res_rasterized_characters/0x169_h32_wBold.txt")),
+        'ũ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            21828,
+            22067,
+        )),
         // letter: 'Ū' / 0x16a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ū' => Some(include!("../res_rasterized_characters/0x16a_h32_wBold.txt")),
+        'Ū' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            22067,
+            22319,
+        )),
         // letter: 'ū' / 0x16b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ū' => Some(include!("../res_rasterized_characters/0x16b_h32_wBold.txt")),
+        'ū' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            22319,
+            22524,
+        )),
         // letter: 'Ŭ' / 0x16c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŭ' => Some(include!("../res_rasterized_characters/0x16c_h32_wBold.txt")),
+        'Ŭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            22524,
+            22798,
+        )),
         // letter: 'ŭ' / 0x16d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŭ' => Some(include!("../res_rasterized_characters/0x16d_h32_wBold.txt")),
+        'ŭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            22798,
+            23025,
+        )),
         // letter: 'Ů' / 0x16e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ů' => Some(include!("../res_rasterized_characters/0x16e_h32_wBold.txt")),
+        'Ů' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            23025,
+            23309,
+        )),
         // letter: 'ů' / 0x16f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ů' => Some(include!("../res_rasterized_characters/0x16f_h32_wBold.txt")),
+        'ů' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            23309,
+            23546,
+        )),
         // letter: 'Ű' / 0x170
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ű' => Some(include!("../res_rasterized_characters/0x170_h32_wBold.txt")),
+        'Ű' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            23546,
+            23829,
+        )),
         // letter: 'ű' / 0x171
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ű' => Some(include!("../res_rasterized_characters/0x171_h32_wBold.txt")),
+        'ű' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            23829,
+            24065,
+        )),
         // letter: 'Ų' / 0x172
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ų' => Some(include!("../res_rasterized_characters/0x172_h32_wBold.txt")),
+        'Ų' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            24065,
+            24330,
+        )),
         // letter: 'ų' / 0x173
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ų' => Some(include!("../res_rasterized_characters/0x173_h32_wBold.txt")),
+        'ų' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            24330,
+            24554,
+        )),
         // letter: 'Ŵ' / 0x174
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŵ' => Some(include!("../res_rasterized_characters/0x174_h32_wBold.txt")),
+        'Ŵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            24554,
+            24838,
+        )),
         // letter: 'ŵ' / 0x175
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŵ' => Some(include!("../res_rasterized_characters/0x175_h32_wBold.txt")),
+        'ŵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            24838,
+            25069,
+        )),
         // letter: 'Ŷ' / 0x176
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŷ' => Some(include!("../res_rasterized_characters/0x176_h32_wBold.txt")),
+        'Ŷ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            25069,
+            25296,
+        )),
         // letter: 'ŷ' / 0x177
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŷ' => Some(include!("../res_rasterized_characters/0x177_h32_wBold.txt")),
+        'ŷ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            25296,
+            25551,
+        )),
         // letter: 'Ÿ' / 0x178
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ÿ' => Some(include!("../res_rasterized_characters/0x178_h32_wBold.txt")),
+        'Ÿ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            25551,
+            25784,
+        )),
         // letter: 'Ź' / 0x179
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ź' => Some(include!("../res_rasterized_characters/0x179_h32_wBold.txt")),
+        'Ź' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            25784,
+            25964,
+        )),
         // letter: 'ź' / 0x17a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ź' => Some(include!("../res_rasterized_characters/0x17a_h32_wBold.txt")),
+        'ź' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            25964,
+            26113,
+        )),
         // letter: 'Ż' / 0x17b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ż' => Some(include!("../res_rasterized_characters/0x17b_h32_wBold.txt")),
+        'Ż' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            26113,
+            26291,
+        )),
         // letter: 'ż' / 0x17c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ż' => Some(include!("../res_rasterized_characters/0x17c_h32_wBold.txt")),
+        'ż' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            26291,
+            26434,
+        )),
         // letter: 'Ž' / 0x17d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ž' => Some(include!("../res_rasterized_characters/0x17d_h32_wBold.txt")),
+        'Ž' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            26434,
+            26627,
+        )),
         // letter: 'ž' / 0x17e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ž' => Some(include!("../res_rasterized_characters/0x17e_h32_wBold.txt")),
+        'ž' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            26627,
+            26790,
+        )),
         // letter: 'ſ' / 0x17f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ſ' => Some(include!("../res_rasterized_characters/0x17f_h32_wBold.txt")),
+        'ſ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            26790,
+            26932,
+        )),
         // letter: '�' / 0xfffd
         #[cfg(feature = "unicode-specials")]
-        '�' => Some(include!(
-            "../res_rasterized_characters/0xfffd_h32_wBold.txt"
+        '�' => Some(crate::glyph_data::bytes_at(
+            UNICODE_SPECIALS_COMPRESSED,
+            0,
+            210,
         )),
         _ => None,
     }
@@ -0,0 +1,32 @@
+//! Module for colors in the `0x00RRGGBB` format, which is used by the integrations
+//! that render into pixel buffers.
+
+/// Blends linearly from `background` to `foreground` by `intensity`. Both colors
+/// are `0x00RRGGBB`.
+pub(crate) const fn blend(background: u32, foreground: u32, intensity: u8) -> u32 {
+    const fn mix(b: u8, f: u8, i: u8) -> u8 {
+        let (b, f, i) = (b as u16, f as u16, i as u16);
+        ((b * (255 - i) + f * i + 127) / 255) as u8
+    }
+    let [_, br, bg, bb] = background.to_be_bytes();
+    let [_, fr, fg, fb] = foreground.to_be_bytes();
+    u32::from_be_bytes([
+        0,
+        mix(br, fr, intensity),
+        mix(bg, fg, intensity),
+        mix(bb, fb, intensity),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blend() {
+        assert_eq!(blend(0x000000, 0xffffff, 0), 0x000000);
+        assert_eq!(blend(0x000000, 0xffffff, 128), 0x808080);
+        assert_eq!(blend(0x102030, 0xffffff, 255), 0xffffff);
+        assert_eq!(blend(0xff0000, 0x0000ff, 51), 0xcc0033);
+    }
+}
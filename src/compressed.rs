@@ -0,0 +1,362 @@
+//! Module for run-length encoded rasters. As most rows consist mostly of zeros, they
+//! need only half to a third of the bytes of the raw rasters. Furthermore, they don't
+//! need a slice per row, which is 16 bytes on 64-bit targets.
+
+use crate::{MAX_RASTER_WIDTH, Raster};
+use core::ops::Deref;
+
+/// Minimum length of a repeat run. The codegen stores shorter repetitions in literal
+/// runs.
+const MIN_REPEAT: usize = 3;
+
+/// Describes a char with a run-length encoded raster.
+///
+/// Get it with [`crate::get_raster_compressed`]. Decode it row by row with
+/// [`CompressedChar::rows`] or at once into a buffer on the stack with
+/// [`CompressedChar::decode_into`]. Neither needs allocations.
+///
+/// The raster is encoded row by row as one stream of runs, i.e., runs continue
+/// across rows. Each run starts with a control byte `n`. If `n < 0x80`, the next
+/// `n + 1` bytes are literal intensities. Otherwise, the next byte is repeated
+/// `n - 0x80 + 3` times.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedChar {
+    /// The runs of the intensities of the raster.
+    pub(crate) data: &'static [u8],
+    /// Height of the raster box. The actual font size is slightly smaller.
+    pub(crate) height: usize,
+    /// Width of the raster box. This equals the width of the
+    /// [`crate::RasterizedChar`] of the same font weight and raster height.
+    pub(crate) width: usize,
+}
+
+impl CompressedChar {
+    /// The run-length encoded intensities of the raster.
+    #[inline]
+    pub const fn data(&self) -> &'static [u8] {
+        self.data
+    }
+
+    /// Height of the raster box. The actual font size is slightly smaller.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The width of the raster box. It is guaranteed, that all chars of the same
+    /// font weight and raster height also have the same width.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns an iterator that decodes the rows of the raster lazily.
+    #[inline]
+    pub const fn rows(&self) -> CompressedRows {
+        CompressedRows {
+            decoder: Decoder::new(self.data),
+            width: self.width,
+            remaining: self.height,
+        }
+    }
+
+    /// Decodes the raster row by row into `buf`, e.g., a
+    /// `[u8; MAX_RASTER_WIDTH * MAX_RASTER_HEIGHT]` on the stack. This also works in
+    /// const contexts.
+    ///
+    /// # Panics
+    /// Panics if `buf` is shorter than `width * height` bytes.
+    pub const fn decode_into(&self, buf: &mut [u8]) {
+        let len = self.width * self.height;
+        assert!(buf.len() >= len, "the buffer is too small for the raster");
+        Decoder::new(self.data).fill(buf.split_at_mut(len).0);
+    }
+}
+
+/// Decoding of the pixels one pixel for another. [`CompressedChar::rows`] or
+/// [`CompressedChar::decode_into`] are faster to decode whole rasters.
+impl Raster for CompressedChar {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width || y >= self.height {
+            return 0;
+        }
+        let mut decoder = Decoder::new(self.data);
+        decoder.skip(y * self.width + x);
+        let mut pixel = [0];
+        decoder.fill(&mut pixel);
+        pixel[0]
+    }
+}
+
+/// Iterator over the decoded rows of a [`CompressedChar`].
+#[derive(Debug, Clone)]
+pub struct CompressedRows {
+    decoder: Decoder,
+    width: usize,
+    remaining: usize,
+}
+
+impl Iterator for CompressedRows {
+    type Item = CompressedRow;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let mut row = CompressedRow {
+            pixels: [0; MAX_RASTER_WIDTH],
+            width: self.width,
+        };
+        self.decoder.fill(&mut row.pixels[..self.width]);
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for CompressedRows {}
+
+impl core::iter::FusedIterator for CompressedRows {}
+
+/// A decoded row of a [`CompressedChar`]. Dereferences to the intensities of its
+/// pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedRow {
+    pixels: [u8; MAX_RASTER_WIDTH],
+    width: usize,
+}
+
+impl Deref for CompressedRow {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.pixels[..self.width]
+    }
+}
+
+/// Decoding state of a stream of runs. All functions are `const`, hence the loops and
+/// the index arithmetic.
+#[derive(Debug, Clone)]
+struct Decoder {
+    /// The stream of runs.
+    data: &'static [u8],
+    /// Position in the stream. During a repeat run, it points to the repeated
+    /// intensity. During a literal run, it points to the remaining intensities.
+    pos: usize,
+    /// Remaining pixels of the current run.
+    remaining: usize,
+    /// Whether the current run is a literal run.
+    literal: bool,
+}
+
+impl Decoder {
+    const fn new(data: &'static [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            remaining: 0,
+            literal: false,
+        }
+    }
+
+    /// Starts the next run. Returns false at the end of the stream.
+    const fn next_run(&mut self) -> bool {
+        if self.pos >= self.data.len() {
+            return false;
+        }
+        let n = self.data[self.pos];
+        self.pos += 1;
+        let rest = self.data.len() - self.pos;
+        self.literal = n < 0x80;
+        if self.literal {
+            self.remaining = min(n as usize + 1, rest);
+        } else if rest == 0 {
+            return false;
+        } else {
+            self.remaining = (n - 0x80) as usize + MIN_REPEAT;
+        }
+        self.remaining > 0
+    }
+
+    /// Consumes `n` pixels of the current run.
+    const fn consume(&mut self, n: usize) {
+        self.remaining -= n;
+        if self.literal {
+            self.pos += n;
+        } else if self.remaining == 0 {
+            self.pos += 1;
+        }
+    }
+
+    /// Fills `out` with the next pixels. Pixels after the end of the stream are zero.
+    const fn fill(&mut self, out: &mut [u8]) {
+        let mut i = 0;
+        while i < out.len() {
+            if self.remaining == 0 && !self.next_run() {
+                while i < out.len() {
+                    out[i] = 0;
+                    i += 1;
+                }
+                return;
+            }
+            let n = min(self.remaining, out.len() - i);
+            let mut j = 0;
+            while j < n {
+                out[i + j] = if self.literal {
+                    self.data[self.pos + j]
+                } else {
+                    self.data[self.pos]
+                };
+                j += 1;
+            }
+            self.consume(n);
+            i += n;
+        }
+    }
+
+    /// Skips the next `n` pixels.
+    const fn skip(&mut self, mut n: usize) {
+        while n > 0 {
+            if self.remaining == 0 && !self.next_run() {
+                return;
+            }
+            let skipped = min(self.remaining, n);
+            self.consume(skipped);
+            n -= skipped;
+        }
+    }
+}
+
+/// `const` replacement for [`Ord::min`].
+const fn min(a: usize, b: usize) -> usize {
+    if a < b { a } else { b }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{
+        FontWeight, MAX_RASTER_HEIGHT, RasterHeight, get_raster, get_raster_compressed,
+        supported_chars,
+    };
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    #[test]
+    fn test_decode() {
+        // 0 0 0 0 | 0 1 2 2 | 2 0 0 7
+        let c = CompressedChar {
+            data: &[0x82, 0, 0x00, 1, 0x80, 2, 0x02, 0, 0, 7],
+            height: 3,
+            width: 4,
+        };
+        let mut rows = c.rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(*rows.next().unwrap(), [0, 0, 0, 0]);
+        assert_eq!(*rows.next().unwrap(), [0, 1, 2, 2]);
+        assert_eq!(*rows.next().unwrap(), [2, 0, 0, 7]);
+        assert!(rows.next().is_none());
+
+        let mut buf = [0xff; 13];
+        c.decode_into(&mut buf);
+        assert_eq!(buf, [0, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 7, 0xff]);
+
+        assert_eq!(c.pixel(2, 1), 2);
+        assert_eq!(c.pixel(3, 2), 7);
+        assert_eq!(c.pixel(4, 0), 0);
+        assert_eq!(c.pixel(0, 3), 0);
+    }
+
+    #[test]
+    fn test_decode_const() {
+        const C: CompressedChar = CompressedChar {
+            data: &[0x82, 0, 0x00, 1, 0x80, 2, 0x02, 0, 0, 7],
+            height: 3,
+            width: 4,
+        };
+        const DECODED: [u8; 12] = {
+            let mut buf = [0; 12];
+            C.decode_into(&mut buf);
+            buf
+        };
+        assert_eq!(DECODED, [0, 0, 0, 0, 0, 1, 2, 2, 2, 0, 0, 7]);
+
+        // the generated rasters are decoded at compile time as well
+        const A: [u8; MAX_RASTER_WIDTH * MAX_RASTER_HEIGHT] = {
+            let mut buf = [0; MAX_RASTER_WIDTH * MAX_RASTER_HEIGHT];
+            match get_raster_compressed('A', WEIGHT, SIZE) {
+                Some(a) => a.decode_into(&mut buf),
+                None => panic!("'A' is not supported"),
+            }
+            buf
+        };
+        let raw = get_raster('A', WEIGHT, SIZE).unwrap();
+        for (y, row) in raw.raster().iter().enumerate() {
+            assert_eq!(&A[y * raw.width()..][..raw.width()], *row);
+        }
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        // the missing pixels are zero, even if the stream ends in a run
+        let c = CompressedChar {
+            data: &[0x03, 1, 2],
+            height: 2,
+            width: 2,
+        };
+        let mut buf = [0xff; 4];
+        c.decode_into(&mut buf);
+        assert_eq!(buf, [1, 2, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decode_into_too_small() {
+        let a = get_raster_compressed('A', WEIGHT, SIZE).unwrap();
+        a.decode_into(&mut [0; 8]);
+    }
+
+    #[test]
+    fn test_matches_raw_rasters() {
+        let mut raw_size = 0;
+        let mut compressed_size = 0;
+        for c in supported_chars() {
+            let raw = get_raster(c, WEIGHT, SIZE).unwrap();
+            let compressed = get_raster_compressed(c, WEIGHT, SIZE).unwrap();
+            assert_eq!(compressed.width(), raw.width());
+            assert_eq!(compressed.height(), raw.height());
+            assert_eq!(compressed.rows().len(), raw.height());
+            for (decoded, row) in compressed.rows().zip(raw.raster()) {
+                assert_eq!(&*decoded, *row);
+            }
+
+            let mut buf = [0; MAX_RASTER_WIDTH * MAX_RASTER_HEIGHT];
+            compressed.decode_into(&mut buf);
+            for (y, row) in raw.raster().iter().enumerate() {
+                assert_eq!(&buf[y * raw.width()..][..raw.width()], *row);
+                assert_eq!(compressed.pixel(3, y), raw.pixel(3, y));
+            }
+
+            raw_size += raw.width() * raw.height();
+            compressed_size += compressed.data().len();
+        }
+        assert!(compressed_size < raw_size * 2 / 3);
+        assert!(get_raster_compressed('\u{1}', WEIGHT, SIZE).is_none());
+    }
+}
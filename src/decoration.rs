@@ -0,0 +1,232 @@
+//! Module for synthetic text decorations, such as underlines, and inverse video.
+
+use crate::Raster;
+use core::ops::{BitOr, BitOrAssign};
+
+/// Vertical positions and thicknesses of text decorations inside the raster of
+/// each character of a [`crate::FontWeight`] and [`crate::RasterHeight`].
+///
+/// The values are computed by the codegen from the metrics of the font. Get them
+/// with [`crate::get_decoration_metrics`]. All values are in pixels. Positions
+/// refer to the first row of the line, counted from the top of the raster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecorationMetrics {
+    pub(crate) underline_position: usize,
+    pub(crate) underline_thickness: usize,
+    pub(crate) strikeout_position: usize,
+    pub(crate) strikeout_thickness: usize,
+    pub(crate) overline_position: usize,
+}
+
+impl DecorationMetrics {
+    /// First row of the underline. Taken from the `post` table of the font.
+    #[inline]
+    pub const fn underline_position(&self) -> usize {
+        self.underline_position
+    }
+
+    /// Thickness of the underline. Taken from the `post` table of the font.
+    #[inline]
+    pub const fn underline_thickness(&self) -> usize {
+        self.underline_thickness
+    }
+
+    /// First row of the strikethrough. Taken from the `OS/2` table of the font.
+    #[inline]
+    pub const fn strikeout_position(&self) -> usize {
+        self.strikeout_position
+    }
+
+    /// Thickness of the strikethrough. Taken from the `OS/2` table of the font.
+    #[inline]
+    pub const fn strikeout_thickness(&self) -> usize {
+        self.strikeout_thickness
+    }
+
+    /// First row of the overline. Fonts don't specify this, hence, the line is
+    /// placed right above the capital letters.
+    #[inline]
+    pub const fn overline_position(&self) -> usize {
+        self.overline_position
+    }
+
+    /// Thickness of the overline. This is the thickness of the underline.
+    #[inline]
+    pub const fn overline_thickness(&self) -> usize {
+        self.underline_thickness
+    }
+}
+
+/// Set of decorations that [`Decorated`] composites onto a raster. Combine
+/// multiple decorations with `|`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Decorations(u8);
+
+impl Decorations {
+    /// No decorations.
+    pub const NONE: Self = Self(0);
+    /// A line below the baseline.
+    pub const UNDERLINE: Self = Self(1 << 0);
+    /// A line through the middle of lowercase letters.
+    pub const STRIKETHROUGH: Self = Self(1 << 1);
+    /// A line above capital letters.
+    pub const OVERLINE: Self = Self(1 << 2);
+    /// Swaps foreground and background, i.e., each intensity `i` becomes `255 - i`.
+    /// This is applied after all lines are drawn.
+    pub const INVERSE: Self = Self(1 << 3);
+
+    /// Returns true if all decorations of `other` are also in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the set of decorations that are in `self` or `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOr for Decorations {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Decorations {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// View of a [`Raster`] with text decorations composited onto it.
+/// Created by [`Raster::decorate`].
+///
+/// Lines span the full width of the raster, so that the lines of neighboring
+/// characters connect. The [`DecorationMetrics`] refer to unscaled rasters, hence,
+/// decorate rasters before transforming them.
+#[derive(Debug, Clone, Copy)]
+pub struct Decorated<R> {
+    raster: R,
+    decorations: Decorations,
+    metrics: DecorationMetrics,
+}
+
+impl<R: Raster> Decorated<R> {
+    /// Creates a new view with the given decorations.
+    pub const fn new(raster: R, decorations: Decorations, metrics: DecorationMetrics) -> Self {
+        Self {
+            raster,
+            decorations,
+            metrics,
+        }
+    }
+
+    /// Returns true if row `y` is covered by one of the enabled lines.
+    const fn is_line(&self, y: usize) -> bool {
+        let lines = [
+            (
+                Decorations::UNDERLINE,
+                self.metrics.underline_position(),
+                self.metrics.underline_thickness(),
+            ),
+            (
+                Decorations::STRIKETHROUGH,
+                self.metrics.strikeout_position(),
+                self.metrics.strikeout_thickness(),
+            ),
+            (
+                Decorations::OVERLINE,
+                self.metrics.overline_position(),
+                self.metrics.overline_thickness(),
+            ),
+        ];
+
+        let mut i = 0;
+        while i < lines.len() {
+            let (decoration, position, thickness) = lines[i];
+            if self.decorations.contains(decoration) && y >= position && y < position + thickness {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+impl<R: Raster> Raster for Decorated<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        let intensity = if self.is_line(y) {
+            u8::MAX
+        } else {
+            self.raster.pixel(x, y)
+        };
+
+        if self.decorations.contains(Decorations::INVERSE) {
+            u8::MAX - intensity
+        } else {
+            intensity
+        }
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{FontWeight, RasterHeight, get_decoration_metrics, get_raster};
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    #[test]
+    fn test_decoration_metrics_in_raster() {
+        let metrics = get_decoration_metrics(WEIGHT, SIZE);
+        assert!(metrics.underline_position() + metrics.underline_thickness() <= SIZE.val());
+        assert!(metrics.overline_position() < metrics.strikeout_position());
+        assert!(metrics.strikeout_position() < metrics.underline_position());
+    }
+
+    #[test]
+    fn test_decorations() {
+        let metrics = get_decoration_metrics(WEIGHT, SIZE);
+        let space = get_raster(' ', WEIGHT, SIZE).unwrap();
+
+        let underlined = space.decorate(Decorations::UNDERLINE, metrics);
+        for y in 0..space.height() {
+            let expected = if y >= metrics.underline_position()
+                && y < metrics.underline_position() + metrics.underline_thickness()
+            {
+                255
+            } else {
+                0
+            };
+            for x in 0..space.width() {
+                assert_eq!(underlined.pixel(x, y), expected);
+            }
+        }
+
+        let decorations = Decorations::STRIKETHROUGH | Decorations::INVERSE;
+        assert!(decorations.contains(Decorations::INVERSE));
+        assert!(!decorations.contains(Decorations::UNDERLINE));
+        let inverse = space.decorate(decorations, metrics);
+        assert_eq!(inverse.pixel(0, 0), 255);
+        assert_eq!(inverse.pixel(0, metrics.strikeout_position()), 0);
+    }
+}
@@ -0,0 +1,293 @@
+//! Module for outline, glow, and drop-shadow effects that keep text readable on busy
+//! backgrounds, such as photos.
+
+use crate::{MAX_RASTER_HEIGHT, MAX_RASTER_WIDTH, Raster};
+
+/// Space in pixels that a [`Mask`] adds on each side of a raster. This is the
+/// maximum radius of outlines and glows and the maximum offset of shadows.
+pub const EFFECT_PADDING: usize = 2;
+
+const MASK_WIDTH: usize = MAX_RASTER_WIDTH + 2 * EFFECT_PADDING;
+const MASK_HEIGHT: usize = MAX_RASTER_HEIGHT + 2 * EFFECT_PADDING;
+
+/// A raster with [`EFFECT_PADDING`] pixels of padding on each side that lives in a
+/// fixed-size buffer on the stack.
+///
+/// The buffer fits the biggest raster of all enabled font weights and raster heights,
+/// i.e., [`MAX_RASTER_WIDTH`] x [`MAX_RASTER_HEIGHT`] plus the padding.
+///
+/// The raster of a character is placed at `(EFFECT_PADDING, EFFECT_PADDING)`. Pixels
+/// of rasters that are bigger than the buffer are cut off.
+#[derive(Debug, Clone)]
+pub struct Mask {
+    pixels: [[u8; MASK_WIDTH]; MASK_HEIGHT],
+    width: usize,
+    height: usize,
+}
+
+impl Mask {
+    /// Creates an empty mask that fits `raster` including the padding.
+    fn for_raster(raster: &impl Raster) -> Self {
+        Self {
+            pixels: [[0; MASK_WIDTH]; MASK_HEIGHT],
+            width: (raster.width() + 2 * EFFECT_PADDING).min(MASK_WIDTH),
+            height: (raster.height() + 2 * EFFECT_PADDING).min(MASK_HEIGHT),
+        }
+    }
+
+    /// Returns the intensity of `raster` at the coordinate `(x, y)` of the mask,
+    /// i.e., with the padding removed.
+    fn source_pixel(raster: &impl Raster, x: isize, y: isize) -> u8 {
+        let x = x - EFFECT_PADDING as isize;
+        let y = y - EFFECT_PADDING as isize;
+        if x < 0 || y < 0 {
+            0
+        } else {
+            raster.pixel(x as usize, y as usize)
+        }
+    }
+
+    /// Dilates `raster` by `radius` pixels. If `falloff` is set, the intensity
+    /// decreases linearly with the distance to the original pixel.
+    fn dilate(raster: &impl Raster, radius: usize, falloff: bool) -> Self {
+        let radius = radius.min(EFFECT_PADDING) as isize;
+        let mut mask = Self::for_raster(raster);
+        for y in 0..mask.height {
+            for x in 0..mask.width {
+                let mut max = 0;
+                for dy in -radius..=radius {
+                    for dx in -radius..=radius {
+                        let intensity =
+                            Self::source_pixel(raster, x as isize + dx, y as isize + dy) as usize;
+                        let intensity = if falloff {
+                            let distance = dx.abs().max(dy.abs()) as usize;
+                            intensity * (radius as usize + 1 - distance) / (radius as usize + 1)
+                        } else {
+                            intensity
+                        };
+                        max = max.max(intensity);
+                    }
+                }
+                mask.pixels[y][x] = max as u8;
+            }
+        }
+        mask
+    }
+
+    /// Creates a mask with a copy of `raster`.
+    pub fn glyph(raster: &impl Raster) -> Self {
+        Self::shadow(raster, 0, 0)
+    }
+
+    /// Creates an outline mask, i.e., `raster` dilated by `radius` pixels. The
+    /// radius is limited to [`EFFECT_PADDING`]; 1 or 2 pixels look best.
+    pub fn outline(raster: &impl Raster, radius: usize) -> Self {
+        Self::dilate(raster, radius, false)
+    }
+
+    /// Creates a glow mask, i.e., `raster` dilated by `radius` pixels with an
+    /// intensity that fades with the distance to the glyph. The radius is limited
+    /// to [`EFFECT_PADDING`].
+    pub fn glow(raster: &impl Raster, radius: usize) -> Self {
+        Self::dilate(raster, radius, true)
+    }
+
+    /// Creates a shadow mask, i.e., a copy of `raster` that is moved by `dx` and
+    /// `dy` pixels. The offsets are limited to +/- [`EFFECT_PADDING`].
+    pub fn shadow(raster: &impl Raster, dx: isize, dy: isize) -> Self {
+        let limit = EFFECT_PADDING as isize;
+        let (dx, dy) = (dx.clamp(-limit, limit), dy.clamp(-limit, limit));
+        let mut mask = Self::for_raster(raster);
+        for y in 0..mask.height {
+            for x in 0..mask.width {
+                mask.pixels[y][x] = Self::source_pixel(raster, x as isize - dx, y as isize - dy);
+            }
+        }
+        mask
+    }
+}
+
+impl Raster for Mask {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width || y >= self.height {
+            0
+        } else {
+            self.pixels[y][x]
+        }
+    }
+}
+
+/// The effects and their colors that [`draw_char_with_effects`] draws. `C` is any
+/// color type of the caller, e.g., a `u32` in the pixel format of the framebuffer.
+#[derive(Debug, Clone, Copy)]
+pub struct Effects<C> {
+    color: C,
+    /// radius, whether the intensity fades, and color
+    outline: Option<(usize, bool, C)>,
+    /// x offset, y offset, and color
+    shadow: Option<(isize, isize, C)>,
+}
+
+impl<C: Copy> Effects<C> {
+    /// Draws the glyph in `color` without any effects.
+    pub const fn new(color: C) -> Self {
+        Self {
+            color,
+            outline: None,
+            shadow: None,
+        }
+    }
+
+    /// Draws an outline of `radius` pixels (see [`Mask::outline`]) around the glyph.
+    /// Replaces a previously configured glow.
+    pub const fn with_outline(mut self, radius: usize, color: C) -> Self {
+        self.outline = Some((radius, false, color));
+        self
+    }
+
+    /// Draws a glow of `radius` pixels (see [`Mask::glow`]) around the glyph.
+    /// Replaces a previously configured outline.
+    pub const fn with_glow(mut self, radius: usize, color: C) -> Self {
+        self.outline = Some((radius, true, color));
+        self
+    }
+
+    /// Draws a shadow of the glyph that is moved by `dx` and `dy` pixels (see
+    /// [`Mask::shadow`]).
+    pub const fn with_shadow(mut self, dx: isize, dy: isize, color: C) -> Self {
+        self.shadow = Some((dx, dy, color));
+        self
+    }
+}
+
+/// Draws `raster` with the given [`Effects`]. Returns the width and height of the
+/// drawn area.
+///
+/// Calls `draw(x, y, color, intensity)` for each pixel with a non-zero intensity.
+/// The shadow is drawn first, then the outline or glow, and then the glyph, so that
+/// the caller can blend each layer onto the background.
+///
+/// The coordinates are relative to the top-left corner of the area, which has
+/// [`EFFECT_PADDING`] pixels of padding around the raster. Hence, to place the
+/// glyph at `(x, y)`, the caller draws at `(x - EFFECT_PADDING, y - EFFECT_PADDING)`.
+///
+/// This needs no allocations and no floating point operations. All masks live in
+/// fixed-size buffers on the stack.
+pub fn draw_char_with_effects<C: Copy>(
+    raster: &impl Raster,
+    effects: &Effects<C>,
+    mut draw: impl FnMut(usize, usize, C, u8),
+) -> (usize, usize) {
+    let mut draw_mask = |mask: &Mask, color: C| {
+        for y in 0..mask.height() {
+            for x in 0..mask.width() {
+                let intensity = mask.pixel(x, y);
+                if intensity > 0 {
+                    draw(x, y, color, intensity);
+                }
+            }
+        }
+    };
+
+    if let Some((dx, dy, color)) = effects.shadow {
+        draw_mask(&Mask::shadow(raster, dx, dy), color);
+    }
+    if let Some((radius, falloff, color)) = effects.outline {
+        draw_mask(&Mask::dilate(raster, radius, falloff), color);
+    }
+    let glyph = Mask::glyph(raster);
+    draw_mask(&glyph, effects.color);
+
+    (glyph.width(), glyph.height())
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{FontWeight, RasterHeight, RasterizedChar, get_raster};
+
+    const P: usize = EFFECT_PADDING;
+
+    const fn raster(c: char) -> RasterizedChar {
+        get_raster(c, FontWeight::Regular, RasterHeight::Size16).unwrap()
+    }
+
+    #[test]
+    fn test_mask_dimensions() {
+        let a = raster('A');
+        let mask = Mask::glyph(&a);
+        assert_eq!(mask.width(), a.width() + 2 * P);
+        assert_eq!(mask.height(), a.height() + 2 * P);
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                assert_eq!(mask.pixel(x + P, y + P), a.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_outline_and_glow() {
+        let a = raster('A');
+        let outline = Mask::outline(&a, 1);
+        let glow = Mask::glow(&a, 2);
+        for y in 0..outline.height() {
+            for x in 0..outline.width() {
+                // the maximum of the 3x3 neighborhood in the original raster
+                let expected = (0..3)
+                    .flat_map(|dy| (0..3).map(move |dx| (dx, dy)))
+                    .filter(|&(dx, dy)| x + dx > P && y + dy > P)
+                    .map(|(dx, dy)| a.pixel(x + dx - P - 1, y + dy - P - 1))
+                    .max()
+                    .unwrap_or(0);
+                assert_eq!(outline.pixel(x, y), expected);
+                assert!(glow.pixel(x, y) >= Mask::glyph(&a).pixel(x, y));
+            }
+        }
+
+        // the glow fades out
+        assert_eq!(Mask::glow(&raster(' '), 2).pixel(3, 3), 0);
+        assert!(glow.pixel(P + 3, P + 1) > 0);
+        assert!(glow.pixel(P + 3, P + 1) < glow.pixel(P + 3, P + 2));
+        assert!(glow.pixel(P + 3, P + 2) < glow.pixel(P + 3, P + 3));
+    }
+
+    #[test]
+    fn test_shadow() {
+        let a = raster('A');
+        let shadow = Mask::shadow(&a, 2, -1);
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                assert_eq!(shadow.pixel(x + P + 2, y + P - 1), a.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_draw_char_with_effects_layer_order() {
+        let a = raster('A');
+        let effects = Effects::new('g')
+            .with_shadow(1, 1, 's')
+            .with_outline(1, 'o');
+        let mut layers = [' '; 3];
+        let mut count = 0;
+        let (width, height) = draw_char_with_effects(&a, &effects, |_, _, color, _| {
+            if count == 0 || layers[count - 1] != color {
+                layers[count] = color;
+                count += 1;
+            }
+        });
+        assert_eq!(layers, ['s', 'o', 'g']);
+        assert_eq!((width, height), (a.width() + 2 * P, a.height() + 2 * P));
+    }
+}
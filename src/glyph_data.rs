@@ -0,0 +1,81 @@
+//! Module for the access to the pre-rasterized characters. The codegen stores the
+//! rows of the rasters in binary files per font weight, size, and unicode range.
+//! Identical rows are stored only once, and an index file lists the rows of each
+//! raster. The generated modules include these files with `include_bytes!` and
+//! build the rows at compile time. The run-length encoded rasters of the
+//! `compressed` feature are stored in one file per unicode range as they are.
+
+/// Builds `N` rows of `width` bytes each from the distinct rows in `rows` and the
+/// little-endian `u16` indices of the rows at the beginning of `index`. This is meant
+/// to build the statics with the rows of the distinct rasters of a unicode range at
+/// compile time. Identical rows share their bytes.
+///
+/// # Panics
+/// Panics if an index is out of bounds, which fails the build if evaluated at compile
+/// time.
+#[allow(dead_code)]
+pub(crate) const fn intern_rows<const N: usize>(
+    rows: &'static [u8],
+    index: &'static [u8],
+    width: usize,
+) -> [&'static [u8]; N] {
+    assert!(index.len() >= 2 * N, "invalid length of the glyph index");
+    let mut result: [&[u8]; N] = [&[]; N];
+    let mut i = 0;
+    while i < N {
+        let row = u16::from_le_bytes([index[2 * i], index[2 * i + 1]]) as usize;
+        result[i] = rows.split_at(row * width).1.split_at(width).0;
+        i += 1;
+    }
+    result
+}
+
+/// Returns the `index`-th raster of `rows`, where each raster consists of `height`
+/// consecutive rows.
+#[allow(dead_code)]
+pub(crate) const fn raster_at(
+    rows: &'static [&'static [u8]],
+    index: usize,
+    height: usize,
+) -> &'static [&'static [u8]] {
+    rows.split_at(index * height).1.split_at(height).0
+}
+
+/// Returns the bytes from `start` to `end` of `bytes`, e.g., the run-length encoded
+/// raster of a character.
+#[allow(dead_code)]
+pub(crate) const fn bytes_at(bytes: &'static [u8], start: usize, end: usize) -> &'static [u8] {
+    bytes.split_at(end).0.split_at(start).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ROWS: [&[u8]; 4] = intern_rows(&[1, 2, 3, 4, 5, 6], &[0, 0, 2, 0, 1, 0, 2, 0, 9], 2);
+
+    #[test]
+    fn test_intern_rows() {
+        assert_eq!(ROWS, [&[1, 2], &[5, 6], &[3, 4], &[5, 6]]);
+        // identical rows share their bytes
+        assert!(core::ptr::eq(ROWS[1], ROWS[3]));
+    }
+
+    #[test]
+    fn test_raster_at() {
+        assert_eq!(raster_at(&ROWS, 0, 2), &[&[1, 2], &[5, 6]]);
+        assert_eq!(raster_at(&ROWS, 1, 2), &[&[3, 4], &[5, 6]]);
+    }
+
+    #[test]
+    fn test_bytes_at() {
+        assert_eq!(bytes_at(&[1, 2, 3, 4], 1, 3), &[2, 3]);
+        assert_eq!(bytes_at(&[1, 2, 3, 4], 4, 4), &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_intern_rows_invalid_index() {
+        let _: [&[u8]; 2] = intern_rows(&[1, 2, 3, 4], &[0, 0, 2, 0], 2);
+    }
+}
@@ -0,0 +1,416 @@
+//! Module for the integration with [`embedded_graphics`], whose built-in mono fonts
+//! only have one bit per pixel.
+//!
+//! [`NotoTextStyle`] implements [`TextRenderer`] and [`CharacterStyle`], hence, it
+//! works with [`Text`](embedded_graphics::text::Text) and all other text drawables.
+
+use crate::measure::char_columns;
+use crate::{
+    FontWeight, GlyphSource, NotoGlyphs, Raster, RasterHeight, TabWidth, get_baseline,
+    get_decoration_metrics, get_raster_width,
+};
+use embedded_graphics::pixelcolor::{PixelColor, Rgb888, RgbColor};
+use embedded_graphics::prelude::{DrawTarget, Point, Size};
+use embedded_graphics::primitives::Rectangle;
+use embedded_graphics::text::renderer::{CharacterStyle, TextMetrics, TextRenderer};
+use embedded_graphics::text::{Baseline, DecorationColor};
+
+/// Pixels with at least this intensity are drawn if there is no background color
+/// to blend with.
+const TRANSPARENT_THRESHOLD: u8 = 128;
+
+/// Character style for [`embedded_graphics`] that draws the anti-aliased glyphs of
+/// this crate.
+///
+/// The intensities of the glyphs are blended from the background color to the text
+/// color, which works for all color types that convert from and to [`Rgb888`], such
+/// as `Rgb565`, `Rgb888`, and `Gray8`. As a [`DrawTarget`] can't be read, there is
+/// nothing to blend with if the background is transparent, i.e., if no background
+/// color is set. In that case, only pixels with an intensity of at least 50% are
+/// drawn in the text color.
+///
+/// ```rust
+/// use embedded_graphics::mock_display::MockDisplay;
+/// use embedded_graphics::pixelcolor::Rgb565;
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics::text::Text;
+/// use noto_sans_mono_bitmap::{FontWeight, NotoTextStyle, RasterHeight};
+///
+/// let style = NotoTextStyle::new(FontWeight::Regular, RasterHeight::Size16, Rgb565::WHITE)
+///     .with_background_color(Rgb565::BLACK);
+/// let mut display = MockDisplay::<Rgb565>::new();
+/// Text::new("Hello", Point::new(0, 20), style)
+///     .draw(&mut display)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NotoTextStyle<C, G = NotoGlyphs> {
+    glyphs: G,
+    style: FontWeight,
+    size: RasterHeight,
+    text_color: Option<C>,
+    background_color: Option<C>,
+    underline_color: DecorationColor<C>,
+    strikethrough_color: DecorationColor<C>,
+}
+
+impl<C: PixelColor> NotoTextStyle<C> {
+    /// Creates a style with the built-in glyphs and a transparent background.
+    pub const fn new(style: FontWeight, size: RasterHeight, text_color: C) -> Self {
+        Self {
+            glyphs: NotoGlyphs,
+            style,
+            size,
+            text_color: Some(text_color),
+            background_color: None,
+            underline_color: DecorationColor::None,
+            strikethrough_color: DecorationColor::None,
+        }
+    }
+}
+
+impl<C: PixelColor, G: GlyphSource> NotoTextStyle<C, G> {
+    /// Sets the background color, which the glyphs are blended with.
+    pub const fn with_background_color(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color);
+        self
+    }
+
+    /// Replaces the glyphs, e.g., with custom glyphs that fall back to the built-in
+    /// ones. See [`GlyphSource::chain`].
+    pub fn with_glyphs<S: GlyphSource>(self, glyphs: S) -> NotoTextStyle<C, S> {
+        NotoTextStyle {
+            glyphs,
+            style: self.style,
+            size: self.size,
+            text_color: self.text_color,
+            background_color: self.background_color,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+        }
+    }
+
+    /// Returns the vertical offset between the line position and the top edge of the
+    /// raster, with the same semantics as the mono fonts of `embedded-graphics`.
+    const fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        let offset = match baseline {
+            Baseline::Top => 0,
+            Baseline::Bottom => self.size.val() - 1,
+            Baseline::Middle => (self.size.val() - 1) / 2,
+            // the last row of characters without descenders
+            Baseline::Alphabetic => get_baseline(self.style, self.size) - 1,
+        };
+        offset as i32
+    }
+
+    /// Returns the width of `text` in pixels. See [`crate::measure_str`].
+    fn text_width(&self, text: &str) -> u32 {
+        let columns = text.chars().fold(0, |column, c| {
+            column + char_columns(c, column, TabWidth::default())
+        });
+        (columns * get_raster_width(self.style, self.size)) as u32
+    }
+
+    /// Returns the color of a pixel with `intensity` or `None` if it is transparent.
+    fn color(&self, intensity: u8) -> Option<C>
+    where
+        C: From<Rgb888> + Into<Rgb888>,
+    {
+        match (self.text_color, self.background_color) {
+            (Some(text), Some(background)) => Some(blend(background, text, intensity)),
+            (Some(text), None) => (intensity >= TRANSPARENT_THRESHOLD).then_some(text),
+            (None, background) => background,
+        }
+    }
+
+    fn draw_decorations<D>(
+        &self,
+        width: u32,
+        top_left: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let metrics = get_decoration_metrics(self.style, self.size);
+        let decorations = [
+            (
+                self.strikethrough_color,
+                metrics.strikeout_position(),
+                metrics.strikeout_thickness(),
+            ),
+            (
+                self.underline_color,
+                metrics.underline_position(),
+                metrics.underline_thickness(),
+            ),
+        ];
+        for (color, position, thickness) in decorations {
+            let color = match color {
+                DecorationColor::None => None,
+                DecorationColor::TextColor => self.text_color,
+                DecorationColor::Custom(color) => Some(color),
+            };
+            if let Some(color) = color {
+                let rect = Rectangle::new(
+                    top_left + Point::new(0, position as i32),
+                    Size::new(width, thickness as u32),
+                );
+                target.fill_solid(&rect, color)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Blends linearly from `background` to `foreground` by `intensity`.
+fn blend<C: From<Rgb888> + Into<Rgb888>>(background: C, foreground: C, intensity: u8) -> C {
+    let (background, foreground): (Rgb888, Rgb888) = (background.into(), foreground.into());
+    let mix = |b: u8, f: u8| {
+        let (b, f, i) = (u16::from(b), u16::from(f), u16::from(intensity));
+        ((b * (255 - i) + f * i + 127) / 255) as u8
+    };
+    Rgb888::new(
+        mix(background.r(), foreground.r()),
+        mix(background.g(), foreground.g()),
+        mix(background.b(), foreground.b()),
+    )
+    .into()
+}
+
+impl<C: PixelColor, G: GlyphSource + Clone> CharacterStyle for NotoTextStyle<C, G> {
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+impl<C, G> TextRenderer for NotoTextStyle<C, G>
+where
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+    G: GlyphSource,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let top_left = position - Point::new(0, self.baseline_offset(baseline));
+        let (cell_width, cell_height) = (get_raster_width(self.style, self.size), self.size.val());
+
+        let mut column = 0;
+        for c in text.chars() {
+            let columns = char_columns(c, column, TabWidth::default());
+            let cell = Rectangle::new(
+                top_left + Point::new((column * cell_width) as i32, 0),
+                Size::new((columns * cell_width) as u32, cell_height as u32),
+            );
+            match (self.glyphs.glyph(c, self.style, self.size), columns) {
+                (_, 0) => {}
+                (Some(raster), _) => {
+                    let pixels = (0..cell_height).flat_map(|y| {
+                        (0..cell_width).filter_map(move |x| {
+                            let color = self.color(raster.pixel(x, y))?;
+                            let point = cell.top_left + Point::new(x as i32, y as i32);
+                            Some(embedded_graphics::Pixel(point, color))
+                        })
+                    });
+                    target.draw_iter(pixels)?;
+                    // a glyph of a tab fills only the first of its columns
+                    if let (Some(background), 2..) = (self.background_color, columns) {
+                        let rest = Rectangle::new(
+                            cell.top_left + Point::new(cell_width as i32, 0),
+                            Size::new(((columns - 1) * cell_width) as u32, cell_height as u32),
+                        );
+                        target.fill_solid(&rest, background)?;
+                    }
+                }
+                // unsupported chars and tabs only get the background
+                (None, _) => {
+                    if let Some(background) = self.background_color {
+                        target.fill_solid(&cell, background)?;
+                    }
+                }
+            }
+            column += columns;
+        }
+
+        let width = (column * cell_width) as u32;
+        self.draw_decorations(width, top_left, target)?;
+        Ok(position + Point::new(width as i32, 0))
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let top_left = position - Point::new(0, self.baseline_offset(baseline));
+        if width != 0 {
+            if let Some(background) = self.background_color {
+                let size = Size::new(width, self.size.val() as u32);
+                target.fill_solid(&Rectangle::new(top_left, size), background)?;
+            }
+            self.draw_decorations(width, top_left, target)?;
+        }
+        Ok(position + Point::new(width as i32, 0))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let top_left = position - Point::new(0, self.baseline_offset(baseline));
+        let width = self.text_width(text);
+        TextMetrics {
+            bounding_box: Rectangle::new(top_left, Size::new(width, self.size.val() as u32)),
+            next_position: position + Point::new(width as i32, 0),
+        }
+    }
+
+    fn line_height(&self) -> u32 {
+        self.size.val() as u32
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{NotoGlyphs, RasterizedChar, get_raster};
+    use embedded_graphics::Drawable;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{BinaryColor, Gray8, GrayColor, Rgb565};
+    use embedded_graphics::text::Text;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    #[test]
+    fn test_blend() {
+        assert_eq!(blend(Rgb888::BLACK, Rgb888::WHITE, 0), Rgb888::BLACK);
+        assert_eq!(blend(Rgb888::BLACK, Rgb888::WHITE, 255), Rgb888::WHITE);
+        assert_eq!(blend(Gray8::BLACK, Gray8::WHITE, 128), Gray8::new(128));
+        assert_eq!(blend(Rgb565::BLACK, Rgb565::RED, 255), Rgb565::RED);
+    }
+
+    #[test]
+    fn test_draw_string_with_background() {
+        let style =
+            NotoTextStyle::new(WEIGHT, SIZE, Gray8::WHITE).with_background_color(Gray8::BLACK);
+        let mut display = MockDisplay::<Gray8>::new();
+        let next = Text::with_baseline("Ab", Point::new(1, 2), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(next, Point::new(1 + 2 * 7, 2));
+
+        for (i, c) in ['A', 'b'].into_iter().enumerate() {
+            let raster = get_raster(c, WEIGHT, SIZE).unwrap();
+            for y in 0..16 {
+                for x in 0..7 {
+                    let point = Point::new(1 + (7 * i + x) as i32, 2 + y as i32);
+                    let expected = Gray8::new(raster.pixel(x, y));
+                    assert_eq!(display.get_pixel(point), Some(expected), "{c} {point}");
+                }
+            }
+        }
+        assert_eq!(display.get_pixel(Point::new(0, 2)), None);
+        assert_eq!(display.get_pixel(Point::new(1, 18)), None);
+    }
+
+    #[test]
+    fn test_draw_string_tab_glyph_with_background() {
+        const ROW: &[u8] = &[255; 7];
+        const BLOCK: RasterizedChar = RasterizedChar::new(&[ROW; 16], 7);
+        let tabs = |c: char, _, _| (c == '\t').then_some(BLOCK);
+        let style = NotoTextStyle::new(WEIGHT, SIZE, Gray8::WHITE)
+            .with_background_color(Gray8::BLACK)
+            .with_glyphs(tabs.chain(NotoGlyphs));
+        let mut display = MockDisplay::<Gray8>::new();
+        // the tab spans the columns 1 to 3
+        Text::with_baseline("a\tb", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        for y in 0..16 {
+            for x in 7..4 * 7 {
+                let expected = if x < 2 * 7 {
+                    Gray8::WHITE
+                } else {
+                    Gray8::BLACK
+                };
+                let point = Point::new(x, y);
+                assert_eq!(display.get_pixel(point), Some(expected), "{point}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_draw_string_transparent() {
+        let style = NotoTextStyle::new(WEIGHT, SIZE, BinaryColor::On);
+        let mut display = MockDisplay::<BinaryColor>::new();
+        // the position is the last row of characters without descenders
+        Text::new("A", Point::new(0, 10), style)
+            .draw(&mut display)
+            .unwrap();
+
+        let raster = get_raster('A', WEIGHT, SIZE).unwrap();
+        for y in 0..16 {
+            for x in 0..7 {
+                let expected =
+                    (raster.pixel(x, y) >= TRANSPARENT_THRESHOLD).then_some(BinaryColor::On);
+                assert_eq!(display.get_pixel(Point::new(x as i32, y as i32)), expected);
+            }
+        }
+        // 'A' ends on the alphabetic baseline
+        assert!((0..7).any(|x| raster.pixel(x, 10) >= TRANSPARENT_THRESHOLD));
+        assert!((0..7).all(|x| raster.pixel(x, 11) == 0));
+    }
+
+    #[test]
+    fn test_measure_and_decorations() {
+        let mut style = NotoTextStyle::new(WEIGHT, SIZE, Rgb565::WHITE);
+        let metrics = style.measure_string("a\tb", Point::new(0, 20), Baseline::Bottom);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::new(0, 5), Size::new(5 * 7, 16))
+        );
+        assert_eq!(metrics.next_position, Point::new(5 * 7, 20));
+        assert_eq!(style.line_height(), 16);
+
+        style.set_text_color(None);
+        style.set_underline_color(DecorationColor::Custom(Rgb565::RED));
+        let mut display = MockDisplay::<Rgb565>::new();
+        style
+            .draw_whitespace(3, Point::zero(), Baseline::Top, &mut display)
+            .unwrap();
+        let underline = get_decoration_metrics(WEIGHT, SIZE).underline_position() as i32;
+        assert_eq!(
+            display.get_pixel(Point::new(2, underline)),
+            Some(Rgb565::RED)
+        );
+        assert_eq!(display.get_pixel(Point::new(3, underline)), None);
+        assert_eq!(display.get_pixel(Point::new(0, 0)), None);
+    }
+}
@@ -17,7 +17,7 @@
 //! * ✅ nice anti-aliasing/smoothing and better looking than legacy bitmap fonts
 //! * ✅ every pixel is encoded in a byte (0-255) and not a bit, which results in a much nicer result on the screen.
 //! * ✅ relevant font sizes, such as 14, 18, 22, and 32px (as optional build time features)
-//! * ✅ zero dependencies
+//! * ✅ zero dependencies (optional `embedded-graphics`, `ratatui`, and `log` integrations)
 //! * ✅ All characters are aligned in their box/raster. If they are printed next to each other, the result looks nice.
 //!
 //! Please check [README.md](https://github.com/phip1611/noto-sans-mono-bitmap-rs/blob/main/README.md)
@@ -46,12 +46,72 @@
 
 // # THIS FILE IS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
 
+#[cfg(feature = "ratatui")]
+extern crate std;
+
+#[cfg(feature = "bold")]
 mod bold;
+#[cfg(feature = "light")]
 mod light;
+#[cfg(feature = "regular")]
 mod regular;
 
+mod blob;
+#[cfg(any(feature = "ratatui", feature = "log"))]
+mod color;
+#[cfg(feature = "compressed")]
+mod compressed;
+mod decoration;
+mod effects;
+mod glyph_data;
+#[cfg(feature = "embedded-graphics")]
+mod graphics;
+#[cfg(feature = "log")]
+mod logger;
+mod measure;
+mod raster;
+mod rotate;
+mod scale;
+mod select;
+mod source;
+#[cfg(feature = "subpixel")]
+mod subpixel;
+mod supported;
+mod synthetic;
+#[cfg(test)]
+mod tests;
+#[cfg(feature = "ratatui")]
+mod tui;
+mod wrap;
+
+pub use blob::{BlobFont, BlobRaster, FontBlob, ParseBlobError};
+#[cfg(feature = "compressed")]
+pub use compressed::{CompressedChar, CompressedRow, CompressedRows};
+pub use decoration::{Decorated, DecorationMetrics, Decorations};
+pub use effects::{EFFECT_PADDING, Effects, Mask, draw_char_with_effects};
+#[cfg(feature = "embedded-graphics")]
+pub use graphics::NotoTextStyle;
+#[cfg(feature = "log")]
+pub use logger::{FramebufferLogger, Overflow};
+pub use measure::{TabWidth, measure_str};
+pub use raster::Raster;
+pub use rotate::{Mirror, Mirrored, Rotated, Rotation, draw_str_rotated};
+pub use scale::{Downscaled, ScaleMode, Upscaled};
+pub use select::{FontSpec, ParseFontError};
+pub use source::{Chain, GlyphSource, NotoGlyphs};
+#[cfg(feature = "subpixel")]
+pub use subpixel::{SubpixelChar, SubpixelOrder};
+pub use supported::{SupportedChars, is_supported, supported_chars};
+pub use synthetic::{Emboldened, Thinned};
+#[cfg(feature = "ratatui")]
+pub use tui::FramebufferBackend;
+pub use wrap::{Alignment, WrapIter, WrappedLine, wrap_str};
+
 /// Describes the relevant information for a rendered char of the font.
-#[derive(Debug)]
+///
+/// Implements [`Raster`], hence, it can be transformed at runtime, e.g., with
+/// [`Raster::upscale`].
+#[derive(Debug, Clone, Copy)]
 pub struct RasterizedChar {
     /// The actual font data that is `height` * `width` bytes in size.
     /// Each byte describes the intensity of a pixel from 0 to 255.
@@ -65,6 +125,29 @@ pub struct RasterizedChar {
 }
 
 impl RasterizedChar {
+    /// Creates a raster of `width` x `raster.len()` pixels from custom data, e.g.,
+    /// for logos or icons of a custom [`GlyphSource`]. Each byte describes the
+    /// intensity of a pixel from 0 to 255.
+    ///
+    /// # Panics
+    /// Panics if a row is not `width` bytes long. In a `const` context, this is
+    /// a compile time error.
+    pub const fn new(raster: &'static [&'static [u8]], width: usize) -> Self {
+        let mut i = 0;
+        while i < raster.len() {
+            assert!(
+                raster[i].len() == width,
+                "all rows must be `width` bytes long"
+            );
+            i += 1;
+        }
+        Self {
+            raster,
+            height: raster.len(),
+            width,
+        }
+    }
+
     /// The actual font data that is `height` * `width` bytes in size.
     /// Each byte describes the intensity of a pixel from 0 to 255.
     #[inline]
@@ -90,7 +173,7 @@ impl RasterizedChar {
 /// Supported font weights.
 ///
 /// The available variants depend on the selected Cargo build features.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(usize)]
 pub enum FontWeight {
     #[cfg(feature = "light")]
@@ -102,11 +185,36 @@ pub enum FontWeight {
 }
 
 impl FontWeight {
+    /// All font weights that are enabled by Cargo features, from the lightest to the
+    /// boldest.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "light")]
+        Self::Light,
+        #[cfg(feature = "regular")]
+        Self::Regular,
+        #[cfg(feature = "bold")]
+        Self::Bold,
+    ];
+
     /// Returns the numeric value of the enum variant.
     #[inline]
     pub const fn val(self) -> usize {
         self as _
     }
+
+    /// Returns the name of the font weight, which is also the name of its Cargo
+    /// feature, such as `"bold"`.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "light")]
+            Self::Light => "light",
+            #[cfg(feature = "regular")]
+            Self::Regular => "regular",
+            #[cfg(feature = "bold")]
+            Self::Bold => "bold",
+        }
+    }
 }
 
 /// The height of the pre-rasterized font.
@@ -117,7 +225,7 @@ impl FontWeight {
 /// horizontal padding included.
 ///
 /// The available variants depend on the selected Cargo build features.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(usize)]
 pub enum RasterHeight {
     #[cfg(feature = "size_16")]
@@ -131,6 +239,18 @@ pub enum RasterHeight {
 }
 
 impl RasterHeight {
+    /// All raster heights that are enabled by Cargo features in ascending order.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "size_16")]
+        Self::Size16,
+        #[cfg(feature = "size_20")]
+        Self::Size20,
+        #[cfg(feature = "size_24")]
+        Self::Size24,
+        #[cfg(feature = "size_32")]
+        Self::Size32,
+    ];
+
     /// Returns the numeric value of the variant.
     #[inline]
     pub const fn val(self) -> usize {
@@ -193,6 +313,129 @@ pub const fn get_raster(c: char, style: FontWeight, size: RasterHeight) -> Optio
     }
 }
 
+/// Returns a [`SubpixelChar`] for LCD subpixel rendering for the given char,
+/// [`FontWeight`], and [`RasterHeight`].
+///
+/// Returns None, if the given char is not known by the font or if the corresponding
+/// unicode range feature is not enabled, exactly like [`get_raster`].
+#[cfg(feature = "subpixel")]
+#[inline]
+pub const fn get_raster_subpixel(
+    c: char,
+    style: FontWeight,
+    size: RasterHeight,
+) -> Option<SubpixelChar> {
+    let raster = match style {
+        #[cfg(feature = "light")]
+        FontWeight::Light => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::light::size_16::get_char_subpixel(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::light::size_20::get_char_subpixel(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::light::size_24::get_char_subpixel(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::light::size_32::get_char_subpixel(c),
+        },
+        #[cfg(feature = "regular")]
+        FontWeight::Regular => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::regular::size_16::get_char_subpixel(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::regular::size_20::get_char_subpixel(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::regular::size_24::get_char_subpixel(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::regular::size_32::get_char_subpixel(c),
+        },
+        #[cfg(feature = "bold")]
+        FontWeight::Bold => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::bold::size_16::get_char_subpixel(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::bold::size_20::get_char_subpixel(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::bold::size_24::get_char_subpixel(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::bold::size_32::get_char_subpixel(c),
+        },
+    };
+
+    // Option::map() not yet const-compatible
+    if let Some(raster) = raster {
+        let subpixel_char = SubpixelChar {
+            raster,
+            height: size.val(),
+            width: get_raster_width(style, size),
+        };
+        Some(subpixel_char)
+    } else {
+        None
+    }
+}
+
+/// Returns a [`CompressedChar`] with the run-length encoded raster for the given
+/// char, [`FontWeight`], and [`RasterHeight`].
+///
+/// Returns None, if the given char is not known by the font. The encoded rasters
+/// are much smaller than the raw rasters of [`get_raster`]. If only this function
+/// is used, the linker discards the raw rasters.
+#[cfg(feature = "compressed")]
+#[inline]
+pub const fn get_raster_compressed(
+    c: char,
+    style: FontWeight,
+    size: RasterHeight,
+) -> Option<CompressedChar> {
+    let data = match style {
+        #[cfg(feature = "light")]
+        FontWeight::Light => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::light::size_16::get_char_compressed(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::light::size_20::get_char_compressed(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::light::size_24::get_char_compressed(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::light::size_32::get_char_compressed(c),
+        },
+        #[cfg(feature = "regular")]
+        FontWeight::Regular => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::regular::size_16::get_char_compressed(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::regular::size_20::get_char_compressed(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::regular::size_24::get_char_compressed(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::regular::size_32::get_char_compressed(c),
+        },
+        #[cfg(feature = "bold")]
+        FontWeight::Bold => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::bold::size_16::get_char_compressed(c),
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::bold::size_20::get_char_compressed(c),
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::bold::size_24::get_char_compressed(c),
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::bold::size_32::get_char_compressed(c),
+        },
+    };
+
+    // Option::map() not yet const-compatible
+    if let Some(data) = data {
+        let compressed_char = CompressedChar {
+            data,
+            height: size.val(),
+            width: get_raster_width(style, size),
+        };
+        Some(compressed_char)
+    } else {
+        None
+    }
+}
+
 /// Returns the width in pixels a char will occupy on the screen.
 ///
 /// The width is constant for all characters regarding the same combination
@@ -237,4 +480,202 @@ pub const fn get_raster_width(style: FontWeight, size: RasterHeight) -> usize {
     }
 }
 
+/// The maximum [`get_raster_width`] of all enabled font weights and raster heights.
+/// Useful to size buffers on the stack, so that they fit every [`RasterizedChar`].
+pub const MAX_RASTER_WIDTH: usize = {
+    let max = 0;
+    #[cfg(all(feature = "light", feature = "size_16"))]
+    let max = if crate::light::size_16::RASTER_WIDTH > max {
+        crate::light::size_16::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "light", feature = "size_20"))]
+    let max = if crate::light::size_20::RASTER_WIDTH > max {
+        crate::light::size_20::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "light", feature = "size_24"))]
+    let max = if crate::light::size_24::RASTER_WIDTH > max {
+        crate::light::size_24::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "light", feature = "size_32"))]
+    let max = if crate::light::size_32::RASTER_WIDTH > max {
+        crate::light::size_32::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "regular", feature = "size_16"))]
+    let max = if crate::regular::size_16::RASTER_WIDTH > max {
+        crate::regular::size_16::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "regular", feature = "size_20"))]
+    let max = if crate::regular::size_20::RASTER_WIDTH > max {
+        crate::regular::size_20::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "regular", feature = "size_24"))]
+    let max = if crate::regular::size_24::RASTER_WIDTH > max {
+        crate::regular::size_24::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "regular", feature = "size_32"))]
+    let max = if crate::regular::size_32::RASTER_WIDTH > max {
+        crate::regular::size_32::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "bold", feature = "size_16"))]
+    let max = if crate::bold::size_16::RASTER_WIDTH > max {
+        crate::bold::size_16::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "bold", feature = "size_20"))]
+    let max = if crate::bold::size_20::RASTER_WIDTH > max {
+        crate::bold::size_20::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "bold", feature = "size_24"))]
+    let max = if crate::bold::size_24::RASTER_WIDTH > max {
+        crate::bold::size_24::RASTER_WIDTH
+    } else {
+        max
+    };
+    #[cfg(all(feature = "bold", feature = "size_32"))]
+    let max = if crate::bold::size_32::RASTER_WIDTH > max {
+        crate::bold::size_32::RASTER_WIDTH
+    } else {
+        max
+    };
+
+    max
+};
+
+/// The maximum [`RasterHeight`] of all enabled raster heights. Useful to size
+/// buffers on the stack, so that they fit every [`RasterizedChar`].
+pub const MAX_RASTER_HEIGHT: usize = {
+    let max = 0;
+    #[cfg(feature = "size_16")]
+    let max = if 16 > max { 16 } else { max };
+    #[cfg(feature = "size_20")]
+    let max = if 20 > max { 20 } else { max };
+    #[cfg(feature = "size_24")]
+    let max = if 24 > max { 24 } else { max };
+    #[cfg(feature = "size_32")]
+    let max = if 32 > max { 32 } else { max };
+
+    max
+};
+
+/// The contiguous ranges of all chars with a raster, i.e., all chars of the enabled
+/// unicode ranges without ignored symbols, such as control characters. Sorted in
+/// ascending order.
+pub(crate) const SUPPORTED_CHAR_RANGES: &[(char, char)] = &[
+    #[cfg(feature = "unicode-basic-latin")]
+    (' ', '~'),
+    #[cfg(feature = "unicode-latin-1-supplement")]
+    ('¡', '¬'),
+    #[cfg(feature = "unicode-latin-1-supplement")]
+    ('®', 'ÿ'),
+    #[cfg(feature = "unicode-latin-extended-a")]
+    ('Ā', 'ſ'),
+    #[cfg(feature = "unicode-specials")]
+    ('�', '�'),
+];
+
+/// Returns the row of the baseline in the raster of each character for the given
+/// [`FontWeight`] and [`RasterHeight`], i.e., the number of rows above the baseline.
+///
+/// Characters without descenders, such as `A`, end in the row above it.
+#[inline]
+pub const fn get_baseline(style: FontWeight, size: RasterHeight) -> usize {
+    match style {
+        #[cfg(feature = "light")]
+        FontWeight::Light => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::light::size_16::BASELINE,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::light::size_20::BASELINE,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::light::size_24::BASELINE,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::light::size_32::BASELINE,
+        },
+        #[cfg(feature = "regular")]
+        FontWeight::Regular => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::regular::size_16::BASELINE,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::regular::size_20::BASELINE,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::regular::size_24::BASELINE,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::regular::size_32::BASELINE,
+        },
+        #[cfg(feature = "bold")]
+        FontWeight::Bold => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::bold::size_16::BASELINE,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::bold::size_20::BASELINE,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::bold::size_24::BASELINE,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::bold::size_32::BASELINE,
+        },
+    }
+}
+
+/// Returns the [`DecorationMetrics`] for the given [`FontWeight`] and [`RasterHeight`].
+///
+/// They describe where underlines, strikethroughs, and overlines are placed in the
+/// raster of each character. Use them with [`Raster::decorate`].
+#[inline]
+pub const fn get_decoration_metrics(style: FontWeight, size: RasterHeight) -> DecorationMetrics {
+    match style {
+        #[cfg(feature = "light")]
+        FontWeight::Light => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::light::size_16::DECORATION_METRICS,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::light::size_20::DECORATION_METRICS,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::light::size_24::DECORATION_METRICS,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::light::size_32::DECORATION_METRICS,
+        },
+        #[cfg(feature = "regular")]
+        FontWeight::Regular => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::regular::size_16::DECORATION_METRICS,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::regular::size_20::DECORATION_METRICS,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::regular::size_24::DECORATION_METRICS,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::regular::size_32::DECORATION_METRICS,
+        },
+        #[cfg(feature = "bold")]
+        FontWeight::Bold => match size {
+            #[cfg(feature = "size_16")]
+            RasterHeight::Size16 => crate::bold::size_16::DECORATION_METRICS,
+            #[cfg(feature = "size_20")]
+            RasterHeight::Size20 => crate::bold::size_20::DECORATION_METRICS,
+            #[cfg(feature = "size_24")]
+            RasterHeight::Size24 => crate::bold::size_24::DECORATION_METRICS,
+            #[cfg(feature = "size_32")]
+            RasterHeight::Size32 => crate::bold::size_32::DECORATION_METRICS,
+        },
+    }
+}
+
 // # THIS FILE IS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
@@ -4,6 +4,8 @@
 //! * Downloaded from: <https://fonts.google.com/noto/specimen/Noto+Sans+Mono>
 //! * License: SIL Open Font License (OFL) <https://scripts.sil.org/cms/scripts/page.php?site_id=nrsi&id=OFL>
 
+use crate::DecorationMetrics;
+
 /// The constant height of each rasterized character for the given font weight
 /// and size. This size corresponds to the size of the module name. The font
 /// size will be a few percent smaller, as each raster contains a small vertical
@@ -17,6 +19,56 @@ pub const RASTER_HEIGHT: usize = 16;
 /// have to perform additional alignment of characters or fill in padding.
 pub const RASTER_WIDTH: usize = 7;
 
+/// The row of the baseline in the raster, i.e., the number of rows above the
+/// baseline. Characters without descenders end in the row above it.
+pub const BASELINE: usize = 11;
+
+/// The positions of text decorations inside the raster of each character. They
+/// are computed from the `post` and `OS/2` tables of the font.
+pub const DECORATION_METRICS: DecorationMetrics = DecorationMetrics {
+    underline_position: 12,
+    underline_thickness: 1,
+    strikeout_position: 7,
+    strikeout_thickness: 1,
+    overline_position: 2,
+};
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-basic-latin`.
+#[cfg(feature = "unicode-basic-latin")]
+static UNICODE_BASIC_LATIN: [&[u8]; 95 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-basic-latin.bin"),
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-basic-latin_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement`.
+#[cfg(feature = "unicode-latin-1-supplement")]
+static UNICODE_LATIN_1_SUPPLEMENT: [&[u8]; 94 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-latin-1-supplement.bin"),
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-latin-1-supplement_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-extended-a`.
+#[cfg(feature = "unicode-latin-extended-a")]
+static UNICODE_LATIN_EXTENDED_A: [&[u8]; 128 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-latin-extended-a.bin"),
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-latin-extended-a_index.bin"),
+    RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-specials`.
+#[cfg(feature = "unicode-specials")]
+static UNICODE_SPECIALS: [&[u8]; RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-specials.bin"),
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-specials_index.bin"),
+    RASTER_WIDTH,
+);
+
 /// Returns the raster of the given character for font weight light and font size 11px.
 /// Wide characters, such as '�', will be truncated in their width in order to fulfill
 /// the mono font guarantee. All characters are centered in their raster.
@@ -25,1215 +77,6776 @@ pub const fn get_char(c: char) -> Option<&'static [&'static [u8]]> {
     match c {
         // letter: ' ' / 0x20
         #[cfg(feature = "unicode-basic-latin")]
-        ' ' => Some(include!("../res_rasterized_characters/0x20_h16_wLight.txt")),
+        ' ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            0,
+            RASTER_HEIGHT,
+        )),
         // letter: '!' / 0x21
         #[cfg(feature = "unicode-basic-latin")]
-        '!' => Some(include!("../res_rasterized_characters/0x21_h16_wLight.txt")),
+        '!' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            1,
+            RASTER_HEIGHT,
+        )),
         // letter: '"' / 0x22
         #[cfg(feature = "unicode-basic-latin")]
-        '"' => Some(include!("../res_rasterized_characters/0x22_h16_wLight.txt")),
+        '"' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            2,
+            RASTER_HEIGHT,
+        )),
         // letter: '#' / 0x23
         #[cfg(feature = "unicode-basic-latin")]
-        '#' => Some(include!("../res_rasterized_characters/0x23_h16_wLight.txt")),
+        '#' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            3,
+            RASTER_HEIGHT,
+        )),
         // letter: '$' / 0x24
         #[cfg(feature = "unicode-basic-latin")]
-        '$' => Some(include!("../res_rasterized_characters/0x24_h16_wLight.txt")),
+        '$' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            4,
+            RASTER_HEIGHT,
+        )),
         // letter: '%' / 0x25
         #[cfg(feature = "unicode-basic-latin")]
-        '%' => Some(include!("../res_rasterized_characters/0x25_h16_wLight.txt")),
+        '%' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            5,
+            RASTER_HEIGHT,
+        )),
         // letter: '&' / 0x26
         #[cfg(feature = "unicode-basic-latin")]
-        '&' => Some(include!("../res_rasterized_characters/0x26_h16_wLight.txt")),
+        '&' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            6,
+            RASTER_HEIGHT,
+        )),
         // letter: ''' / 0x27
         #[cfg(feature = "unicode-basic-latin")]
-        '\'' => Some(include!("../res_rasterized_characters/0x27_h16_wLight.txt")),
+        '\'' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            7,
+            RASTER_HEIGHT,
+        )),
         // letter: '(' / 0x28
         #[cfg(feature = "unicode-basic-latin")]
-        '(' => Some(include!("../res_rasterized_characters/0x28_h16_wLight.txt")),
+        '(' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            8,
+            RASTER_HEIGHT,
+        )),
         // letter: ')' / 0x29
         #[cfg(feature = "unicode-basic-latin")]
-        ')' => Some(include!("../res_rasterized_characters/0x29_h16_wLight.txt")),
+        ')' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            9,
+            RASTER_HEIGHT,
+        )),
         // letter: '*' / 0x2a
         #[cfg(feature = "unicode-basic-latin")]
-        '*' => Some(include!("../res_rasterized_characters/0x2a_h16_wLight.txt")),
+        '*' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            10,
+            RASTER_HEIGHT,
+        )),
         // letter: '+' / 0x2b
         #[cfg(feature = "unicode-basic-latin")]
-        '+' => Some(include!("../res_rasterized_characters/0x2b_h16_wLight.txt")),
+        '+' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            11,
+            RASTER_HEIGHT,
+        )),
         // letter: ',' / 0x2c
         #[cfg(feature = "unicode-basic-latin")]
-        ',' => Some(include!("../res_rasterized_characters/0x2c_h16_wLight.txt")),
+        ',' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            12,
+            RASTER_HEIGHT,
+        )),
         // letter: '-' / 0x2d
         #[cfg(feature = "unicode-basic-latin")]
-        '-' => Some(include!("../res_rasterized_characters/0x2d_h16_wLight.txt")),
+        '-' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            13,
+            RASTER_HEIGHT,
+        )),
         // letter: '.' / 0x2e
         #[cfg(feature = "unicode-basic-latin")]
-        '.' => Some(include!("../res_rasterized_characters/0x2e_h16_wLight.txt")),
+        '.' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            14,
+            RASTER_HEIGHT,
+        )),
         // letter: '/' / 0x2f
         #[cfg(feature = "unicode-basic-latin")]
-        '/' => Some(include!("../res_rasterized_characters/0x2f_h16_wLight.txt")),
+        '/' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            15,
+            RASTER_HEIGHT,
+        )),
         // letter: '0' / 0x30
         #[cfg(feature = "unicode-basic-latin")]
-        '0' => Some(include!("../res_rasterized_characters/0x30_h16_wLight.txt")),
+        '0' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            16,
+            RASTER_HEIGHT,
+        )),
         // letter: '1' / 0x31
         #[cfg(feature = "unicode-basic-latin")]
-        '1' => Some(include!("../res_rasterized_characters/0x31_h16_wLight.txt")),
+        '1' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            17,
+            RASTER_HEIGHT,
+        )),
         // letter: '2' / 0x32
         #[cfg(feature = "unicode-basic-latin")]
-        '2' => Some(include!("../res_rasterized_characters/0x32_h16_wLight.txt")),
+        '2' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            18,
+            RASTER_HEIGHT,
+        )),
         // letter: '3' / 0x33
         #[cfg(feature = "unicode-basic-latin")]
-        '3' => Some(include!("../res_rasterized_characters/0x33_h16_wLight.txt")),
+        '3' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            19,
+            RASTER_HEIGHT,
+        )),
         // letter: '4' / 0x34
         #[cfg(feature = "unicode-basic-latin")]
-        '4' => Some(include!("../res_rasterized_characters/0x34_h16_wLight.txt")),
+        '4' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            20,
+            RASTER_HEIGHT,
+        )),
         // letter: '5' / 0x35
         #[cfg(feature = "unicode-basic-latin")]
-        '5' => Some(include!("../res_rasterized_characters/0x35_h16_wLight.txt")),
+        '5' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            21,
+            RASTER_HEIGHT,
+        )),
         // letter: '6' / 0x36
         #[cfg(feature = "unicode-basic-latin")]
-        '6' => Some(include!("../res_rasterized_characters/0x36_h16_wLight.txt")),
+        '6' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            22,
+            RASTER_HEIGHT,
+        )),
         // letter: '7' / 0x37
         #[cfg(feature = "unicode-basic-latin")]
-        '7' => Some(include!("../res_rasterized_characters/0x37_h16_wLight.txt")),
+        '7' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            23,
+            RASTER_HEIGHT,
+        )),
         // letter: '8' / 0x38
         #[cfg(feature = "unicode-basic-latin")]
-        '8' => Some(include!("../res_rasterized_characters/0x38_h16_wLight.txt")),
+        '8' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            24,
+            RASTER_HEIGHT,
+        )),
         // letter: '9' / 0x39
         #[cfg(feature = "unicode-basic-latin")]
-        '9' => Some(include!("../res_rasterized_characters/0x39_h16_wLight.txt")),
+        '9' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            25,
+            RASTER_HEIGHT,
+        )),
         // letter: ':' / 0x3a
         #[cfg(feature = "unicode-basic-latin")]
-        ':' => Some(include!("../res_rasterized_characters/0x3a_h16_wLight.txt")),
+        ':' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            26,
+            RASTER_HEIGHT,
+        )),
         // letter: ';' / 0x3b
         #[cfg(feature = "unicode-basic-latin")]
-        ';' => Some(include!("../res_rasterized_characters/0x3b_h16_wLight.txt")),
+        ';' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            27,
+            RASTER_HEIGHT,
+        )),
         // letter: '<' / 0x3c
         #[cfg(feature = "unicode-basic-latin")]
-        '<' => Some(include!("../res_rasterized_characters/0x3c_h16_wLight.txt")),
+        '<' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            28,
+            RASTER_HEIGHT,
+        )),
         // letter: '=' / 0x3d
         #[cfg(feature = "unicode-basic-latin")]
-        '=' => Some(include!("../res_rasterized_characters/0x3d_h16_wLight.txt")),
+        '=' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            29,
+            RASTER_HEIGHT,
+        )),
         // letter: '>' / 0x3e
         #[cfg(feature = "unicode-basic-latin")]
-        '>' => Some(include!("../res_rasterized_characters/0x3e_h16_wLight.txt")),
+        '>' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            30,
+            RASTER_HEIGHT,
+        )),
         // letter: '?' / 0x3f
         #[cfg(feature = "unicode-basic-latin")]
-        '?' => Some(include!("../res_rasterized_characters/0x3f_h16_wLight.txt")),
+        '?' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            31,
+            RASTER_HEIGHT,
+        )),
         // letter: '@' / 0x40
         #[cfg(feature = "unicode-basic-latin")]
-        '@' => Some(include!("../res_rasterized_characters/0x40_h16_wLight.txt")),
+        '@' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            32,
+            RASTER_HEIGHT,
+        )),
         // letter: 'A' / 0x41
         #[cfg(feature = "unicode-basic-latin")]
-        'A' => Some(include!("../res_rasterized_characters/0x41_h16_wLight.txt")),
+        'A' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            33,
+            RASTER_HEIGHT,
+        )),
         // letter: 'B' / 0x42
         #[cfg(feature = "unicode-basic-latin")]
-        'B' => Some(include!("../res_rasterized_characters/0x42_h16_wLight.txt")),
+        'B' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            34,
+            RASTER_HEIGHT,
+        )),
         // letter: 'C' / 0x43
         #[cfg(feature = "unicode-basic-latin")]
-        'C' => Some(include!("../res_rasterized_characters/0x43_h16_wLight.txt")),
+        'C' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            35,
+            RASTER_HEIGHT,
+        )),
         // letter: 'D' / 0x44
         #[cfg(feature = "unicode-basic-latin")]
-        'D' => Some(include!("../res_rasterized_characters/0x44_h16_wLight.txt")),
+        'D' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            36,
+            RASTER_HEIGHT,
+        )),
         // letter: 'E' / 0x45
         #[cfg(feature = "unicode-basic-latin")]
-        'E' => Some(include!("../res_rasterized_characters/0x45_h16_wLight.txt")),
+        'E' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            37,
+            RASTER_HEIGHT,
+        )),
         // letter: 'F' / 0x46
         #[cfg(feature = "unicode-basic-latin")]
-        'F' => Some(include!("../res_rasterized_characters/0x46_h16_wLight.txt")),
+        'F' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            38,
+            RASTER_HEIGHT,
+        )),
         // letter: 'G' / 0x47
         #[cfg(feature = "unicode-basic-latin")]
-        'G' => Some(include!("../res_rasterized_characters/0x47_h16_wLight.txt")),
+        'G' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            39,
+            RASTER_HEIGHT,
+        )),
         // letter: 'H' / 0x48
         #[cfg(feature = "unicode-basic-latin")]
-        'H' => Some(include!("../res_rasterized_characters/0x48_h16_wLight.txt")),
+        'H' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            40,
+            RASTER_HEIGHT,
+        )),
         // letter: 'I' / 0x49
         #[cfg(feature = "unicode-basic-latin")]
-        'I' => Some(include!("../res_rasterized_characters/0x49_h16_wLight.txt")),
+        'I' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            41,
+            RASTER_HEIGHT,
+        )),
         // letter: 'J' / 0x4a
         #[cfg(feature = "unicode-basic-latin")]
-        'J' => Some(include!("../res_rasterized_characters/0x4a_h16_wLight.txt")),
+        'J' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            42,
+            RASTER_HEIGHT,
+        )),
         // letter: 'K' / 0x4b
         #[cfg(feature = "unicode-basic-latin")]
-        'K' => Some(include!("../res_rasterized_characters/0x4b_h16_wLight.txt")),
+        'K' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            43,
+            RASTER_HEIGHT,
+        )),
         // letter: 'L' / 0x4c
         #[cfg(feature = "unicode-basic-latin")]
-        'L' => Some(include!("../res_rasterized_characters/0x4c_h16_wLight.txt")),
+        'L' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            44,
+            RASTER_HEIGHT,
+        )),
         // letter: 'M' / 0x4d
         #[cfg(feature = "unicode-basic-latin")]
-        'M' => Some(include!("../res_rasterized_characters/0x4d_h16_wLight.txt")),
+        'M' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            45,
+            RASTER_HEIGHT,
+        )),
         // letter: 'N' / 0x4e
         #[cfg(feature = "unicode-basic-latin")]
-        'N' => Some(include!("../res_rasterized_characters/0x4e_h16_wLight.txt")),
+        'N' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            46,
+            RASTER_HEIGHT,
+        )),
         // letter: 'O' / 0x4f
         #[cfg(feature = "unicode-basic-latin")]
-        'O' => Some(include!("../res_rasterized_characters/0x4f_h16_wLight.txt")),
+        'O' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            47,
+            RASTER_HEIGHT,
+        )),
         // letter: 'P' / 0x50
         #[cfg(feature = "unicode-basic-latin")]
-        'P' => Some(include!("../res_rasterized_characters/0x50_h16_wLight.txt")),
+        'P' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            48,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Q' / 0x51
         #[cfg(feature = "unicode-basic-latin")]
-        'Q' => Some(include!("../res_rasterized_characters/0x51_h16_wLight.txt")),
+        'Q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            49,
+            RASTER_HEIGHT,
+        )),
         // letter: 'R' / 0x52
         #[cfg(feature = "unicode-basic-latin")]
-        'R' => Some(include!("../res_rasterized_characters/0x52_h16_wLight.txt")),
+        'R' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            50,
+            RASTER_HEIGHT,
+        )),
         // letter: 'S' / 0x53
         #[cfg(feature = "unicode-basic-latin")]
-        'S' => Some(include!("../res_rasterized_characters/0x53_h16_wLight.txt")),
+        'S' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            51,
+            RASTER_HEIGHT,
+        )),
         // letter: 'T' / 0x54
         #[cfg(feature = "unicode-basic-latin")]
-        'T' => Some(include!("../res_rasterized_characters/0x54_h16_wLight.txt")),
+        'T' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            52,
+            RASTER_HEIGHT,
+        )),
         // letter: 'U' / 0x55
         #[cfg(feature = "unicode-basic-latin")]
-        'U' => Some(include!("../res_rasterized_characters/0x55_h16_wLight.txt")),
+        'U' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            53,
+            RASTER_HEIGHT,
+        )),
         // letter: 'V' / 0x56
         #[cfg(feature = "unicode-basic-latin")]
-        'V' => Some(include!("../res_rasterized_characters/0x56_h16_wLight.txt")),
+        'V' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            54,
+            RASTER_HEIGHT,
+        )),
         // letter: 'W' / 0x57
         #[cfg(feature = "unicode-basic-latin")]
-        'W' => Some(include!("../res_rasterized_characters/0x57_h16_wLight.txt")),
+        'W' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            55,
+            RASTER_HEIGHT,
+        )),
         // letter: 'X' / 0x58
         #[cfg(feature = "unicode-basic-latin")]
-        'X' => Some(include!("../res_rasterized_characters/0x58_h16_wLight.txt")),
+        'X' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            56,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Y' / 0x59
         #[cfg(feature = "unicode-basic-latin")]
-        'Y' => Some(include!("../res_rasterized_characters/0x59_h16_wLight.txt")),
+        'Y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            57,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Z' / 0x5a
         #[cfg(feature = "unicode-basic-latin")]
-        'Z' => Some(include!("../res_rasterized_characters/0x5a_h16_wLight.txt")),
+        'Z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            58,
+            RASTER_HEIGHT,
+        )),
         // letter: '[' / 0x5b
         #[cfg(feature = "unicode-basic-latin")]
-        '[' => Some(include!("../res_rasterized_characters/0x5b_h16_wLight.txt")),
+        '[' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            59,
+            RASTER_HEIGHT,
+        )),
         // letter: '\' / 0x5c
         #[cfg(feature = "unicode-basic-latin")]
-        '\\' => Some(include!("../res_rasterized_characters/0x5c_h16_wLight.txt")),
+        '\\' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            60,
+            RASTER_HEIGHT,
+        )),
         // letter: ']' / 0x5d
         #[cfg(feature = "unicode-basic-latin")]
-        ']' => Some(include!("../res_rasterized_characters/0x5d_h16_wLight.txt")),
+        ']' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            61,
+            RASTER_HEIGHT,
+        )),
         // letter: '^' / 0x5e
         #[cfg(feature = "unicode-basic-latin")]
-        '^' => Some(include!("../res_rasterized_characters/0x5e_h16_wLight.txt")),
+        '^' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            62,
+            RASTER_HEIGHT,
+        )),
         // letter: '_' / 0x5f
         #[cfg(feature = "unicode-basic-latin")]
-        '_' => Some(include!("../res_rasterized_characters/0x5f_h16_wLight.txt")),
+        '_' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            63,
+            RASTER_HEIGHT,
+        )),
         // letter: '`' / 0x60
         #[cfg(feature = "unicode-basic-latin")]
-        '`' => Some(include!("../res_rasterized_characters/0x60_h16_wLight.txt")),
+        '`' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            64,
+            RASTER_HEIGHT,
+        )),
         // letter: 'a' / 0x61
         #[cfg(feature = "unicode-basic-latin")]
-        'a' => Some(include!("../res_rasterized_characters/0x61_h16_wLight.txt")),
+        'a' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            65,
+            RASTER_HEIGHT,
+        )),
         // letter: 'b' / 0x62
         #[cfg(feature = "unicode-basic-latin")]
-        'b' => Some(include!("../res_rasterized_characters/0x62_h16_wLight.txt")),
+        'b' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            66,
+            RASTER_HEIGHT,
+        )),
         // letter: 'c' / 0x63
         #[cfg(feature = "unicode-basic-latin")]
-        'c' => Some(include!("../res_rasterized_characters/0x63_h16_wLight.txt")),
+        'c' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            67,
+            RASTER_HEIGHT,
+        )),
         // letter: 'd' / 0x64
         #[cfg(feature = "unicode-basic-latin")]
-        'd' => Some(include!("../res_rasterized_characters/0x64_h16_wLight.txt")),
+        'd' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            68,
+            RASTER_HEIGHT,
+        )),
         // letter: 'e' / 0x65
         #[cfg(feature = "unicode-basic-latin")]
-        'e' => Some(include!("../res_rasterized_characters/0x65_h16_wLight.txt")),
+        'e' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            69,
+            RASTER_HEIGHT,
+        )),
         // letter: 'f' / 0x66
         #[cfg(feature = "unicode-basic-latin")]
-        'f' => Some(include!("../res_rasterized_characters/0x66_h16_wLight.txt")),
+        'f' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            70,
+            RASTER_HEIGHT,
+        )),
         // letter: 'g' / 0x67
         #[cfg(feature = "unicode-basic-latin")]
-        'g' => Some(include!("../res_rasterized_characters/0x67_h16_wLight.txt")),
+        'g' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            71,
+            RASTER_HEIGHT,
+        )),
         // letter: 'h' / 0x68
         #[cfg(feature = "unicode-basic-latin")]
-        'h' => Some(include!("../res_rasterized_characters/0x68_h16_wLight.txt")),
+        'h' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            72,
+            RASTER_HEIGHT,
+        )),
         // letter: 'i' / 0x69
         #[cfg(feature = "unicode-basic-latin")]
-        'i' => Some(include!("../res_rasterized_characters/0x69_h16_wLight.txt")),
+        'i' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            73,
+            RASTER_HEIGHT,
+        )),
         // letter: 'j' / 0x6a
         #[cfg(feature = "unicode-basic-latin")]
-        'j' => Some(include!("../res_rasterized_characters/0x6a_h16_wLight.txt")),
+        'j' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            74,
+            RASTER_HEIGHT,
+        )),
         // letter: 'k' / 0x6b
         #[cfg(feature = "unicode-basic-latin")]
-        'k' => Some(include!("../res_rasterized_characters/0x6b_h16_wLight.txt")),
+        'k' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            75,
+            RASTER_HEIGHT,
+        )),
         // letter: 'l' / 0x6c
         #[cfg(feature = "unicode-basic-latin")]
-        'l' => Some(include!("../res_rasterized_characters/0x6c_h16_wLight.txt")),
+        'l' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            76,
+            RASTER_HEIGHT,
+        )),
         // letter: 'm' / 0x6d
         #[cfg(feature = "unicode-basic-latin")]
-        'm' => Some(include!("../res_rasterized_characters/0x6d_h16_wLight.txt")),
+        'm' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            77,
+            RASTER_HEIGHT,
+        )),
         // letter: 'n' / 0x6e
         #[cfg(feature = "unicode-basic-latin")]
-        'n' => Some(include!("../res_rasterized_characters/0x6e_h16_wLight.txt")),
+        'n' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            78,
+            RASTER_HEIGHT,
+        )),
         // letter: 'o' / 0x6f
         #[cfg(feature = "unicode-basic-latin")]
-        'o' => Some(include!("../res_rasterized_characters/0x6f_h16_wLight.txt")),
+        'o' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            79,
+            RASTER_HEIGHT,
+        )),
         // letter: 'p' / 0x70
         #[cfg(feature = "unicode-basic-latin")]
-        'p' => Some(include!("../res_rasterized_characters/0x70_h16_wLight.txt")),
+        'p' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            80,
+            RASTER_HEIGHT,
+        )),
         // letter: 'q' / 0x71
         #[cfg(feature = "unicode-basic-latin")]
-        'q' => Some(include!("../res_rasterized_characters/0x71_h16_wLight.txt")),
+        'q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            81,
+            RASTER_HEIGHT,
+        )),
         // letter: 'r' / 0x72
         #[cfg(feature = "unicode-basic-latin")]
-        'r' => Some(include!("../res_rasterized_characters/0x72_h16_wLight.txt")),
+        'r' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            82,
+            RASTER_HEIGHT,
+        )),
         // letter: 's' / 0x73
         #[cfg(feature = "unicode-basic-latin")]
-        's' => Some(include!("../res_rasterized_characters/0x73_h16_wLight.txt")),
+        's' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            83,
+            RASTER_HEIGHT,
+        )),
         // letter: 't' / 0x74
         #[cfg(feature = "unicode-basic-latin")]
-        't' => Some(include!("../res_rasterized_characters/0x74_h16_wLight.txt")),
+        't' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            84,
+            RASTER_HEIGHT,
+        )),
         // letter: 'u' / 0x75
         #[cfg(feature = "unicode-basic-latin")]
-        'u' => Some(include!("../res_rasterized_characters/0x75_h16_wLight.txt")),
+        'u' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            85,
+            RASTER_HEIGHT,
+        )),
         // letter: 'v' / 0x76
         #[cfg(feature = "unicode-basic-latin")]
-        'v' => Some(include!("../res_rasterized_characters/0x76_h16_wLight.txt")),
+        'v' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            86,
+            RASTER_HEIGHT,
+        )),
         // letter: 'w' / 0x77
         #[cfg(feature = "unicode-basic-latin")]
-        'w' => Some(include!("../res_rasterized_characters/0x77_h16_wLight.txt")),
+        'w' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            87,
+            RASTER_HEIGHT,
+        )),
         // letter: 'x' / 0x78
         #[cfg(feature = "unicode-basic-latin")]
-        'x' => Some(include!("../res_rasterized_characters/0x78_h16_wLight.txt")),
+        'x' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            88,
+            RASTER_HEIGHT,
+        )),
         // letter: 'y' / 0x79
         #[cfg(feature = "unicode-basic-latin")]
-        'y' => Some(include!("../res_rasterized_characters/0x79_h16_wLight.txt")),
+        'y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            89,
+            RASTER_HEIGHT,
+        )),
         // letter: 'z' / 0x7a
         #[cfg(feature = "unicode-basic-latin")]
-        'z' => Some(include!("../res_rasterized_characters/0x7a_h16_wLight.txt")),
+        'z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            90,
+            RASTER_HEIGHT,
+        )),
         // letter: '{' / 0x7b
         #[cfg(feature = "unicode-basic-latin")]
-        '{' => Some(include!("../res_rasterized_characters/0x7b_h16_wLight.txt")),
+        '{' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            91,
+            RASTER_HEIGHT,
+        )),
         // letter: '|' / 0x7c
         #[cfg(feature = "unicode-basic-latin")]
-        '|' => Some(include!("../res_rasterized_characters/0x7c_h16_wLight.txt")),
+        '|' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            92,
+            RASTER_HEIGHT,
+        )),
         // letter: '}' / 0x7d
         #[cfg(feature = "unicode-basic-latin")]
-        '}' => Some(include!("../res_rasterized_characters/0x7d_h16_wLight.txt")),
+        '}' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            93,
+            RASTER_HEIGHT,
+        )),
         // letter: '~' / 0x7e
         #[cfg(feature = "unicode-basic-latin")]
-        '~' => Some(include!("../res_rasterized_characters/0x7e_h16_wLight.txt")),
+        '~' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN,
+            94,
+            RASTER_HEIGHT,
+        )),
         // letter: '¡' / 0xa1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¡' => Some(include!("../res_rasterized_characters/0xa1_h16_wLight.txt")),
+        '¡' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            0,
+            RASTER_HEIGHT,
+        )),
         // letter: '¢' / 0xa2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¢' => Some(include!("../res_rasterized_characters/0xa2_h16_wLight.txt")),
+        '¢' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            1,
+            RASTER_HEIGHT,
+        )),
         // letter: '£' / 0xa3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '£' => Some(include!("../res_rasterized_characters/0xa3_h16_wLight.txt")),
+        '£' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            2,
+            RASTER_HEIGHT,
+        )),
         // letter: '¤' / 0xa4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¤' => Some(include!("../res_rasterized_characters/0xa4_h16_wLight.txt")),
+        '¤' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            3,
+            RASTER_HEIGHT,
+        )),
         // letter: '¥' / 0xa5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¥' => Some(include!("../res_rasterized_characters/0xa5_h16_wLight.txt")),
+        '¥' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            4,
+            RASTER_HEIGHT,
+        )),
         // letter: '¦' / 0xa6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¦' => Some(include!("../res_rasterized_characters/0xa6_h16_wLight.txt")),
+        '¦' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            5,
+            RASTER_HEIGHT,
+        )),
         // letter: '§' / 0xa7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '§' => Some(include!("../res_rasterized_characters/0xa7_h16_wLight.txt")),
+        '§' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            6,
+            RASTER_HEIGHT,
+        )),
         // letter: '¨' / 0xa8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¨' => Some(include!("../res_rasterized_characters/0xa8_h16_wLight.txt")),
+        '¨' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            7,
+            RASTER_HEIGHT,
+        )),
         // letter: '©' / 0xa9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '©' => Some(include!("../res_rasterized_characters/0xa9_h16_wLight.txt")),
+        '©' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            8,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ª' / 0xaa
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ª' => Some(include!("../res_rasterized_characters/0xaa_h16_wLight.txt")),
+        'ª' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            9,
+            RASTER_HEIGHT,
+        )),
         // letter: '«' / 0xab
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '«' => Some(include!("../res_rasterized_characters/0xab_h16_wLight.txt")),
+        '«' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            10,
+            RASTER_HEIGHT,
+        )),
         // letter: '¬' / 0xac
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¬' => Some(include!("../res_rasterized_characters/0xac_h16_wLight.txt")),
+        '¬' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            11,
+            RASTER_HEIGHT,
+        )),
         // letter: '®' / 0xae
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '®' => Some(include!("../res_rasterized_characters/0xae_h16_wLight.txt")),
+        '®' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            12,
+            RASTER_HEIGHT,
+        )),
         // letter: '¯' / 0xaf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¯' => Some(include!("../res_rasterized_characters/0xaf_h16_wLight.txt")),
+        '¯' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            13,
+            RASTER_HEIGHT,
+        )),
         // letter: '°' / 0xb0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '°' => Some(include!("../res_rasterized_characters/0xb0_h16_wLight.txt")),
+        '°' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            14,
+            RASTER_HEIGHT,
+        )),
         // letter: '±' / 0xb1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '±' => Some(include!("../res_rasterized_characters/0xb1_h16_wLight.txt")),
+        '±' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            15,
+            RASTER_HEIGHT,
+        )),
         // letter: '²' / 0xb2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '²' => Some(include!("../res_rasterized_characters/0xb2_h16_wLight.txt")),
+        '²' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            16,
+            RASTER_HEIGHT,
+        )),
         // letter: '³' / 0xb3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '³' => Some(include!("../res_rasterized_characters/0xb3_h16_wLight.txt")),
+        '³' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            17,
+            RASTER_HEIGHT,
+        )),
         // letter: '´' / 0xb4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '´' => Some(include!("../res_rasterized_characters/0xb4_h16_wLight.txt")),
+        '´' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            18,
+            RASTER_HEIGHT,
+        )),
         // letter: 'µ' / 0xb5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'µ' => Some(include!("../res_rasterized_characters/0xb5_h16_wLight.txt")),
+        'µ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            19,
+            RASTER_HEIGHT,
+        )),
         // letter: '¶' / 0xb6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¶' => Some(include!("../res_rasterized_characters/0xb6_h16_wLight.txt")),
+        '¶' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            20,
+            RASTER_HEIGHT,
+        )),
         // letter: '·' / 0xb7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '·' => Some(include!("../res_rasterized_characters/0xb7_h16_wLight.txt")),
+        '·' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            21,
+            RASTER_HEIGHT,
+        )),
         // letter: '¸' / 0xb8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¸' => Some(include!("../res_rasterized_characters/0xb8_h16_wLight.txt")),
+        '¸' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            22,
+            RASTER_HEIGHT,
+        )),
         // letter: '¹' / 0xb9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¹' => Some(include!("../res_rasterized_characters/0xb9_h16_wLight.txt")),
+        '¹' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            23,
+            RASTER_HEIGHT,
+        )),
         // letter: 'º' / 0xba
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'º' => Some(include!("../res_rasterized_characters/0xba_h16_wLight.txt")),
+        'º' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            24,
+            RASTER_HEIGHT,
+        )),
         // letter: '»' / 0xbb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '»' => Some(include!("../res_rasterized_characters/0xbb_h16_wLight.txt")),
+        '»' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            25,
+            RASTER_HEIGHT,
+        )),
         // letter: '¼' / 0xbc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¼' => Some(include!("../res_rasterized_characters/0xbc_h16_wLight.txt")),
+        '¼' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            26,
+            RASTER_HEIGHT,
+        )),
         // letter: '½' / 0xbd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '½' => Some(include!("../res_rasterized_characters/0xbd_h16_wLight.txt")),
+        '½' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            27,
+            RASTER_HEIGHT,
+        )),
         // letter: '¾' / 0xbe
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¾' => Some(include!("../res_rasterized_characters/0xbe_h16_wLight.txt")),
+        '¾' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            28,
+            RASTER_HEIGHT,
+        )),
         // letter: '¿' / 0xbf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '¿' => Some(include!("../res_rasterized_characters/0xbf_h16_wLight.txt")),
+        '¿' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            29,
+            RASTER_HEIGHT,
+        )),
         // letter: 'À' / 0xc0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'À' => Some(include!("../res_rasterized_characters/0xc0_h16_wLight.txt")),
+        'À' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            30,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Á' / 0xc1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Á' => Some(include!("../res_rasterized_characters/0xc1_h16_wLight.txt")),
+        'Á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            31,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Â' / 0xc2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Â' => Some(include!("../res_rasterized_characters/0xc2_h16_wLight.txt")),
+        'Â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            32,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ã' / 0xc3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ã' => Some(include!("../res_rasterized_characters/0xc3_h16_wLight.txt")),
+        'Ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            33,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ä' / 0xc4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ä' => Some(include!("../res_rasterized_characters/0xc4_h16_wLight.txt")),
+        'Ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            34,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Å' / 0xc5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Å' => Some(include!("../res_rasterized_characters/0xc5_h16_wLight.txt")),
+        'Å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            35,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Æ' / 0xc6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Æ' => Some(include!("../res_rasterized_characters/0xc6_h16_wLight.txt")),
+        'Æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            36,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ç' / 0xc7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ç' => Some(include!("../res_rasterized_characters/0xc7_h16_wLight.txt")),
+        'Ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            37,
+            RASTER_HEIGHT,
+        )),
         // letter: 'È' / 0xc8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'È' => Some(include!("../res_rasterized_characters/0xc8_h16_wLight.txt")),
+        'È' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            38,
+            RASTER_HEIGHT,
+        )),
         // letter: 'É' / 0xc9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'É' => Some(include!("../res_rasterized_characters/0xc9_h16_wLight.txt")),
+        'É' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            39,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ê' / 0xca
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ê' => Some(include!("../res_rasterized_characters/0xca_h16_wLight.txt")),
+        'Ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            40,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ë' / 0xcb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ë' => Some(include!("../res_rasterized_characters/0xcb_h16_wLight.txt")),
+        'Ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            41,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ì' / 0xcc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ì' => Some(include!("../res_rasterized_characters/0xcc_h16_wLight.txt")),
+        'Ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            42,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Í' / 0xcd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Í' => Some(include!("../res_rasterized_characters/0xcd_h16_wLight.txt")),
+        'Í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            43,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Î' / 0xce
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Î' => Some(include!("../res_rasterized_characters/0xce_h16_wLight.txt")),
+        'Î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            44,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ï' / 0xcf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ï' => Some(include!("../res_rasterized_characters/0xcf_h16_wLight.txt")),
+        'Ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            45,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ð' / 0xd0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ð' => Some(include!("../res_rasterized_characters/0xd0_h16_wLight.txt")),
+        'Ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            46,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ñ' / 0xd1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ñ' => Some(include!("../res_rasterized_characters/0xd1_h16_wLight.txt")),
+        'Ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            47,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ò' / 0xd2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ò' => Some(include!("../res_rasterized_characters/0xd2_h16_wLight.txt")),
+        'Ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            48,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ó' / 0xd3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ó' => Some(include!("../res_rasterized_characters/0xd3_h16_wLight.txt")),
+        'Ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            49,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ô' / 0xd4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ô' => Some(include!("../res_rasterized_characters/0xd4_h16_wLight.txt")),
+        'Ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            50,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Õ' / 0xd5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Õ' => Some(include!("../res_rasterized_characters/0xd5_h16_wLight.txt")),
+        'Õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            51,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ö' / 0xd6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ö' => Some(include!("../res_rasterized_characters/0xd6_h16_wLight.txt")),
+        'Ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            52,
+            RASTER_HEIGHT,
+        )),
         // letter: '×' / 0xd7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '×' => Some(include!("../res_rasterized_characters/0xd7_h16_wLight.txt")),
+        '×' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            53,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ø' / 0xd8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ø' => Some(include!("../res_rasterized_characters/0xd8_h16_wLight.txt")),
+        'Ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            54,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ù' / 0xd9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ù' => Some(include!("../res_rasterized_characters/0xd9_h16_wLight.txt")),
+        'Ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            55,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ú' / 0xda
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ú' => Some(include!("../res_rasterized_characters/0xda_h16_wLight.txt")),
+        'Ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            56,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Û' / 0xdb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Û' => Some(include!("../res_rasterized_characters/0xdb_h16_wLight.txt")),
+        'Û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            57,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ü' / 0xdc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ü' => Some(include!("../res_rasterized_characters/0xdc_h16_wLight.txt")),
+        'Ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            58,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Ý' / 0xdd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Ý' => Some(include!("../res_rasterized_characters/0xdd_h16_wLight.txt")),
+        'Ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            59,
+            RASTER_HEIGHT,
+        )),
         // letter: 'Þ' / 0xde
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'Þ' => Some(include!("../res_rasterized_characters/0xde_h16_wLight.txt")),
+        'Þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            60,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ß' / 0xdf
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ß' => Some(include!("../res_rasterized_characters/0xdf_h16_wLight.txt")),
+        'ß' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            61,
+            RASTER_HEIGHT,
+        )),
         // letter: 'à' / 0xe0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'à' => Some(include!("../res_rasterized_characters/0xe0_h16_wLight.txt")),
+        'à' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            62,
+            RASTER_HEIGHT,
+        )),
         // letter: 'á' / 0xe1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'á' => Some(include!("../res_rasterized_characters/0xe1_h16_wLight.txt")),
+        'á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            63,
+            RASTER_HEIGHT,
+        )),
         // letter: 'â' / 0xe2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'â' => Some(include!("../res_rasterized_characters/0xe2_h16_wLight.txt")),
+        'â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            64,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ã' / 0xe3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ã' => Some(include!("../res_rasterized_characters/0xe3_h16_wLight.txt")),
+        'ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            65,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ä' / 0xe4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ä' => Some(include!("../res_rasterized_characters/0xe4_h16_wLight.txt")),
+        'ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            66,
+            RASTER_HEIGHT,
+        )),
         // letter: 'å' / 0xe5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'å' => Some(include!("../res_rasterized_characters/0xe5_h16_wLight.txt")),
+        'å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            67,
+            RASTER_HEIGHT,
+        )),
         // letter: 'æ' / 0xe6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'æ' => Some(include!("../res_rasterized_characters/0xe6_h16_wLight.txt")),
+        'æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            68,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ç' / 0xe7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ç' => Some(include!("../res_rasterized_characters/0xe7_h16_wLight.txt")),
+        'ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            69,
+            RASTER_HEIGHT,
+        )),
         // letter: 'è' / 0xe8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'è' => Some(include!("../res_rasterized_characters/0xe8_h16_wLight.txt")),
+        'è' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            70,
+            RASTER_HEIGHT,
+        )),
         // letter: 'é' / 0xe9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'é' => Some(include!("../res_rasterized_characters/0xe9_h16_wLight.txt")),
+        'é' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            71,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ê' / 0xea
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ê' => Some(include!("../res_rasterized_characters/0xea_h16_wLight.txt")),
+        'ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            72,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ë' / 0xeb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ë' => Some(include!("../res_rasterized_characters/0xeb_h16_wLight.txt")),
+        'ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            73,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ì' / 0xec
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ì' => Some(include!("../res_rasterized_characters/0xec_h16_wLight.txt")),
+        'ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            74,
+            RASTER_HEIGHT,
+        )),
         // letter: 'í' / 0xed
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'í' => Some(include!("../res_rasterized_characters/0xed_h16_wLight.txt")),
+        'í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            75,
+            RASTER_HEIGHT,
+        )),
         // letter: 'î' / 0xee
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'î' => Some(include!("../res_rasterized_characters/0xee_h16_wLight.txt")),
+        'î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            76,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ï' / 0xef
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ï' => Some(include!("../res_rasterized_characters/0xef_h16_wLight.txt")),
+        'ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            77,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ð' / 0xf0
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ð' => Some(include!("../res_rasterized_characters/0xf0_h16_wLight.txt")),
+        'ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            78,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ñ' / 0xf1
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ñ' => Some(include!("../res_rasterized_characters/0xf1_h16_wLight.txt")),
+        'ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            79,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ò' / 0xf2
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ò' => Some(include!("../res_rasterized_characters/0xf2_h16_wLight.txt")),
+        'ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            80,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ó' / 0xf3
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ó' => Some(include!("../res_rasterized_characters/0xf3_h16_wLight.txt")),
+        'ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            81,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ô' / 0xf4
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ô' => Some(include!("../res_rasterized_characters/0xf4_h16_wLight.txt")),
+        'ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            82,
+            RASTER_HEIGHT,
+        )),
         // letter: 'õ' / 0xf5
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'õ' => Some(include!("../res_rasterized_characters/0xf5_h16_wLight.txt")),
+        'õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            83,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ö' / 0xf6
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ö' => Some(include!("../res_rasterized_characters/0xf6_h16_wLight.txt")),
+        'ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            84,
+            RASTER_HEIGHT,
+        )),
         // letter: '÷' / 0xf7
         #[cfg(feature = "unicode-latin-1-supplement")]
-        '÷' => Some(include!("../res_rasterized_characters/0xf7_h16_wLight.txt")),
+        '÷' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            85,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ø' / 0xf8
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ø' => Some(include!("../res_rasterized_characters/0xf8_h16_wLight.txt")),
+        'ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            86,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ù' / 0xf9
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ù' => Some(include!("../res_rasterized_characters/0xf9_h16_wLight.txt")),
+        'ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            87,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ú' / 0xfa
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ú' => Some(include!("../res_rasterized_characters/0xfa_h16_wLight.txt")),
+        'ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            88,
+            RASTER_HEIGHT,
+        )),
         // letter: 'û' / 0xfb
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'û' => Some(include!("../res_rasterized_characters/0xfb_h16_wLight.txt")),
+        'û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            89,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ü' / 0xfc
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ü' => Some(include!("../res_rasterized_characters/0xfc_h16_wLight.txt")),
+        'ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            90,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ý' / 0xfd
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ý' => Some(include!("../res_rasterized_characters/0xfd_h16_wLight.txt")),
+        'ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            91,
+            RASTER_HEIGHT,
+        )),
         // letter: 'þ' / 0xfe
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'þ' => Some(include!("../res_rasterized_characters/0xfe_h16_wLight.txt")),
+        'þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            92,
+            RASTER_HEIGHT,
+        )),
         // letter: 'ÿ' / 0xff
         #[cfg(feature = "unicode-latin-1-supplement")]
-        'ÿ' => Some(include!("../res_rasterized_characters/0xff_h16_wLight.txt")),
+        'ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ā' / 0x100
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ā' / 0x101
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ă' / 0x102
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ă' / 0x103
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ą' / 0x104
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ą' / 0x105
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ć' / 0x106
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ć' / 0x107
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĉ' / 0x108
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĉ' / 0x109
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ċ' / 0x10a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ċ' / 0x10b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Č' / 0x10c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'č' / 0x10d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ď' / 0x10e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ď' / 0x10f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Đ' / 0x110
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'đ' / 0x111
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ē' / 0x112
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ē' / 0x113
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĕ' / 0x114
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĕ' / 0x115
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ė' / 0x116
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ė' / 0x117
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ę' / 0x118
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ę' / 0x119
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ě' / 0x11a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ě' / 0x11b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĝ' / 0x11c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĝ' / 0x11d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ğ' / 0x11e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ğ' / 0x11f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ġ' / 0x120
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ġ' / 0x121
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ģ' / 0x122
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ģ' / 0x123
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĥ' / 0x124
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĥ' / 0x125
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ħ' / 0x126
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ħ' / 0x127
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĩ' / 0x128
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĩ' / 0x129
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ī' / 0x12a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ī' / 0x12b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĭ' / 0x12c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĭ' / 0x12d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Į' / 0x12e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'į' / 0x12f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'İ' / 0x130
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'İ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ı' / 0x131
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ı' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĳ' / 0x132
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĳ' / 0x133
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĵ' / 0x134
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĵ' / 0x135
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ķ' / 0x136
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ķ' / 0x137
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĸ' / 0x138
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĸ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĺ' / 0x139
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĺ' / 0x13a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ļ' / 0x13b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ļ' / 0x13c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ľ' / 0x13d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ľ' / 0x13e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŀ' / 0x13f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŀ' / 0x140
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ł' / 0x141
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ł' / 0x142
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ń' / 0x143
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ń' / 0x144
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ņ' / 0x145
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ņ' / 0x146
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ň' / 0x147
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ň' / 0x148
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŉ' / 0x149
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŋ' / 0x14a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŋ' / 0x14b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ō' / 0x14c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ō' / 0x14d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŏ' / 0x14e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŏ' / 0x14f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ő' / 0x150
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ő' / 0x151
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Œ' / 0x152
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'œ' / 0x153
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŕ' / 0x154
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŕ' / 0x155
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŗ' / 0x156
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŗ' / 0x157
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ř' / 0x158
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ř' / 0x159
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ś' / 0x15a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ś' / 0x15b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŝ' / 0x15c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŝ' / 0x15d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ş' / 0x15e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ş' / 0x15f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            95,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Š' / 0x160
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            96,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'š' / 0x161
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            97,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ţ' / 0x162
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            98,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ţ' / 0x163
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            99,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ť' / 0x164
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            100,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ť' / 0x165
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            101,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŧ' / 0x166
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            102,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŧ' / 0x167
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            103,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ũ' / 0x168
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            104,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ũ' / 0x169
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            105,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ū' / 0x16a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            106,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ū' / 0x16b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            107,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŭ' / 0x16c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            108,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŭ' / 0x16d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            109,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ů' / 0x16e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            110,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ů' / 0x16f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            111,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ű' / 0x170
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            112,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ű' / 0x171
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            113,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ų' / 0x172
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            114,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ų' / 0x173
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            115,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŵ' / 0x174
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            116,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŵ' / 0x175
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            117,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŷ' / 0x176
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            118,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŷ' / 0x177
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            119,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ÿ' / 0x178
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            120,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ź' / 0x179
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            121,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ź' / 0x17a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            122,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ż' / 0x17b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            123,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ż' / 0x17c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            124,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ž' / 0x17d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            125,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ž' / 0x17e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            126,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ſ' / 0x17f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ſ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A,
+            127,
+            RASTER_HEIGHT,
+        )),
+        // letter: '�' / 0xfffd
+        #[cfg(feature = "unicode-specials")]
+        '�' => Some(crate::glyph_data::raster_at(
+            &UNICODE_SPECIALS,
+            0,
+            RASTER_HEIGHT,
+        )),
+        _ => None,
+    }
+}
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-basic-latin` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-basic-latin"))]
+static UNICODE_BASIC_LATIN_SUBPIXEL: [&[u8]; 95 * RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-basic-latin_subpixel.bin"),
+    include_bytes!(
+        "../res_rasterized_characters/h16_wLight_unicode-basic-latin_subpixel_index.bin"
+    ),
+    3 * RASTER_WIDTH,
+);
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-latin-1-supplement"))]
+static UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL: [&[u8]; 94 * RASTER_HEIGHT] =
+    crate::glyph_data::intern_rows(
+        include_bytes!(
+            "../res_rasterized_characters/h16_wLight_unicode-latin-1-supplement_subpixel.bin"
+        ),
+        include_bytes!(
+            "../res_rasterized_characters/h16_wLight_unicode-latin-1-supplement_subpixel_index.bin"
+        ),
+        3 * RASTER_WIDTH,
+    );
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-latin-extended-a` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-latin-extended-a"))]
+static UNICODE_LATIN_EXTENDED_A_SUBPIXEL: [&[u8]; 128 * RASTER_HEIGHT] =
+    crate::glyph_data::intern_rows(
+        include_bytes!(
+            "../res_rasterized_characters/h16_wLight_unicode-latin-extended-a_subpixel.bin"
+        ),
+        include_bytes!(
+            "../res_rasterized_characters/h16_wLight_unicode-latin-extended-a_subpixel_index.bin"
+        ),
+        3 * RASTER_WIDTH,
+    );
+
+/// The rows of the distinct rasters of all characters of the unicode range
+/// `unicode-specials` in subpixel resolution.
+#[cfg(all(feature = "subpixel", feature = "unicode-specials"))]
+static UNICODE_SPECIALS_SUBPIXEL: [&[u8]; RASTER_HEIGHT] = crate::glyph_data::intern_rows(
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-specials_subpixel.bin"),
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-specials_subpixel_index.bin"),
+    3 * RASTER_WIDTH,
+);
+
+/// Returns the subpixel raster of the given character for font weight light and font size
+/// 11px. Each row contains three bytes per pixel with the unfiltered coverage of the
+/// subpixels from left to right.
+#[cfg(feature = "subpixel")]
+#[inline]
+pub const fn get_char_subpixel(c: char) -> Option<&'static [&'static [u8]]> {
+    match c {
+        // letter: ' ' / 0x20
+        #[cfg(feature = "unicode-basic-latin")]
+        ' ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: '!' / 0x21
+        #[cfg(feature = "unicode-basic-latin")]
+        '!' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: '"' / 0x22
+        #[cfg(feature = "unicode-basic-latin")]
+        '"' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: '#' / 0x23
+        #[cfg(feature = "unicode-basic-latin")]
+        '#' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: '$' / 0x24
+        #[cfg(feature = "unicode-basic-latin")]
+        '$' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: '%' / 0x25
+        #[cfg(feature = "unicode-basic-latin")]
+        '%' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: '&' / 0x26
+        #[cfg(feature = "unicode-basic-latin")]
+        '&' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: ''' / 0x27
+        #[cfg(feature = "unicode-basic-latin")]
+        '\'' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: '(' / 0x28
+        #[cfg(feature = "unicode-basic-latin")]
+        '(' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: ')' / 0x29
+        #[cfg(feature = "unicode-basic-latin")]
+        ')' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: '*' / 0x2a
+        #[cfg(feature = "unicode-basic-latin")]
+        '*' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: '+' / 0x2b
+        #[cfg(feature = "unicode-basic-latin")]
+        '+' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: ',' / 0x2c
+        #[cfg(feature = "unicode-basic-latin")]
+        ',' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: '-' / 0x2d
+        #[cfg(feature = "unicode-basic-latin")]
+        '-' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: '.' / 0x2e
+        #[cfg(feature = "unicode-basic-latin")]
+        '.' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: '/' / 0x2f
+        #[cfg(feature = "unicode-basic-latin")]
+        '/' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: '0' / 0x30
+        #[cfg(feature = "unicode-basic-latin")]
+        '0' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: '1' / 0x31
+        #[cfg(feature = "unicode-basic-latin")]
+        '1' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: '2' / 0x32
+        #[cfg(feature = "unicode-basic-latin")]
+        '2' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: '3' / 0x33
+        #[cfg(feature = "unicode-basic-latin")]
+        '3' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: '4' / 0x34
+        #[cfg(feature = "unicode-basic-latin")]
+        '4' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: '5' / 0x35
+        #[cfg(feature = "unicode-basic-latin")]
+        '5' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: '6' / 0x36
+        #[cfg(feature = "unicode-basic-latin")]
+        '6' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: '7' / 0x37
+        #[cfg(feature = "unicode-basic-latin")]
+        '7' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: '8' / 0x38
+        #[cfg(feature = "unicode-basic-latin")]
+        '8' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: '9' / 0x39
+        #[cfg(feature = "unicode-basic-latin")]
+        '9' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: ':' / 0x3a
+        #[cfg(feature = "unicode-basic-latin")]
+        ':' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: ';' / 0x3b
+        #[cfg(feature = "unicode-basic-latin")]
+        ';' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: '<' / 0x3c
+        #[cfg(feature = "unicode-basic-latin")]
+        '<' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: '=' / 0x3d
+        #[cfg(feature = "unicode-basic-latin")]
+        '=' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: '>' / 0x3e
+        #[cfg(feature = "unicode-basic-latin")]
+        '>' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: '?' / 0x3f
+        #[cfg(feature = "unicode-basic-latin")]
+        '?' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: '@' / 0x40
+        #[cfg(feature = "unicode-basic-latin")]
+        '@' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'A' / 0x41
+        #[cfg(feature = "unicode-basic-latin")]
+        'A' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'B' / 0x42
+        #[cfg(feature = "unicode-basic-latin")]
+        'B' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'C' / 0x43
+        #[cfg(feature = "unicode-basic-latin")]
+        'C' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'D' / 0x44
+        #[cfg(feature = "unicode-basic-latin")]
+        'D' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'E' / 0x45
+        #[cfg(feature = "unicode-basic-latin")]
+        'E' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'F' / 0x46
+        #[cfg(feature = "unicode-basic-latin")]
+        'F' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'G' / 0x47
+        #[cfg(feature = "unicode-basic-latin")]
+        'G' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'H' / 0x48
+        #[cfg(feature = "unicode-basic-latin")]
+        'H' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'I' / 0x49
+        #[cfg(feature = "unicode-basic-latin")]
+        'I' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'J' / 0x4a
+        #[cfg(feature = "unicode-basic-latin")]
+        'J' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'K' / 0x4b
+        #[cfg(feature = "unicode-basic-latin")]
+        'K' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'L' / 0x4c
+        #[cfg(feature = "unicode-basic-latin")]
+        'L' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'M' / 0x4d
+        #[cfg(feature = "unicode-basic-latin")]
+        'M' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'N' / 0x4e
+        #[cfg(feature = "unicode-basic-latin")]
+        'N' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'O' / 0x4f
+        #[cfg(feature = "unicode-basic-latin")]
+        'O' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'P' / 0x50
+        #[cfg(feature = "unicode-basic-latin")]
+        'P' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Q' / 0x51
+        #[cfg(feature = "unicode-basic-latin")]
+        'Q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'R' / 0x52
+        #[cfg(feature = "unicode-basic-latin")]
+        'R' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'S' / 0x53
+        #[cfg(feature = "unicode-basic-latin")]
+        'S' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'T' / 0x54
+        #[cfg(feature = "unicode-basic-latin")]
+        'T' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'U' / 0x55
+        #[cfg(feature = "unicode-basic-latin")]
+        'U' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'V' / 0x56
+        #[cfg(feature = "unicode-basic-latin")]
+        'V' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'W' / 0x57
+        #[cfg(feature = "unicode-basic-latin")]
+        'W' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'X' / 0x58
+        #[cfg(feature = "unicode-basic-latin")]
+        'X' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Y' / 0x59
+        #[cfg(feature = "unicode-basic-latin")]
+        'Y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Z' / 0x5a
+        #[cfg(feature = "unicode-basic-latin")]
+        'Z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: '[' / 0x5b
+        #[cfg(feature = "unicode-basic-latin")]
+        '[' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: '\' / 0x5c
+        #[cfg(feature = "unicode-basic-latin")]
+        '\\' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: ']' / 0x5d
+        #[cfg(feature = "unicode-basic-latin")]
+        ']' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: '^' / 0x5e
+        #[cfg(feature = "unicode-basic-latin")]
+        '^' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: '_' / 0x5f
+        #[cfg(feature = "unicode-basic-latin")]
+        '_' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: '`' / 0x60
+        #[cfg(feature = "unicode-basic-latin")]
+        '`' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'a' / 0x61
+        #[cfg(feature = "unicode-basic-latin")]
+        'a' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'b' / 0x62
+        #[cfg(feature = "unicode-basic-latin")]
+        'b' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'c' / 0x63
+        #[cfg(feature = "unicode-basic-latin")]
+        'c' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'd' / 0x64
+        #[cfg(feature = "unicode-basic-latin")]
+        'd' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'e' / 0x65
+        #[cfg(feature = "unicode-basic-latin")]
+        'e' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'f' / 0x66
+        #[cfg(feature = "unicode-basic-latin")]
+        'f' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'g' / 0x67
+        #[cfg(feature = "unicode-basic-latin")]
+        'g' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'h' / 0x68
+        #[cfg(feature = "unicode-basic-latin")]
+        'h' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'i' / 0x69
+        #[cfg(feature = "unicode-basic-latin")]
+        'i' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'j' / 0x6a
+        #[cfg(feature = "unicode-basic-latin")]
+        'j' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'k' / 0x6b
+        #[cfg(feature = "unicode-basic-latin")]
+        'k' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'l' / 0x6c
+        #[cfg(feature = "unicode-basic-latin")]
+        'l' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'm' / 0x6d
+        #[cfg(feature = "unicode-basic-latin")]
+        'm' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'n' / 0x6e
+        #[cfg(feature = "unicode-basic-latin")]
+        'n' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'o' / 0x6f
+        #[cfg(feature = "unicode-basic-latin")]
+        'o' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'p' / 0x70
+        #[cfg(feature = "unicode-basic-latin")]
+        'p' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'q' / 0x71
+        #[cfg(feature = "unicode-basic-latin")]
+        'q' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'r' / 0x72
+        #[cfg(feature = "unicode-basic-latin")]
+        'r' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 's' / 0x73
+        #[cfg(feature = "unicode-basic-latin")]
+        's' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 't' / 0x74
+        #[cfg(feature = "unicode-basic-latin")]
+        't' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'u' / 0x75
+        #[cfg(feature = "unicode-basic-latin")]
+        'u' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'v' / 0x76
+        #[cfg(feature = "unicode-basic-latin")]
+        'v' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'w' / 0x77
+        #[cfg(feature = "unicode-basic-latin")]
+        'w' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'x' / 0x78
+        #[cfg(feature = "unicode-basic-latin")]
+        'x' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'y' / 0x79
+        #[cfg(feature = "unicode-basic-latin")]
+        'y' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'z' / 0x7a
+        #[cfg(feature = "unicode-basic-latin")]
+        'z' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: '{' / 0x7b
+        #[cfg(feature = "unicode-basic-latin")]
+        '{' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: '|' / 0x7c
+        #[cfg(feature = "unicode-basic-latin")]
+        '|' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: '}' / 0x7d
+        #[cfg(feature = "unicode-basic-latin")]
+        '}' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: '~' / 0x7e
+        #[cfg(feature = "unicode-basic-latin")]
+        '~' => Some(crate::glyph_data::raster_at(
+            &UNICODE_BASIC_LATIN_SUBPIXEL,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¡' / 0xa1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¡' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¢' / 0xa2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¢' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: '£' / 0xa3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '£' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¤' / 0xa4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¤' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¥' / 0xa5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¥' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¦' / 0xa6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¦' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: '§' / 0xa7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '§' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¨' / 0xa8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¨' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: '©' / 0xa9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '©' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ª' / 0xaa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ª' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: '«' / 0xab
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '«' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¬' / 0xac
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¬' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: '®' / 0xae
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '®' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¯' / 0xaf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¯' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: '°' / 0xb0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '°' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: '±' / 0xb1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '±' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: '²' / 0xb2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '²' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: '³' / 0xb3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '³' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: '´' / 0xb4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '´' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'µ' / 0xb5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'µ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¶' / 0xb6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¶' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: '·' / 0xb7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '·' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¸' / 0xb8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¸' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¹' / 0xb9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¹' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'º' / 0xba
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'º' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: '»' / 0xbb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '»' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¼' / 0xbc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¼' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: '½' / 0xbd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '½' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¾' / 0xbe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¾' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: '¿' / 0xbf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¿' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'À' / 0xc0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'À' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Á' / 0xc1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Â' / 0xc2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ã' / 0xc3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ä' / 0xc4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Å' / 0xc5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Æ' / 0xc6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ç' / 0xc7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'È' / 0xc8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'È' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'É' / 0xc9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'É' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ê' / 0xca
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ë' / 0xcb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ì' / 0xcc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Í' / 0xcd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Î' / 0xce
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ï' / 0xcf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ð' / 0xd0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ñ' / 0xd1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ò' / 0xd2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ó' / 0xd3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ô' / 0xd4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Õ' / 0xd5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ö' / 0xd6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: '×' / 0xd7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '×' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ø' / 0xd8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ù' / 0xd9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ú' / 0xda
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Û' / 0xdb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ü' / 0xdc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ý' / 0xdd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Þ' / 0xde
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ß' / 0xdf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ß' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'à' / 0xe0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'à' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'á' / 0xe1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'á' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'â' / 0xe2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'â' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ã' / 0xe3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ã' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ä' / 0xe4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ä' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'å' / 0xe5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'å' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'æ' / 0xe6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'æ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ç' / 0xe7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ç' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'è' / 0xe8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'è' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'é' / 0xe9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'é' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ê' / 0xea
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ê' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ë' / 0xeb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ë' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ì' / 0xec
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ì' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'í' / 0xed
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'í' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'î' / 0xee
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'î' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ï' / 0xef
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ï' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ð' / 0xf0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ð' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ñ' / 0xf1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ñ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ò' / 0xf2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ò' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ó' / 0xf3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ó' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ô' / 0xf4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ô' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'õ' / 0xf5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'õ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ö' / 0xf6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ö' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: '÷' / 0xf7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '÷' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ø' / 0xf8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ø' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ù' / 0xf9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ù' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ú' / 0xfa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ú' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'û' / 0xfb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'û' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ü' / 0xfc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ü' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ý' / 0xfd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ý' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'þ' / 0xfe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'þ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ÿ' / 0xff
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_1_SUPPLEMENT_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ā' / 0x100
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ā' / 0x101
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ā' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            1,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ă' / 0x102
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            2,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ă' / 0x103
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ă' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            3,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ą' / 0x104
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            4,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ą' / 0x105
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ą' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            5,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ć' / 0x106
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            6,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ć' / 0x107
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ć' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            7,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĉ' / 0x108
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            8,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĉ' / 0x109
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            9,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ċ' / 0x10a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            10,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ċ' / 0x10b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ċ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            11,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Č' / 0x10c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            12,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'č' / 0x10d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'č' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            13,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ď' / 0x10e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            14,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ď' / 0x10f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ď' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            15,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Đ' / 0x110
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            16,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'đ' / 0x111
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'đ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            17,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ē' / 0x112
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            18,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ē' / 0x113
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ē' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            19,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĕ' / 0x114
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            20,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĕ' / 0x115
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            21,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ė' / 0x116
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            22,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ė' / 0x117
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ė' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            23,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ę' / 0x118
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            24,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ę' / 0x119
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ę' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            25,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ě' / 0x11a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            26,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ě' / 0x11b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ě' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            27,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĝ' / 0x11c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            28,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĝ' / 0x11d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            29,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ğ' / 0x11e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            30,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ğ' / 0x11f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ğ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            31,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ġ' / 0x120
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            32,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ġ' / 0x121
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ġ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            33,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ģ' / 0x122
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            34,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ģ' / 0x123
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ģ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            35,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĥ' / 0x124
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            36,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĥ' / 0x125
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĥ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            37,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ħ' / 0x126
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            38,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ħ' / 0x127
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ħ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            39,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĩ' / 0x128
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            40,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĩ' / 0x129
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĩ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            41,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ī' / 0x12a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            42,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ī' / 0x12b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ī' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            43,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĭ' / 0x12c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            44,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĭ' / 0x12d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            45,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Į' / 0x12e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            46,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'į' / 0x12f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'į' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            47,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'İ' / 0x130
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'İ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            48,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ı' / 0x131
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ı' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            49,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĳ' / 0x132
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            50,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĳ' / 0x133
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĳ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            51,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĵ' / 0x134
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            52,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĵ' / 0x135
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            53,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ķ' / 0x136
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            54,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ķ' / 0x137
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ķ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            55,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĸ' / 0x138
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĸ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            56,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ĺ' / 0x139
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            57,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ĺ' / 0x13a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ĺ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            58,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ļ' / 0x13b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            59,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ļ' / 0x13c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ļ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            60,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ľ' / 0x13d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            61,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ľ' / 0x13e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ľ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            62,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŀ' / 0x13f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            63,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŀ' / 0x140
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŀ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            64,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ł' / 0x141
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            65,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ł' / 0x142
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ł' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            66,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ń' / 0x143
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            67,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ń' / 0x144
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ń' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            68,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ņ' / 0x145
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            69,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ņ' / 0x146
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ņ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            70,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ň' / 0x147
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            71,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ň' / 0x148
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ň' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            72,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŉ' / 0x149
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŉ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            73,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŋ' / 0x14a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            74,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŋ' / 0x14b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŋ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            75,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ō' / 0x14c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            76,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ō' / 0x14d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ō' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            77,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŏ' / 0x14e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            78,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŏ' / 0x14f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŏ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            79,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ő' / 0x150
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            80,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ő' / 0x151
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ő' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            81,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Œ' / 0x152
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            82,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'œ' / 0x153
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'œ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            83,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŕ' / 0x154
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            84,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŕ' / 0x155
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŕ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            85,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŗ' / 0x156
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            86,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŗ' / 0x157
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŗ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            87,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ř' / 0x158
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            88,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ř' / 0x159
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ř' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            89,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ś' / 0x15a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            90,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ś' / 0x15b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ś' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            91,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŝ' / 0x15c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            92,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŝ' / 0x15d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŝ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            93,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ş' / 0x15e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            94,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ş' / 0x15f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ş' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            95,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Š' / 0x160
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            96,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'š' / 0x161
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'š' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            97,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ţ' / 0x162
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            98,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ţ' / 0x163
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ţ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            99,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ť' / 0x164
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            100,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ť' / 0x165
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ť' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            101,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŧ' / 0x166
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            102,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŧ' / 0x167
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŧ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            103,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ũ' / 0x168
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            104,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ũ' / 0x169
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ũ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            105,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ū' / 0x16a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            106,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ū' / 0x16b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ū' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            107,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŭ' / 0x16c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            108,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŭ' / 0x16d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŭ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            109,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ů' / 0x16e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            110,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ů' / 0x16f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ů' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            111,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ű' / 0x170
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            112,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ű' / 0x171
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ű' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            113,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ų' / 0x172
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            114,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ų' / 0x173
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ų' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            115,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŵ' / 0x174
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            116,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŵ' / 0x175
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŵ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            117,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ŷ' / 0x176
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            118,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ŷ' / 0x177
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ŷ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            119,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ÿ' / 0x178
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ÿ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            120,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ź' / 0x179
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            121,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ź' / 0x17a
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ź' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            122,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ż' / 0x17b
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            123,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ż' / 0x17c
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ż' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            124,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'Ž' / 0x17d
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'Ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            125,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ž' / 0x17e
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ž' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            126,
+            RASTER_HEIGHT,
+        )),
+        // letter: 'ſ' / 0x17f
+        #[cfg(feature = "unicode-latin-extended-a")]
+        'ſ' => Some(crate::glyph_data::raster_at(
+            &UNICODE_LATIN_EXTENDED_A_SUBPIXEL,
+            127,
+            RASTER_HEIGHT,
+        )),
+        // letter: '�' / 0xfffd
+        #[cfg(feature = "unicode-specials")]
+        '�' => Some(crate::glyph_data::raster_at(
+            &UNICODE_SPECIALS_SUBPIXEL,
+            0,
+            RASTER_HEIGHT,
+        )),
+        _ => None,
+    }
+}
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-basic-latin`.
+#[cfg(all(feature = "compressed", feature = "unicode-basic-latin"))]
+static UNICODE_BASIC_LATIN_COMPRESSED: &[u8] =
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-basic-latin_compressed.bin");
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-latin-1-supplement`.
+#[cfg(all(feature = "compressed", feature = "unicode-latin-1-supplement"))]
+static UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED: &[u8] = include_bytes!(
+    "../res_rasterized_characters/h16_wLight_unicode-latin-1-supplement_compressed.bin"
+);
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-latin-extended-a`.
+#[cfg(all(feature = "compressed", feature = "unicode-latin-extended-a"))]
+static UNICODE_LATIN_EXTENDED_A_COMPRESSED: &[u8] = include_bytes!(
+    "../res_rasterized_characters/h16_wLight_unicode-latin-extended-a_compressed.bin"
+);
+
+/// The run-length encoded rasters of all characters of the unicode range
+/// `unicode-specials`.
+#[cfg(all(feature = "compressed", feature = "unicode-specials"))]
+static UNICODE_SPECIALS_COMPRESSED: &[u8] =
+    include_bytes!("../res_rasterized_characters/h16_wLight_unicode-specials_compressed.bin");
+
+/// Returns the run-length encoded raster of the given character for font weight light
+/// and font size 11px. See [`crate::CompressedChar`] for the encoding.
+#[cfg(feature = "compressed")]
+#[inline]
+pub const fn get_char_compressed(c: char) -> Option<&'static [u8]> {
+    match c {
+        // letter: ' ' / 0x20
+        #[cfg(feature = "unicode-basic-latin")]
+        ' ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            0,
+            2,
+        )),
+        // letter: '!' / 0x21
+        #[cfg(feature = "unicode-basic-latin")]
+        '!' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2,
+            41,
+        )),
+        // letter: '"' / 0x22
+        #[cfg(feature = "unicode-basic-latin")]
+        '"' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            41,
+            61,
+        )),
+        // letter: '#' / 0x23
+        #[cfg(feature = "unicode-basic-latin")]
+        '#' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            61,
+            117,
+        )),
+        // letter: '$' / 0x24
+        #[cfg(feature = "unicode-basic-latin")]
+        '$' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            117,
+            180,
+        )),
+        // letter: '%' / 0x25
+        #[cfg(feature = "unicode-basic-latin")]
+        '%' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            180,
+            245,
+        )),
+        // letter: '&' / 0x26
+        #[cfg(feature = "unicode-basic-latin")]
+        '&' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            245,
+            309,
+        )),
+        // letter: ''' / 0x27
+        #[cfg(feature = "unicode-basic-latin")]
+        '\'' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            309,
+            324,
+        )),
+        // letter: '(' / 0x28
+        #[cfg(feature = "unicode-basic-latin")]
+        '(' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            324,
+            376,
+        )),
+        // letter: ')' / 0x29
+        #[cfg(feature = "unicode-basic-latin")]
+        ')' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            376,
+            426,
+        )),
+        // letter: '*' / 0x2a
+        #[cfg(feature = "unicode-basic-latin")]
+        '*' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            426,
+            463,
+        )),
+        // letter: '+' / 0x2b
+        #[cfg(feature = "unicode-basic-latin")]
+        '+' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            463,
+            497,
+        )),
+        // letter: ',' / 0x2c
+        #[cfg(feature = "unicode-basic-latin")]
+        ',' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            497,
+            518,
+        )),
+        // letter: '-' / 0x2d
+        #[cfg(feature = "unicode-basic-latin")]
+        '-' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            518,
+            534,
+        )),
+        // letter: '.' / 0x2e
+        #[cfg(feature = "unicode-basic-latin")]
+        '.' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            534,
+            545,
+        )),
+        // letter: '/' / 0x2f
+        #[cfg(feature = "unicode-basic-latin")]
+        '/' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            545,
+            586,
+        )),
+        // letter: '0' / 0x30
+        #[cfg(feature = "unicode-basic-latin")]
+        '0' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            586,
+            650,
+        )),
+        // letter: '1' / 0x31
+        #[cfg(feature = "unicode-basic-latin")]
+        '1' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            650,
+            697,
+        )),
+        // letter: '2' / 0x32
+        #[cfg(feature = "unicode-basic-latin")]
+        '2' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            697,
+            750,
+        )),
+        // letter: '3' / 0x33
+        #[cfg(feature = "unicode-basic-latin")]
+        '3' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            750,
+            808,
+        )),
+        // letter: '4' / 0x34
+        #[cfg(feature = "unicode-basic-latin")]
+        '4' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            808,
+            860,
+        )),
+        // letter: '5' / 0x35
+        #[cfg(feature = "unicode-basic-latin")]
+        '5' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            860,
+            913,
+        )),
+        // letter: '6' / 0x36
+        #[cfg(feature = "unicode-basic-latin")]
+        '6' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            913,
+            974,
+        )),
+        // letter: '7' / 0x37
+        #[cfg(feature = "unicode-basic-latin")]
+        '7' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            974,
+            1018,
+        )),
+        // letter: '8' / 0x38
+        #[cfg(feature = "unicode-basic-latin")]
+        '8' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1018,
+            1082,
+        )),
+        // letter: '9' / 0x39
+        #[cfg(feature = "unicode-basic-latin")]
+        '9' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1082,
+            1143,
+        )),
+        // letter: ':' / 0x3a
+        #[cfg(feature = "unicode-basic-latin")]
+        ':' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1143,
+            1163,
+        )),
+        // letter: ';' / 0x3b
+        #[cfg(feature = "unicode-basic-latin")]
+        ';' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1163,
+            1192,
+        )),
+        // letter: '<' / 0x3c
+        #[cfg(feature = "unicode-basic-latin")]
+        '<' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1192,
+            1230,
+        )),
+        // letter: '=' / 0x3d
+        #[cfg(feature = "unicode-basic-latin")]
+        '=' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1230,
+            1258,
+        )),
+        // letter: '>' / 0x3e
+        #[cfg(feature = "unicode-basic-latin")]
+        '>' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1258,
+            1295,
+        )),
+        // letter: '?' / 0x3f
+        #[cfg(feature = "unicode-basic-latin")]
+        '?' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1295,
+            1342,
+        )),
+        // letter: '@' / 0x40
+        #[cfg(feature = "unicode-basic-latin")]
+        '@' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1342,
+            1405,
+        )),
+        // letter: 'A' / 0x41
+        #[cfg(feature = "unicode-basic-latin")]
+        'A' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1405,
+            1461,
+        )),
+        // letter: 'B' / 0x42
+        #[cfg(feature = "unicode-basic-latin")]
+        'B' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1461,
+            1520,
+        )),
+        // letter: 'C' / 0x43
+        #[cfg(feature = "unicode-basic-latin")]
+        'C' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1520,
+            1572,
+        )),
+        // letter: 'D' / 0x44
+        #[cfg(feature = "unicode-basic-latin")]
+        'D' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1572,
+            1632,
+        )),
+        // letter: 'E' / 0x45
+        #[cfg(feature = "unicode-basic-latin")]
+        'E' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1632,
+            1679,
+        )),
+        // letter: 'F' / 0x46
+        #[cfg(feature = "unicode-basic-latin")]
+        'F' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1679,
+            1722,
+        )),
+        // letter: 'G' / 0x47
+        #[cfg(feature = "unicode-basic-latin")]
+        'G' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1722,
+            1783,
+        )),
+        // letter: 'H' / 0x48
+        #[cfg(feature = "unicode-basic-latin")]
+        'H' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1783,
+            1843,
+        )),
+        // letter: 'I' / 0x49
+        #[cfg(feature = "unicode-basic-latin")]
+        'I' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1843,
+            1891,
+        )),
+        // letter: 'J' / 0x4a
+        #[cfg(feature = "unicode-basic-latin")]
+        'J' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1891,
+            1934,
+        )),
+        // letter: 'K' / 0x4b
+        #[cfg(feature = "unicode-basic-latin")]
+        'K' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1934,
+            1994,
+        )),
+        // letter: 'L' / 0x4c
+        #[cfg(feature = "unicode-basic-latin")]
+        'L' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            1994,
+            2039,
+        )),
+        // letter: 'M' / 0x4d
+        #[cfg(feature = "unicode-basic-latin")]
+        'M' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2039,
+            2099,
+        )),
+        // letter: 'N' / 0x4e
+        #[cfg(feature = "unicode-basic-latin")]
+        'N' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2099,
+            2159,
+        )),
+        // letter: 'O' / 0x4f
+        #[cfg(feature = "unicode-basic-latin")]
+        'O' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2159,
+            2223,
+        )),
+        // letter: 'P' / 0x50
+        #[cfg(feature = "unicode-basic-latin")]
+        'P' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2223,
+            2272,
+        )),
+        // letter: 'Q' / 0x51
+        #[cfg(feature = "unicode-basic-latin")]
+        'Q' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2272,
+            2343,
+        )),
+        // letter: 'R' / 0x52
+        #[cfg(feature = "unicode-basic-latin")]
+        'R' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2343,
+            2403,
+        )),
+        // letter: 'S' / 0x53
+        #[cfg(feature = "unicode-basic-latin")]
+        'S' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2403,
+            2458,
+        )),
+        // letter: 'T' / 0x54
+        #[cfg(feature = "unicode-basic-latin")]
+        'T' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2458,
+            2504,
+        )),
+        // letter: 'U' / 0x55
+        #[cfg(feature = "unicode-basic-latin")]
+        'U' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2504,
+            2569,
+        )),
+        // letter: 'V' / 0x56
+        #[cfg(feature = "unicode-basic-latin")]
+        'V' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2569,
+            2624,
+        )),
+        // letter: 'W' / 0x57
+        #[cfg(feature = "unicode-basic-latin")]
+        'W' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2624,
+            2684,
+        )),
+        // letter: 'X' / 0x58
+        #[cfg(feature = "unicode-basic-latin")]
+        'X' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2684,
+            2743,
+        )),
+        // letter: 'Y' / 0x59
+        #[cfg(feature = "unicode-basic-latin")]
+        'Y' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2743,
+            2792,
+        )),
+        // letter: 'Z' / 0x5a
+        #[cfg(feature = "unicode-basic-latin")]
+        'Z' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2792,
+            2840,
+        )),
+        // letter: '[' / 0x5b
+        #[cfg(feature = "unicode-basic-latin")]
+        '[' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2840,
+            2894,
+        )),
+        // letter: '\' / 0x5c
+        #[cfg(feature = "unicode-basic-latin")]
+        '\\' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2894,
+            2935,
+        )),
+        // letter: ']' / 0x5d
+        #[cfg(feature = "unicode-basic-latin")]
+        ']' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2935,
+            2981,
+        )),
+        // letter: '^' / 0x5e
+        #[cfg(feature = "unicode-basic-latin")]
+        '^' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            2981,
+            3016,
+        )),
+        // letter: '_' / 0x5f
+        #[cfg(feature = "unicode-basic-latin")]
+        '_' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3016,
+            3028,
+        )),
+        // letter: '`' / 0x60
+        #[cfg(feature = "unicode-basic-latin")]
+        '`' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3028,
+            3045,
+        )),
+        // letter: 'a' / 0x61
+        #[cfg(feature = "unicode-basic-latin")]
+        'a' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3045,
+            3093,
+        )),
+        // letter: 'b' / 0x62
+        #[cfg(feature = "unicode-basic-latin")]
+        'b' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3093,
+            3155,
+        )),
+        // letter: 'c' / 0x63
+        #[cfg(feature = "unicode-basic-latin")]
+        'c' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3155,
+            3196,
+        )),
+        // letter: 'd' / 0x64
+        #[cfg(feature = "unicode-basic-latin")]
+        'd' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3196,
+            3260,
+        )),
+        // letter: 'e' / 0x65
+        #[cfg(feature = "unicode-basic-latin")]
+        'e' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3260,
+            3308,
+        )),
+        // letter: 'f' / 0x66
+        #[cfg(feature = "unicode-basic-latin")]
+        'f' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3308,
+            3362,
+        )),
+        // letter: 'g' / 0x67
+        #[cfg(feature = "unicode-basic-latin")]
+        'g' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3362,
+            3427,
+        )),
+        // letter: 'h' / 0x68
+        #[cfg(feature = "unicode-basic-latin")]
+        'h' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3427,
+            3484,
+        )),
+        // letter: 'i' / 0x69
+        #[cfg(feature = "unicode-basic-latin")]
+        'i' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3484,
+            3526,
+        )),
+        // letter: 'j' / 0x6a
+        #[cfg(feature = "unicode-basic-latin")]
+        'j' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3526,
+            3588,
+        )),
+        // letter: 'k' / 0x6b
+        #[cfg(feature = "unicode-basic-latin")]
+        'k' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3588,
+            3646,
+        )),
+        // letter: 'l' / 0x6c
+        #[cfg(feature = "unicode-basic-latin")]
+        'l' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3646,
+            3693,
+        )),
+        // letter: 'm' / 0x6d
+        #[cfg(feature = "unicode-basic-latin")]
+        'm' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3693,
+            3739,
+        )),
+        // letter: 'n' / 0x6e
+        #[cfg(feature = "unicode-basic-latin")]
+        'n' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3739,
+            3784,
+        )),
+        // letter: 'o' / 0x6f
+        #[cfg(feature = "unicode-basic-latin")]
+        'o' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3784,
+            3834,
+        )),
+        // letter: 'p' / 0x70
+        #[cfg(feature = "unicode-basic-latin")]
+        'p' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3834,
+            3893,
+        )),
+        // letter: 'q' / 0x71
+        #[cfg(feature = "unicode-basic-latin")]
+        'q' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3893,
+            3955,
+        )),
+        // letter: 'r' / 0x72
+        #[cfg(feature = "unicode-basic-latin")]
+        'r' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3955,
+            3995,
+        )),
+        // letter: 's' / 0x73
+        #[cfg(feature = "unicode-basic-latin")]
+        's' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            3995,
+            4039,
+        )),
+        // letter: 't' / 0x74
+        #[cfg(feature = "unicode-basic-latin")]
+        't' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4039,
+            4093,
+        )),
+        // letter: 'u' / 0x75
+        #[cfg(feature = "unicode-basic-latin")]
+        'u' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4093,
+            4144,
+        )),
+        // letter: 'v' / 0x76
+        #[cfg(feature = "unicode-basic-latin")]
+        'v' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4144,
+            4186,
+        )),
+        // letter: 'w' / 0x77
+        #[cfg(feature = "unicode-basic-latin")]
+        'w' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4186,
+            4231,
+        )),
+        // letter: 'x' / 0x78
+        #[cfg(feature = "unicode-basic-latin")]
+        'x' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4231,
+            4276,
+        )),
+        // letter: 'y' / 0x79
+        #[cfg(feature = "unicode-basic-latin")]
+        'y' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4276,
+            4335,
+        )),
+        // letter: 'z' / 0x7a
+        #[cfg(feature = "unicode-basic-latin")]
+        'z' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4335,
+            4374,
+        )),
+        // letter: '{' / 0x7b
+        #[cfg(feature = "unicode-basic-latin")]
+        '{' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4374,
+            4428,
+        )),
+        // letter: '|' / 0x7c
+        #[cfg(feature = "unicode-basic-latin")]
+        '|' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4428,
+            4478,
+        )),
+        // letter: '}' / 0x7d
+        #[cfg(feature = "unicode-basic-latin")]
+        '}' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4478,
+            4528,
+        )),
+        // letter: '~' / 0x7e
+        #[cfg(feature = "unicode-basic-latin")]
+        '~' => Some(crate::glyph_data::bytes_at(
+            UNICODE_BASIC_LATIN_COMPRESSED,
+            4528,
+            4552,
+        )),
+        // letter: '¡' / 0xa1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¡' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            0,
+            45,
+        )),
+        // letter: '¢' / 0xa2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¢' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            45,
+            93,
+        )),
+        // letter: '£' / 0xa3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '£' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            93,
+            142,
+        )),
+        // letter: '¤' / 0xa4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¤' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            142,
+            188,
+        )),
+        // letter: '¥' / 0xa5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¥' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            188,
+            246,
+        )),
+        // letter: '¦' / 0xa6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¦' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            246,
+            288,
+        )),
+        // letter: '§' / 0xa7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '§' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            288,
+            355,
+        )),
+        // letter: '¨' / 0xa8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¨' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            355,
+            364,
+        )),
+        // letter: '©' / 0xa9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '©' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            364,
+            409,
+        )),
+        // letter: 'ª' / 0xaa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ª' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            409,
+            437,
+        )),
+        // letter: '«' / 0xab
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '«' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            437,
+            481,
+        )),
+        // letter: '¬' / 0xac
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¬' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            481,
+            505,
+        )),
+        // letter: '®' / 0xae
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '®' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            505,
+            550,
+        )),
+        // letter: '¯' / 0xaf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¯' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            550,
+            566,
+        )),
+        // letter: '°' / 0xb0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '°' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            566,
+            595,
+        )),
+        // letter: '±' / 0xb1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '±' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            595,
+            637,
+        )),
+        // letter: '²' / 0xb2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '²' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            637,
+            677,
+        )),
+        // letter: '³' / 0xb3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '³' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            677,
+            718,
+        )),
+        // letter: '´' / 0xb4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '´' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            718,
+            735,
+        )),
+        // letter: 'µ' / 0xb5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'µ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            735,
+            796,
+        )),
+        // letter: '¶' / 0xb6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¶' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            796,
+            871,
+        )),
+        // letter: '·' / 0xb7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '·' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            871,
+            882,
+        )),
+        // letter: '¸' / 0xb8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¸' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            882,
+            905,
+        )),
+        // letter: '¹' / 0xb9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¹' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            905,
+            936,
+        )),
+        // letter: 'º' / 0xba
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'º' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            936,
+            964,
+        )),
+        // letter: '»' / 0xbb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '»' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            964,
+            1006,
+        )),
+        // letter: '¼' / 0xbc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¼' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1006,
+            1066,
+        )),
+        // letter: '½' / 0xbd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '½' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1066,
+            1126,
+        )),
+        // letter: '¾' / 0xbe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¾' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1126,
+            1187,
+        )),
+        // letter: '¿' / 0xbf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '¿' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1187,
+            1236,
+        )),
+        // letter: 'À' / 0xc0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'À' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1236,
+            1305,
+        )),
+        // letter: 'Á' / 0xc1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Á' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1305,
+            1376,
+        )),
+        // letter: 'Â' / 0xc2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Â' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1376,
+            1451,
+        )),
+        // letter: 'Ã' / 0xc3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ã' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1451,
+            1521,
+        )),
+        // letter: 'Ä' / 0xc4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ä' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1521,
+            1584,
+        )),
+        // letter: 'Å' / 0xc5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Å' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1584,
+            1657,
+        )),
+        // letter: 'Æ' / 0xc6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Æ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1657,
+            1714,
+        )),
+        // letter: 'Ç' / 0xc7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ç' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1714,
+            1777,
+        )),
+        // letter: 'È' / 0xc8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'È' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1777,
+            1838,
+        )),
+        // letter: 'É' / 0xc9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'É' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1838,
+            1900,
+        )),
+        // letter: 'Ê' / 0xca
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ê' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1900,
+            1965,
+        )),
+        // letter: 'Ë' / 0xcb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ë' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            1965,
+            2019,
+        )),
+        // letter: 'Ì' / 0xcc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ì' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2019,
+            2080,
+        )),
+        // letter: 'Í' / 0xcd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Í' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2080,
+            2143,
+        )),
+        // letter: 'Î' / 0xce
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Î' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2143,
+            2210,
+        )),
+        // letter: 'Ï' / 0xcf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ï' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2210,
+            2265,
+        )),
+        // letter: 'Ð' / 0xd0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ð' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2265,
+            2324,
+        )),
+        // letter: 'Ñ' / 0xd1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ñ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2324,
+            2397,
+        )),
+        // letter: 'Ò' / 0xd2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ò' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2397,
+            2474,
+        )),
+        // letter: 'Ó' / 0xd3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ó' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2474,
+            2553,
+        )),
+        // letter: 'Ô' / 0xd4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ô' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2553,
+            2636,
+        )),
+        // letter: 'Õ' / 0xd5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Õ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2636,
+            2714,
+        )),
+        // letter: 'Ö' / 0xd6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ö' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2714,
+            2785,
+        )),
+        // letter: '×' / 0xd7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '×' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2785,
+            2829,
+        )),
+        // letter: 'Ø' / 0xd8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ø' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2829,
+            2896,
+        )),
+        // letter: 'Ù' / 0xd9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ù' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2896,
+            2974,
+        )),
+        // letter: 'Ú' / 0xda
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ú' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            2974,
+            3054,
+        )),
+        // letter: 'Û' / 0xdb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Û' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3054,
+            3137,
+        )),
+        // letter: 'Ü' / 0xdc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ü' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3137,
+            3209,
+        )),
+        // letter: 'Ý' / 0xdd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Ý' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3209,
+            3273,
+        )),
+        // letter: 'Þ' / 0xde
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'Þ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3273,
+            3325,
+        )),
+        // letter: 'ß' / 0xdf
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ß' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3325,
+            3396,
+        )),
+        // letter: 'à' / 0xe0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'à' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3396,
+            3458,
+        )),
+        // letter: 'á' / 0xe1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'á' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3458,
+            3521,
+        )),
+        // letter: 'â' / 0xe2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'â' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3521,
+            3587,
+        )),
+        // letter: 'ã' / 0xe3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ã' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3587,
+            3649,
+        )),
+        // letter: 'ä' / 0xe4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ä' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3649,
+            3704,
+        )),
+        // letter: 'å' / 0xe5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'å' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3704,
+            3770,
+        )),
+        // letter: 'æ' / 0xe6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'æ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3770,
+            3823,
+        )),
+        // letter: 'ç' / 0xe7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ç' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3823,
+            3875,
+        )),
+        // letter: 'è' / 0xe8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'è' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3875,
+            3936,
+        )),
+        // letter: 'é' / 0xe9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'é' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3936,
+            3999,
+        )),
+        // letter: 'ê' / 0xea
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ê' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            3999,
+            4065,
+        )),
+        // letter: 'ë' / 0xeb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ë' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4065,
+            4120,
+        )),
+        // letter: 'ì' / 0xec
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ì' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4120,
+            4167,
+        )),
+        // letter: 'í' / 0xed
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'í' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4167,
+            4215,
+        )),
+        // letter: 'î' / 0xee
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'î' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4215,
+            4267,
+        )),
+        // letter: 'ï' / 0xef
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ï' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4267,
+            4307,
+        )),
+        // letter: 'ð' / 0xf0
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ð' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4307,
+            4377,
+        )),
+        // letter: 'ñ' / 0xf1
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ñ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4377,
+            4436,
+        )),
+        // letter: 'ò' / 0xf2
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ò' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4436,
+            4500,
+        )),
+        // letter: 'ó' / 0xf3
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ó' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4500,
+            4565,
+        )),
+        // letter: 'ô' / 0xf4
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ô' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4565,
+            4634,
+        )),
+        // letter: 'õ' / 0xf5
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'õ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4634,
+            4698,
+        )),
+        // letter: 'ö' / 0xf6
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ö' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4698,
+            4755,
+        )),
+        // letter: '÷' / 0xf7
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        '÷' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4755,
+            4791,
+        )),
+        // letter: 'ø' / 0xf8
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ø' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4791,
+            4844,
+        )),
+        // letter: 'ù' / 0xf9
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ù' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4844,
+            4909,
+        )),
+        // letter: 'ú' / 0xfa
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ú' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4909,
+            4975,
+        )),
+        // letter: 'û' / 0xfb
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'û' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            4975,
+            5044,
+        )),
+        // letter: 'ü' / 0xfc
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ü' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5044,
+            5102,
+        )),
+        // letter: 'ý' / 0xfd
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ý' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5102,
+            5176,
+        )),
+        // letter: 'þ' / 0xfe
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'þ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5176,
+            5247,
+        )),
+        // letter: 'ÿ' / 0xff
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        'ÿ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_1_SUPPLEMENT_COMPRESSED,
+            5247,
+            5313,
+        )),
         // letter: 'Ā' / 0x100
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ā' => Some(include!(
-            "../res_rasterized_characters/0x100_h16_wLight.txt"
+        'Ā' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            0,
+            70,
         )),
         // letter: 'ā' / 0x101
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ā' => Some(include!(
-            "../res_rasterized_characters/0x101_h16_wLight.txt"
+        'ā' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            70,
+            132,
         )),
         // letter: 'Ă' / 0x102
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ă' => Some(include!(
-            "../res_rasterized_characters/0x102_h16_wLight.txt"
+        'Ă' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            132,
+            207,
         )),
         // letter: 'ă' / 0x103
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ă' => Some(include!(
-            "../res_rasterized_characters/0x103_h16_wLight.txt"
+        'ă' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            207,
+            275,
         )),
         // letter: 'Ą' / 0x104
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ą' => Some(include!(
-            "../res_rasterized_characters/0x104_h16_wLight.txt"
+        'Ą' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            275,
+            346,
         )),
         // letter: 'ą' / 0x105
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ą' => Some(include!(
-            "../res_rasterized_characters/0x105_h16_wLight.txt"
+        'ą' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            346,
+            406,
         )),
         // letter: 'Ć' / 0x106
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ć' => Some(include!(
-            "../res_rasterized_characters/0x106_h16_wLight.txt"
+        'Ć' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            406,
+            474,
         )),
         // letter: 'ć' / 0x107
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ć' => Some(include!(
-            "../res_rasterized_characters/0x107_h16_wLight.txt"
+        'ć' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            474,
+            529,
         )),
         // letter: 'Ĉ' / 0x108
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĉ' => Some(include!(
-            "../res_rasterized_characters/0x108_h16_wLight.txt"
+        'Ĉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            529,
+            599,
         )),
         // letter: 'ĉ' / 0x109
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĉ' => Some(include!(
-            "../res_rasterized_characters/0x109_h16_wLight.txt"
+        'ĉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            599,
+            657,
         )),
         // letter: 'Ċ' / 0x10a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ċ' => Some(include!(
-            "../res_rasterized_characters/0x10a_h16_wLight.txt"
+        'Ċ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            657,
+            719,
         )),
         // letter: 'ċ' / 0x10b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ċ' => Some(include!(
-            "../res_rasterized_characters/0x10b_h16_wLight.txt"
+        'ċ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            719,
+            769,
         )),
         // letter: 'Č' / 0x10c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Č' => Some(include!(
-            "../res_rasterized_characters/0x10c_h16_wLight.txt"
+        'Č' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            769,
+            840,
         )),
         // letter: 'č' / 0x10d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'č' => Some(include!(
-            "../res_rasterized_characters/0x10d_h16_wLight.txt"
+        'č' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            840,
+            899,
         )),
         // letter: 'Ď' / 0x10e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ď' => Some(include!(
-            "../res_rasterized_characters/0x10e_h16_wLight.txt"
+        'Ď' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            899,
+            977,
         )),
         // letter: 'ď' / 0x10f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ď' => Some(include!(
-            "../res_rasterized_characters/0x10f_h16_wLight.txt"
+        'ď' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            977,
+            1043,
         )),
         // letter: 'Đ' / 0x110
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Đ' => Some(include!(
-            "../res_rasterized_characters/0x110_h16_wLight.txt"
+        'Đ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1043,
+            1102,
         )),
         // letter: 'đ' / 0x111
         #[cfg(feature = "unicode-latin-extended-a")]
-        'đ' => Some(include!(
-            "../res_rasterized_characters/0x111_h16_wLight.txt"
+        'đ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1102,
+            1170,
         )),
         // letter: 'Ē' / 0x112
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ē' => Some(include!(
-            "../res_rasterized_characters/0x112_h16_wLight.txt"
+        'Ē' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1170,
+            1231,
         )),
         // letter: 'ē' / 0x113
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ē' => Some(include!(
-            "../res_rasterized_characters/0x113_h16_wLight.txt"
+        'ē' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1231,
+            1293,
         )),
         // letter: 'Ĕ' / 0x114
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĕ' => Some(include!(
-            "../res_rasterized_characters/0x114_h16_wLight.txt"
+        'Ĕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1293,
+            1359,
         )),
         // letter: 'ĕ' / 0x115
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĕ' => Some(include!(
-            "../res_rasterized_characters/0x115_h16_wLight.txt"
+        'ĕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1359,
+            1427,
         )),
         // letter: 'Ė' / 0x116
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ė' => Some(include!(
-            "../res_rasterized_characters/0x116_h16_wLight.txt"
+        'Ė' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1427,
+            1483,
         )),
         // letter: 'ė' / 0x117
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ė' => Some(include!(
-            "../res_rasterized_characters/0x117_h16_wLight.txt"
+        'ė' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1483,
+            1541,
         )),
         // letter: 'Ę' / 0x118
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ę' => Some(include!(
-            "../res_rasterized_characters/0x118_h16_wLight.txt"
+        'Ę' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1541,
+            1605,
         )),
         // letter: 'ę' / 0x119
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ę' => Some(include!(
-            "../res_rasterized_characters/0x119_h16_wLight.txt"
+        'ę' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1605,
+            1665,
         )),
         // letter: 'Ě' / 0x11a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ě' => Some(include!(
-            "../res_rasterized_characters/0x11a_h16_wLight.txt"
+        'Ě' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1665,
+            1729,
         )),
         // letter: 'ě' / 0x11b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ě' => Some(include!(
-            "../res_rasterized_characters/0x11b_h16_wLight.txt"
+        'ě' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1729,
+            1795,
         )),
         // letter: 'Ĝ' / 0x11c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĝ' => Some(include!(
-            "../res_rasterized_characters/0x11c_h16_wLight.txt"
+        'Ĝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1795,
+            1874,
         )),
         // letter: 'ĝ' / 0x11d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĝ' => Some(include!(
-            "../res_rasterized_characters/0x11d_h16_wLight.txt"
+        'ĝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1874,
+            1958,
         )),
         // letter: 'Ğ' / 0x11e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ğ' => Some(include!(
-            "../res_rasterized_characters/0x11e_h16_wLight.txt"
+        'Ğ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            1958,
+            2039,
         )),
         // letter: 'ğ' / 0x11f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ğ' => Some(include!(
-            "../res_rasterized_characters/0x11f_h16_wLight.txt"
+        'ğ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2039,
+            2124,
         )),
         // letter: 'Ġ' / 0x120
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ġ' => Some(include!(
-            "../res_rasterized_characters/0x120_h16_wLight.txt"
+        'Ġ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2124,
+            2194,
         )),
         // letter: 'ġ' / 0x121
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ġ' => Some(include!(
-            "../res_rasterized_characters/0x121_h16_wLight.txt"
+        'ġ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2194,
+            2269,
         )),
         // letter: 'Ģ' / 0x122
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ģ' => Some(include!(
-            "../res_rasterized_characters/0x122_h16_wLight.txt"
+        'Ģ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2269,
+            2339,
         )),
         // letter: 'ģ' / 0x123
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ģ' => Some(include!(
-            "../res_rasterized_characters/0x123_h16_wLight.txt"
+        'ģ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2339,
+            2418,
         )),
         // letter: 'Ĥ' / 0x124
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĥ' => Some(include!(
-            "../res_rasterized_characters/0x124_h16_wLight.txt"
+        'Ĥ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2418,
+            2496,
         )),
         // letter: 'ĥ' / 0x125
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĥ' => Some(include!(
-            "../res_rasterized_characters/0x125_h16_wLight.txt"
+        'ĥ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2496,
+            2565,
         )),
         // letter: 'Ħ' / 0x126
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ħ' => Some(include!(
-            "../res_rasterized_characters/0x126_h16_wLight.txt"
+        'Ħ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2565,
+            2625,
         )),
         // letter: 'ħ' / 0x127
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ħ' => Some(include!(
-            "../res_rasterized_characters/0x127_h16_wLight.txt"
+        'ħ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2625,
+            2688,
         )),
         // letter: 'Ĩ' / 0x128
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĩ' => Some(include!(
-            "../res_rasterized_characters/0x128_h16_wLight.txt"
+        'Ĩ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2688,
+            2750,
         )),
         // letter: 'ĩ' / 0x129
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĩ' => Some(include!(
-            "../res_rasterized_characters/0x129_h16_wLight.txt"
+        'ĩ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2750,
+            2797,
         )),
         // letter: 'Ī' / 0x12a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ī' => Some(include!(
-            "../res_rasterized_characters/0x12a_h16_wLight.txt"
+        'Ī' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2797,
+            2859,
         )),
         // letter: 'ī' / 0x12b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ī' => Some(include!(
-            "../res_rasterized_characters/0x12b_h16_wLight.txt"
+        'ī' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2859,
+            2906,
         )),
         // letter: 'Ĭ' / 0x12c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĭ' => Some(include!(
-            "../res_rasterized_characters/0x12c_h16_wLight.txt"
+        'Ĭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2906,
+            2973,
         )),
         // letter: 'ĭ' / 0x12d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĭ' => Some(include!(
-            "../res_rasterized_characters/0x12d_h16_wLight.txt"
+        'ĭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            2973,
+            3026,
         )),
         // letter: 'Į' / 0x12e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Į' => Some(include!(
-            "../res_rasterized_characters/0x12e_h16_wLight.txt"
+        'Į' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3026,
+            3089,
         )),
         // letter: 'į' / 0x12f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'į' => Some(include!(
-            "../res_rasterized_characters/0x12f_h16_wLight.txt"
+        'į' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3089,
+            3148,
         )),
         // letter: 'İ' / 0x130
         #[cfg(feature = "unicode-latin-extended-a")]
-        'İ' => Some(include!(
-            "../res_rasterized_characters/0x130_h16_wLight.txt"
+        'İ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3148,
+            3205,
         )),
         // letter: 'ı' / 0x131
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ı' => Some(include!(
-            "../res_rasterized_characters/0x131_h16_wLight.txt"
+        'ı' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3205,
+            3238,
         )),
         // letter: 'Ĳ' / 0x132
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĳ' => Some(include!(
-            "../res_rasterized_characters/0x132_h16_wLight.txt"
+        'Ĳ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3238,
+            3302,
         )),
         // letter: 'ĳ' / 0x133
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĳ' => Some(include!(
-            "../res_rasterized_characters/0x133_h16_wLight.txt"
+        'ĳ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3302,
+            3380,
         )),
         // letter: 'Ĵ' / 0x134
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĵ' => Some(include!(
-            "../res_rasterized_characters/0x134_h16_wLight.txt"
+        'Ĵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3380,
+            3442,
         )),
         // letter: 'ĵ' / 0x135
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĵ' => Some(include!(
-            "../res_rasterized_characters/0x135_h16_wLight.txt"
+        'ĵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3442,
+            3512,
         )),
         // letter: 'Ķ' / 0x136
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ķ' => Some(include!(
-            "../res_rasterized_characters/0x136_h16_wLight.txt"
+        'Ķ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3512,
+            3586,
         )),
         // letter: 'ķ' / 0x137
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ķ' => Some(include!(
-            "../res_rasterized_characters/0x137_h16_wLight.txt"
+        'ķ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3586,
+            3658,
         )),
         // letter: 'ĸ' / 0x138
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĸ' => Some(include!(
-            "../res_rasterized_characters/0x138_h16_wLight.txt"
+        'ĸ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3658,
+            3704,
         )),
         // letter: 'Ĺ' / 0x139
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ĺ' => Some(include!(
-            "../res_rasterized_characters/0x139_h16_wLight.txt"
+        'Ĺ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3704,
+            3764,
         )),
         // letter: 'ĺ' / 0x13a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ĺ' => Some(include!(
-            "../res_rasterized_characters/0x13a_h16_wLight.txt"
+        'ĺ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3764,
+            3822,
         )),
         // letter: 'Ļ' / 0x13b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ļ' => Some(include!(
-            "../res_rasterized_characters/0x13b_h16_wLight.txt"
+        'Ļ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3822,
+            3881,
         )),
         // letter: 'ļ' / 0x13c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ļ' => Some(include!(
-            "../res_rasterized_characters/0x13c_h16_wLight.txt"
+        'ļ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3881,
+            3942,
         )),
         // letter: 'Ľ' / 0x13d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ľ' => Some(include!(
-            "../res_rasterized_characters/0x13d_h16_wLight.txt"
+        'Ľ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3942,
+            3991,
         )),
         // letter: 'ľ' / 0x13e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ľ' => Some(include!(
-            "../res_rasterized_characters/0x13e_h16_wLight.txt"
+        'ľ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            3991,
+            4043,
         )),
         // letter: 'Ŀ' / 0x13f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŀ' => Some(include!(
-            "../res_rasterized_characters/0x13f_h16_wLight.txt"
+        'Ŀ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4043,
+            4092,
         )),
         // letter: 'ŀ' / 0x140
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŀ' => Some(include!(
-            "../res_rasterized_characters/0x140_h16_wLight.txt"
+        'ŀ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4092,
+            4145,
         )),
         // letter: 'Ł' / 0x141
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ł' => Some(include!(
-            "../res_rasterized_characters/0x141_h16_wLight.txt"
+        'Ł' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4145,
+            4195,
         )),
         // letter: 'ł' / 0x142
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ł' => Some(include!(
-            "../res_rasterized_characters/0x142_h16_wLight.txt"
+        'ł' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4195,
+            4249,
         )),
         // letter: 'Ń' / 0x143
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ń' => Some(include!(
-            "../res_rasterized_characters/0x143_h16_wLight.txt"
+        'Ń' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4249,
+            4324,
         )),
         // letter: 'ń' / 0x144
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ń' => Some(include!(
-            "../res_rasterized_characters/0x144_h16_wLight.txt"
+        'ń' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4324,
+            4383,
         )),
         // letter: 'Ņ' / 0x145
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ņ' => Some(include!(
-            "../res_rasterized_characters/0x145_h16_wLight.txt"
+        'Ņ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4383,
+            4458,
         )),
         // letter: 'ņ' / 0x146
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ņ' => Some(include!(
-            "../res_rasterized_characters/0x146_h16_wLight.txt"
+        'ņ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4458,
+            4517,
         )),
         // letter: 'Ň' / 0x147
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ň' => Some(include!(
-            "../res_rasterized_characters/0x147_h16_wLight.txt"
+        'Ň' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4517,
+            4594,
         )),
         // letter: 'ň' / 0x148
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ň' => Some(include!(
-            "../res_rasterized_characters/0x148_h16_wLight.txt"
+        'ň' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4594,
+            4658,
         )),
         // letter: 'ŉ' / 0x149
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŉ' => Some(include!(
-            "../res_rasterized_characters/0x149_h16_wLight.txt"
+        'ŉ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4658,
+            4714,
         )),
         // letter: 'Ŋ' / 0x14a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŋ' => Some(include!(
-            "../res_rasterized_characters/0x14a_h16_wLight.txt"
+        'Ŋ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4714,
+            4786,
         )),
         // letter: 'ŋ' / 0x14b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŋ' => Some(include!(
-            "../res_rasterized_characters/0x14b_h16_wLight.txt"
+        'ŋ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4786,
+            4846,
         )),
         // letter: 'Ō' / 0x14c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ō' => Some(include!(
-            "../res_rasterized_characters/0x14c_h16_wLight.txt"
+        'Ō' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4846,
+            4924,
         )),
         // letter: 'ō' / 0x14d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ō' => Some(include!(
-            "../res_rasterized_characters/0x14d_h16_wLight.txt"
+        'ō' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4924,
+            4988,
         )),
         // letter: 'Ŏ' / 0x14e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŏ' => Some(include!(
-            "../res_rasterized_characters/0x14e_h16_wLight.txt"
+        'Ŏ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            4988,
+            5071,
         )),
         // letter: 'ŏ' / 0x14f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŏ' => Some(include!(
-            "../res_rasterized_characters/0x14f_h16_wLight.txt"
+        'ŏ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5071,
+            5141,
         )),
         // letter: 'Ő' / 0x150
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ő' => Some(include!(
-            "../res_rasterized_characters/0x150_h16_wLight.txt"
+        'Ő' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5141,
+            5225,
         )),
         // letter: 'ő' / 0x151
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ő' => Some(include!(
-            "../res_rasterized_characters/0x151_h16_wLight.txt"
+        'ő' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5225,
+            5295,
         )),
         // letter: 'Œ' / 0x152
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Œ' => Some(include!(
-            "../res_rasterized_characters/0x152_h16_wLight.txt"
+        'Œ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5295,
+            5358,
         )),
         // letter: 'œ' / 0x153
         #[cfg(feature = "unicode-latin-extended-a")]
-        'œ' => Some(include!(
-            "../res_rasterized_characters/0x153_h16_wLight.txt"
+        'œ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5358,
+            5411,
         )),
         // letter: 'Ŕ' / 0x154
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŕ' => Some(include!(
-            "../res_rasterized_characters/0x154_h16_wLight.txt"
+        'Ŕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5411,
+            5486,
         )),
         // letter: 'ŕ' / 0x155
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŕ' => Some(include!(
-            "../res_rasterized_characters/0x155_h16_wLight.txt"
+        'ŕ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5486,
+            5541,
         )),
         // letter: 'Ŗ' / 0x156
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŗ' => Some(include!(
-            "../res_rasterized_characters/0x156_h16_wLight.txt"
+        'Ŗ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5541,
+            5615,
         )),
         // letter: 'ŗ' / 0x157
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŗ' => Some(include!(
-            "../res_rasterized_characters/0x157_h16_wLight.txt"
+        'ŗ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5615,
+            5669,
         )),
         // letter: 'Ř' / 0x158
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ř' => Some(include!(
-            "../res_rasterized_characters/0x158_h16_wLight.txt"
+        'Ř' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5669,
+            5746,
         )),
         // letter: 'ř' / 0x159
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ř' => Some(include!(
-            "../res_rasterized_characters/0x159_h16_wLight.txt"
+        'ř' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5746,
+            5805,
         )),
         // letter: 'Ś' / 0x15a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ś' => Some(include!(
-            "../res_rasterized_characters/0x15a_h16_wLight.txt"
+        'Ś' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5805,
+            5874,
         )),
         // letter: 'ś' / 0x15b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ś' => Some(include!(
-            "../res_rasterized_characters/0x15b_h16_wLight.txt"
+        'ś' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5874,
+            5933,
         )),
         // letter: 'Ŝ' / 0x15c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŝ' => Some(include!(
-            "../res_rasterized_characters/0x15c_h16_wLight.txt"
+        'Ŝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            5933,
+            6006,
         )),
         // letter: 'ŝ' / 0x15d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŝ' => Some(include!(
-            "../res_rasterized_characters/0x15d_h16_wLight.txt"
+        'ŝ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6006,
+            6069,
         )),
         // letter: 'Ş' / 0x15e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ş' => Some(include!(
-            "../res_rasterized_characters/0x15e_h16_wLight.txt"
+        'Ş' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6069,
+            6135,
         )),
         // letter: 'ş' / 0x15f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ş' => Some(include!(
-            "../res_rasterized_characters/0x15f_h16_wLight.txt"
+        'ş' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6135,
+            6190,
         )),
         // letter: 'Š' / 0x160
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Š' => Some(include!(
-            "../res_rasterized_characters/0x160_h16_wLight.txt"
+        'Š' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6190,
+            6262,
         )),
         // letter: 'š' / 0x161
         #[cfg(feature = "unicode-latin-extended-a")]
-        'š' => Some(include!(
-            "../res_rasterized_characters/0x161_h16_wLight.txt"
+        'š' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6262,
+            6324,
         )),
         // letter: 'Ţ' / 0x162
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ţ' => Some(include!(
-            "../res_rasterized_characters/0x162_h16_wLight.txt"
+        'Ţ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6324,
+            6386,
         )),
         // letter: 'ţ' / 0x163
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ţ' => Some(include!(
-            "../res_rasterized_characters/0x163_h16_wLight.txt"
+        'ţ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6386,
+            6451,
         )),
         // letter: 'Ť' / 0x164
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ť' => Some(include!(
-            "../res_rasterized_characters/0x164_h16_wLight.txt"
+        'Ť' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6451,
+            6514,
         )),
         // letter: 'ť' / 0x165
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ť' => Some(include!(
-            "../res_rasterized_characters/0x165_h16_wLight.txt"
+        'ť' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6514,
+            6575,
         )),
         // letter: 'Ŧ' / 0x166
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŧ' => Some(include!(
-            "../res_rasterized_characters/0x166_h16_wLight.txt"
+        'Ŧ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6575,
+            6626,
         )),
         // letter: 'ŧ' / 0x167
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŧ' => Some(include!(
-            "../res_rasterized_characters/0x167_h16_wLight.txt"
+        'ŧ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6626,
+            6684,
         )),
         // letter: 'Ũ' / 0x168
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ũ' => Some(include!(
-            "../res_rasterized_characters/0x168_h16_wLight.txt"
+        'Ũ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6684,
+            6762,
         )),
         // letter: 'ũ' / 0x169
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ũ' => Some(include!(
-            "../res_rasterized_characters/0x169_h16_wLight.txt"
+        'ũ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6762,
+            6826,
         )),
         // letter: 'Ū' / 0x16a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ū' => Some(include!(
-            "../res_rasterized_characters/0x16a_h16_wLight.txt"
+        'Ū' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6826,
+            6904,
         )),
         // letter: 'ū' / 0x16b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ū' => Some(include!(
-            "../res_rasterized_characters/0x16b_h16_wLight.txt"
+        'ū' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6904,
+            6968,
         )),
         // letter: 'Ŭ' / 0x16c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŭ' => Some(include!(
-            "../res_rasterized_characters/0x16c_h16_wLight.txt"
+        'Ŭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            6968,
+            7051,
         )),
         // letter: 'ŭ' / 0x16d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŭ' => Some(include!(
-            "../res_rasterized_characters/0x16d_h16_wLight.txt"
+        'ŭ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7051,
+            7121,
         )),
         // letter: 'Ů' / 0x16e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ů' => Some(include!(
-            "../res_rasterized_characters/0x16e_h16_wLight.txt"
+        'Ů' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7121,
+            7203,
         )),
         // letter: 'ů' / 0x16f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ů' => Some(include!(
-            "../res_rasterized_characters/0x16f_h16_wLight.txt"
+        'ů' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7203,
+            7271,
         )),
         // letter: 'Ű' / 0x170
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ű' => Some(include!(
-            "../res_rasterized_characters/0x170_h16_wLight.txt"
+        'Ű' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7271,
+            7355,
         )),
         // letter: 'ű' / 0x171
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ű' => Some(include!(
-            "../res_rasterized_characters/0x171_h16_wLight.txt"
+        'ű' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7355,
+            7425,
         )),
         // letter: 'Ų' / 0x172
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ų' => Some(include!(
-            "../res_rasterized_characters/0x172_h16_wLight.txt"
+        'Ų' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7425,
+            7503,
         )),
         // letter: 'ų' / 0x173
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ų' => Some(include!(
-            "../res_rasterized_characters/0x173_h16_wLight.txt"
+        'ų' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7503,
+            7566,
         )),
         // letter: 'Ŵ' / 0x174
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŵ' => Some(include!(
-            "../res_rasterized_characters/0x174_h16_wLight.txt"
+        'Ŵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7566,
+            7644,
         )),
         // letter: 'ŵ' / 0x175
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŵ' => Some(include!(
-            "../res_rasterized_characters/0x175_h16_wLight.txt"
+        'ŵ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7644,
+            7706,
         )),
         // letter: 'Ŷ' / 0x176
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ŷ' => Some(include!(
-            "../res_rasterized_characters/0x176_h16_wLight.txt"
+        'Ŷ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7706,
+            7773,
         )),
         // letter: 'ŷ' / 0x177
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ŷ' => Some(include!(
-            "../res_rasterized_characters/0x177_h16_wLight.txt"
+        'ŷ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7773,
+            7850,
         )),
         // letter: 'Ÿ' / 0x178
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ÿ' => Some(include!(
-            "../res_rasterized_characters/0x178_h16_wLight.txt"
+        'Ÿ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7850,
+            7906,
         )),
         // letter: 'Ź' / 0x179
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ź' => Some(include!(
-            "../res_rasterized_characters/0x179_h16_wLight.txt"
+        'Ź' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7906,
+            7967,
         )),
         // letter: 'ź' / 0x17a
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ź' => Some(include!(
-            "../res_rasterized_characters/0x17a_h16_wLight.txt"
+        'ź' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            7967,
+            8020,
         )),
         // letter: 'Ż' / 0x17b
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ż' => Some(include!(
-            "../res_rasterized_characters/0x17b_h16_wLight.txt"
+        'Ż' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8020,
+            8077,
         )),
         // letter: 'ż' / 0x17c
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ż' => Some(include!(
-            "../res_rasterized_characters/0x17c_h16_wLight.txt"
+        'ż' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8077,
+            8125,
         )),
         // letter: 'Ž' / 0x17d
         #[cfg(feature = "unicode-latin-extended-a")]
-        'Ž' => Some(include!(
-            "../res_rasterized_characters/0x17d_h16_wLight.txt"
+        'Ž' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8125,
+            8191,
         )),
         // letter: 'ž' / 0x17e
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ž' => Some(include!(
-            "../res_rasterized_characters/0x17e_h16_wLight.txt"
+        'ž' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8191,
+            8249,
         )),
         // letter: 'ſ' / 0x17f
         #[cfg(feature = "unicode-latin-extended-a")]
-        'ſ' => Some(include!(
-            "../res_rasterized_characters/0x17f_h16_wLight.txt"
+        'ſ' => Some(crate::glyph_data::bytes_at(
+            UNICODE_LATIN_EXTENDED_A_COMPRESSED,
+            8249,
+            8299,
         )),
         // letter: '�' / 0xfffd
         #[cfg(feature = "unicode-specials")]
-        '�' => Some(include!(
-            "../res_rasterized_characters/0xfffd_h16_wLight.txt"
+        '�' => Some(crate::glyph_data::bytes_at(
+            UNICODE_SPECIALS_COMPRESSED,
+            0,
+            75,
         )),
         _ => None,
     }
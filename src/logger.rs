@@ -0,0 +1,442 @@
+//! Module for the integration with the [`log`] crate, which renders log records
+//! onto a framebuffer, e.g., in kernels that also forward them to a serial port.
+
+use crate::color::blend;
+use crate::measure::char_columns;
+use crate::{
+    FontWeight, GlyphSource, NotoGlyphs, Raster, RasterHeight, TabWidth, get_raster_width,
+};
+use core::fmt::{Debug, Formatter, Write};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Default colors of the levels `ERROR`, `WARN`, `INFO`, `DEBUG`, and `TRACE`.
+const LEVEL_COLORS: [u32; 5] = [0xff5555, 0xffff55, 0x55ff55, 0xaaaaaa, 0x777777];
+
+/// Replacement for chars that are not supported by the [`GlyphSource`].
+const REPLACEMENT_CHAR: char = '?';
+
+/// Behavior of [`FramebufferLogger`] for lines that are wider than the screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Continues the line in the next row.
+    #[default]
+    Wrap,
+    /// Cuts the line off at the right edge of the screen.
+    Truncate,
+}
+
+/// [`Log`] implementation that renders each record as `[LEVEL] target: message`
+/// onto a framebuffer, in the color of its level.
+///
+/// The logger draws each pixel with `put_pixel(x, y, color)`, where `color` is
+/// `0x00RRGGBB`. Characters are blended with the background color, hence, nothing
+/// has to be read from the framebuffer. The write path needs no locks and no
+/// allocations, so records can be logged from interrupt handlers, as long as
+/// `put_pixel` can be called there, too. Each row of the screen is reserved with a
+/// single atomic operation. Concurrent records therefore never overwrite each
+/// other, but the rows of wrapped records may interleave.
+///
+/// There is no scrolling, as it would need to move the whole screen. When the
+/// last row is reached, the output continues in the first one.
+///
+/// The logger draws the built-in glyphs unless others are set with
+/// [`FramebufferLogger::with_glyphs`].
+///
+/// ```rust
+/// use log::LevelFilter;
+/// use noto_sans_mono_bitmap::{FontWeight, FramebufferLogger, RasterHeight};
+///
+/// fn put_pixel(x: usize, y: usize, color: u32) {
+///     // write `color` to the framebuffer
+/// }
+///
+/// type PutPixel = fn(usize, usize, u32);
+///
+/// static LOGGER: FramebufferLogger<PutPixel> = FramebufferLogger::new(
+///     1280,
+///     720,
+///     FontWeight::Regular,
+///     RasterHeight::Size16,
+///     put_pixel as PutPixel,
+/// )
+/// .with_max_level(LevelFilter::Info);
+///
+/// log::set_logger(&LOGGER).unwrap();
+/// log::set_max_level(LevelFilter::Info);
+/// log::info!("booted in {} ms", 42);
+/// ```
+pub struct FramebufferLogger<F, G = NotoGlyphs> {
+    put_pixel: F,
+    glyphs: G,
+    style: FontWeight,
+    size: RasterHeight,
+    columns: usize,
+    rows: usize,
+    level_colors: [u32; 5],
+    background_color: u32,
+    overflow: Overflow,
+    max_level: LevelFilter,
+    next_row: AtomicUsize,
+}
+
+impl<F, G> Debug for FramebufferLogger<F, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FramebufferLogger")
+            .field("style", &self.style)
+            .field("size", &self.size)
+            .field("columns", &self.columns)
+            .field("rows", &self.rows)
+            .field("level_colors", &self.level_colors)
+            .field("background_color", &self.background_color)
+            .field("overflow", &self.overflow)
+            .field("max_level", &self.max_level)
+            .field("next_row", &self.next_row)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fn(usize, usize, u32)> FramebufferLogger<F> {
+    /// Creates a logger for a framebuffer of `width_px` x `height_px` pixels with the
+    /// built-in glyphs and a black background that logs all levels. Partial cells at
+    /// the right and bottom edges stay untouched.
+    pub const fn new(
+        width_px: usize,
+        height_px: usize,
+        style: FontWeight,
+        size: RasterHeight,
+        put_pixel: F,
+    ) -> Self {
+        Self {
+            put_pixel,
+            glyphs: NotoGlyphs,
+            style,
+            size,
+            columns: width_px / get_raster_width(style, size),
+            rows: height_px / size.val(),
+            level_colors: LEVEL_COLORS,
+            background_color: 0x000000,
+            overflow: Overflow::Wrap,
+            max_level: LevelFilter::Trace,
+            next_row: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<F: Fn(usize, usize, u32) + Copy> FramebufferLogger<F> {
+    /// Replaces the glyphs, e.g., with custom glyphs that fall back to the built-in
+    /// ones. See [`GlyphSource::chain`].
+    ///
+    /// This is `const`, so that a `static` logger can have custom glyphs. Therefore,
+    /// `put_pixel` must be `Copy`, such as a function pointer or a closure that
+    /// captures only references.
+    pub const fn with_glyphs<G: GlyphSource>(self, glyphs: G) -> FramebufferLogger<F, G> {
+        FramebufferLogger {
+            put_pixel: self.put_pixel,
+            glyphs,
+            style: self.style,
+            size: self.size,
+            columns: self.columns,
+            rows: self.rows,
+            level_colors: self.level_colors,
+            background_color: self.background_color,
+            overflow: self.overflow,
+            max_level: self.max_level,
+            next_row: self.next_row,
+        }
+    }
+}
+
+impl<F: Fn(usize, usize, u32), G: GlyphSource> FramebufferLogger<F, G> {
+    /// Sets the color of the records of `level` as `0x00RRGGBB`.
+    pub const fn with_level_color(mut self, level: Level, color: u32) -> Self {
+        self.level_colors[level as usize - 1] = color;
+        self
+    }
+
+    /// Sets the background color as `0x00RRGGBB`.
+    pub const fn with_background_color(mut self, background_color: u32) -> Self {
+        self.background_color = background_color;
+        self
+    }
+
+    /// Sets the behavior for lines that are wider than the screen.
+    pub const fn with_overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the most verbose level that is rendered. Note that the `log` crate
+    /// filters records with [`log::set_max_level`] before they reach the logger.
+    pub const fn with_max_level(mut self, max_level: LevelFilter) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Returns the number of characters per row.
+    #[inline]
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Fills the screen with the background color and continues the output in the
+    /// first row.
+    pub fn clear(&self) {
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                self.draw_char(column, row, ' ', self.background_color);
+            }
+        }
+        self.next_row.store(0, Ordering::Relaxed);
+    }
+
+    /// Reserves the next row for the calling writer.
+    fn reserve_row(&self) -> usize {
+        self.next_row.fetch_add(1, Ordering::Relaxed) % self.rows
+    }
+
+    fn draw_char(&self, column: usize, row: usize, c: char, color: u32) {
+        let raster = self
+            .glyphs
+            .glyph(c, self.style, self.size)
+            .or_else(|| self.glyphs.glyph(REPLACEMENT_CHAR, self.style, self.size));
+        let (cell_width, cell_height) = (get_raster_width(self.style, self.size), self.size.val());
+        let (left, top) = (column * cell_width, row * cell_height);
+        for y in 0..cell_height {
+            for x in 0..cell_width {
+                let intensity = raster.as_ref().map_or(0, |raster| raster.pixel(x, y));
+                let color = blend(self.background_color, color, intensity);
+                (self.put_pixel)(left + x, top + y, color);
+            }
+        }
+    }
+}
+
+impl<F, G> Log for FramebufferLogger<F, G>
+where
+    F: Fn(usize, usize, u32) + Send + Sync,
+    G: GlyphSource + Send + Sync,
+{
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= self.max_level
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) || self.columns == 0 || self.rows == 0 {
+            return;
+        }
+        let mut writer = LineWriter {
+            logger: self,
+            color: self.level_colors[record.level() as usize - 1],
+            row: self.reserve_row(),
+            column: 0,
+        };
+        // `LineWriter` never fails, only `Display` implementations of the arguments
+        let _ = write!(
+            writer,
+            "[{}] {}: {}",
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        writer.clear_line();
+    }
+
+    fn flush(&self) {}
+}
+
+/// Writes the characters of a record into the rows that it reserves.
+struct LineWriter<'a, F, G> {
+    logger: &'a FramebufferLogger<F, G>,
+    color: u32,
+    row: usize,
+    column: usize,
+}
+
+impl<F: Fn(usize, usize, u32), G: GlyphSource> LineWriter<'_, F, G> {
+    /// Fills the rest of the current row with the background color.
+    fn clear_line(&mut self) {
+        while self.column < self.logger.columns {
+            self.logger
+                .draw_char(self.column, self.row, ' ', self.color);
+            self.column += 1;
+        }
+    }
+
+    fn new_line(&mut self) {
+        self.clear_line();
+        self.row = self.logger.reserve_row();
+        self.column = 0;
+    }
+
+    fn put_char(&mut self, c: char) {
+        if c == '\n' {
+            self.new_line();
+            return;
+        }
+        for _ in 0..char_columns(c, self.column, TabWidth::default()) {
+            if self.column == self.logger.columns {
+                match self.logger.overflow {
+                    Overflow::Wrap => self.new_line(),
+                    Overflow::Truncate => return,
+                }
+            }
+            let c = if c == '\t' { ' ' } else { c };
+            self.logger.draw_char(self.column, self.row, c, self.color);
+            self.column += 1;
+        }
+    }
+}
+
+impl<F: Fn(usize, usize, u32), G: GlyphSource> Write for LineWriter<'_, F, G> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().for_each(|c| self.put_char(c));
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{Chain, RasterizedChar, get_raster};
+    use core::sync::atomic::AtomicU32;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+    const COLUMNS: usize = 12;
+    const ROWS: usize = 3;
+    const WIDTH: usize = COLUMNS * 7;
+    const HEIGHT: usize = ROWS * 16;
+    /// Color of pixels that were never drawn.
+    const UNTOUCHED: u32 = 0x123456;
+
+    struct Screen([AtomicU32; WIDTH * HEIGHT]);
+
+    impl Screen {
+        const fn new() -> Self {
+            Self([const { AtomicU32::new(UNTOUCHED) }; WIDTH * HEIGHT])
+        }
+
+        fn logger(
+            &self,
+        ) -> FramebufferLogger<impl Fn(usize, usize, u32) + Copy + Send + Sync + '_> {
+            FramebufferLogger::new(WIDTH, HEIGHT, WEIGHT, SIZE, |x, y, color| {
+                self.0[y * WIDTH + x].store(color, Ordering::Relaxed);
+            })
+        }
+
+        fn pixel(&self, x: usize, y: usize) -> u32 {
+            self.0[y * WIDTH + x].load(Ordering::Relaxed)
+        }
+
+        /// Returns true if the cell at `column` and `row` shows `c` in `color`.
+        fn has_char(&self, column: usize, row: usize, c: char, color: u32) -> bool {
+            let raster = get_raster(c, WEIGHT, SIZE).unwrap();
+            (0..16).all(|y| {
+                (0..7).all(|x| {
+                    self.pixel(column * 7 + x, row * 16 + y) == blend(0, color, raster.pixel(x, y))
+                })
+            })
+        }
+
+        fn has_str(&self, row: usize, s: &str, color: u32) -> bool {
+            s.chars()
+                .enumerate()
+                .all(|(column, c)| self.has_char(column, row, c, color))
+        }
+
+        fn is_untouched(&self, row: usize) -> bool {
+            (row * 16 * WIDTH..(row + 1) * 16 * WIDTH)
+                .all(|i| self.0[i].load(Ordering::Relaxed) == UNTOUCHED)
+        }
+    }
+
+    fn log(logger: &impl Log, level: Level, message: &str) {
+        logger.log(
+            &Record::builder()
+                .level(level)
+                .target("t")
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_log() {
+        let screen = Screen::new();
+        let logger = screen.logger().with_level_color(Level::Info, 0xffffff);
+        assert_eq!((logger.columns(), logger.rows()), (COLUMNS, ROWS));
+
+        log(&logger, Level::Info, "a");
+        assert!(screen.has_str(0, "[INFO] t: a ", 0xffffff));
+        assert!(screen.is_untouched(1));
+
+        log(&logger, Level::Error, "b\nc");
+        assert!(screen.has_str(1, "[ERROR] t: b", LEVEL_COLORS[0]));
+        assert!(screen.has_str(2, "c           ", LEVEL_COLORS[0]));
+
+        // continues in the first row
+        log(&logger, Level::Warn, "\u{E000}");
+        assert!(screen.has_str(0, "[WARN] t: ? ", LEVEL_COLORS[1]));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let screen = Screen::new();
+        let logger = screen.logger();
+        log(&logger, Level::Info, "wrapped\tx");
+        assert!(screen.has_str(0, "[INFO] t: wr", LEVEL_COLORS[2]));
+        assert!(screen.has_str(1, "apped   x   ", LEVEL_COLORS[2]));
+
+        let screen = Screen::new();
+        let logger = screen.logger().with_overflow(Overflow::Truncate);
+        log(&logger, Level::Info, "truncated\nnext");
+        assert!(screen.has_str(0, "[INFO] t: tr", LEVEL_COLORS[2]));
+        assert!(screen.has_str(1, "next        ", LEVEL_COLORS[2]));
+        assert!(screen.is_untouched(2));
+    }
+
+    #[test]
+    fn test_max_level() {
+        let screen = Screen::new();
+        let logger = screen.logger().with_max_level(LevelFilter::Info);
+        log(&logger, Level::Debug, "a");
+        assert!(screen.is_untouched(0));
+
+        logger.clear();
+        assert!((0..WIDTH * HEIGHT).all(|i| screen.0[i].load(Ordering::Relaxed) == 0));
+    }
+
+    #[test]
+    fn test_custom_glyphs() {
+        const ROW: &[u8] = &[255; 7];
+        const BLOCK: RasterizedChar = RasterizedChar::new(&[ROW; 16], 7);
+        type PutPixel = fn(usize, usize, u32);
+        type Icons = fn(char, FontWeight, RasterHeight) -> Option<RasterizedChar>;
+
+        fn icons(c: char, _: FontWeight, _: RasterHeight) -> Option<RasterizedChar> {
+            (c == '\u{E000}').then_some(BLOCK)
+        }
+
+        // custom glyphs are also available for static loggers
+        static LOGGER: FramebufferLogger<PutPixel, Chain<Icons, NotoGlyphs>> =
+            FramebufferLogger::new(WIDTH, HEIGHT, WEIGHT, SIZE, (|_, _, _| {}) as PutPixel)
+                .with_glyphs(Chain::new(icons as Icons, NotoGlyphs));
+
+        assert_eq!(LOGGER.columns(), COLUMNS);
+
+        let screen = Screen::new();
+        let logger = screen.logger().with_glyphs(icons.chain(NotoGlyphs));
+        log(&logger, Level::Info, "\u{E000}\u{E001}");
+        assert!(screen.has_str(0, "[INFO] t: ", LEVEL_COLORS[2]));
+        // the icon and the replacement char of the built-in glyphs
+        assert!((0..16).all(|y| (0..7).all(|x| screen.pixel(70 + x, y) == LEVEL_COLORS[2])));
+        assert!(screen.has_char(11, 0, '?', LEVEL_COLORS[2]));
+    }
+}
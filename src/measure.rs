@@ -0,0 +1,132 @@
+//! Module for measuring the pixel extent of text before it is drawn.
+
+use crate::{FontWeight, RasterHeight, get_raster_width};
+
+/// The distance between two tab stops, measured in columns (i.e., characters).
+///
+/// A `\t` advances the cursor to the next column that is a multiple of this
+/// value. A tab width of zero makes `\t` occupy no space at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabWidth(usize);
+
+impl TabWidth {
+    /// Creates a new tab width of `columns` characters.
+    #[inline]
+    pub const fn new(columns: usize) -> Self {
+        Self(columns)
+    }
+
+    /// Returns the number of columns between two tab stops.
+    #[inline]
+    pub const fn val(self) -> usize {
+        self.0
+    }
+}
+
+impl Default for TabWidth {
+    /// Four columns, as in most terminals and editors.
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+/// Returns the number of columns that `c` advances the cursor when it is printed
+/// at column `column`. `\n` must be handled by the caller.
+///
+/// Control characters (except `\t`) are never drawn, hence they occupy no
+/// column. Every other character occupies exactly one column, even if
+/// [`crate::get_raster`] doesn't know it (as renderers usually draw a fallback,
+/// such as `' '` or `'�'`, in that case) or if it is a wide character (as its
+/// raster is truncated to fulfill the mono font guarantee).
+#[inline]
+pub(crate) fn char_columns(c: char, column: usize, tab_width: TabWidth) -> usize {
+    match c {
+        '\t' if tab_width.val() == 0 => 0,
+        '\t' => tab_width.val() - column % tab_width.val(),
+        c if c.is_control() => 0,
+        _ => 1,
+    }
+}
+
+/// Measures the pixel extent of `s` if it is printed with the given [`FontWeight`] and
+/// [`RasterHeight`]. Returns a tuple of `(width_px, height_px, lines)`.
+///
+/// The width is the width of the widest line. Each `\n` starts a new line, i.e., a
+/// trailing `\n` results in an additional empty line. An empty string occupies zero
+/// lines. Tabs advance to the next tab stop according to `tab_width`. Control
+/// characters, such as `\r`, occupy no space. All other characters occupy one
+/// column, even if they are not supported by [`crate::get_raster`] or if they are
+/// wide characters, such as CJK symbols, which get truncated to the mono width.
+///
+/// This needs no allocations and no floating point operations.
+pub fn measure_str(
+    s: &str,
+    style: FontWeight,
+    size: RasterHeight,
+    tab_width: TabWidth,
+) -> (usize, usize, usize) {
+    if s.is_empty() {
+        return (0, 0, 0);
+    }
+
+    let mut lines = 1;
+    let mut max_columns = 0;
+    let mut column = 0;
+    for c in s.chars() {
+        if c == '\n' {
+            lines += 1;
+            column = 0;
+        } else {
+            column += char_columns(c, column, tab_width);
+            max_columns = max_columns.max(column);
+        }
+    }
+
+    (
+        max_columns * get_raster_width(style, size),
+        lines * size.val(),
+        lines,
+    )
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    fn measure(s: &str) -> (usize, usize, usize) {
+        measure_str(s, WEIGHT, SIZE, TabWidth::default())
+    }
+
+    #[test]
+    fn test_measure_str_lines() {
+        let w = get_raster_width(WEIGHT, SIZE);
+        assert_eq!(measure(""), (0, 0, 0));
+        assert_eq!(measure("abc"), (3 * w, 16, 1));
+        assert_eq!(measure("ab\ncdef\ng"), (4 * w, 3 * 16, 3));
+        assert_eq!(measure("\n"), (0, 2 * 16, 2));
+        assert_eq!(measure("a\r\n"), (w, 2 * 16, 2));
+    }
+
+    #[test]
+    fn test_measure_str_tabs() {
+        let w = get_raster_width(WEIGHT, SIZE);
+        assert_eq!(measure("\t"), (4 * w, 16, 1));
+        assert_eq!(measure("ab\tc"), (5 * w, 16, 1));
+        assert_eq!(measure("abcd\tc"), (9 * w, 16, 1));
+        assert_eq!(
+            measure_str("a\tb", WEIGHT, SIZE, TabWidth::new(0)),
+            (2 * w, 16, 1)
+        );
+    }
+
+    #[test]
+    fn test_measure_str_unsupported_and_wide_chars() {
+        let w = get_raster_width(WEIGHT, SIZE);
+        // neither symbol is part of the default unicode ranges
+        assert_eq!(measure("€字"), (2 * w, 16, 1));
+        assert_eq!(measure("a\x07b"), (2 * w, 16, 1));
+    }
+}
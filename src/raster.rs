@@ -0,0 +1,128 @@
+//! Module for the [`Raster`] abstraction that all glyph transformations build upon.
+
+use crate::RasterizedChar;
+use crate::decoration::{Decorated, DecorationMetrics, Decorations};
+use crate::rotate::{Mirror, Mirrored, Rotated, Rotation};
+use crate::scale::{Downscaled, ScaleMode, Upscaled};
+use crate::synthetic::{Emboldened, Thinned};
+
+/// Read access to a grid of pixel intensities, such as a [`RasterizedChar`].
+///
+/// Transformations, such as [`Raster::upscale`], don't copy any pixels but return
+/// a view that computes each pixel on the fly from the underlying raster. Hence,
+/// they need no allocations and can be chained.
+pub trait Raster {
+    /// Width of the raster in pixels.
+    fn width(&self) -> usize;
+
+    /// Height of the raster in pixels.
+    fn height(&self) -> usize;
+
+    /// Returns the intensity of the pixel from 0 to 255. Pixels outside the
+    /// raster have an intensity of zero.
+    fn pixel(&self, x: usize, y: usize) -> u8;
+
+    /// Returns a view that is `factor` times bigger in each dimension.
+    ///
+    /// # Panics
+    /// Panics if `factor` is zero.
+    fn upscale(self, factor: usize, mode: ScaleMode) -> Upscaled<Self>
+    where
+        Self: Sized,
+    {
+        Upscaled::new(self, factor, mode)
+    }
+
+    /// Returns a view that is `factor` times smaller in each dimension. Each pixel
+    /// is the average of a `factor` x `factor` box of the underlying raster.
+    ///
+    /// # Panics
+    /// Panics if `factor` is zero.
+    fn downscale(self, factor: usize) -> Downscaled<Self>
+    where
+        Self: Sized,
+    {
+        Downscaled::new(self, factor)
+    }
+
+    /// Returns a view that is rotated clockwise by `rotation`.
+    fn rotate(self, rotation: Rotation) -> Rotated<Self>
+    where
+        Self: Sized,
+    {
+        Rotated::new(self, rotation)
+    }
+
+    /// Returns a view that is mirrored along `mirror`.
+    fn mirror(self, mirror: Mirror) -> Mirrored<Self>
+    where
+        Self: Sized,
+    {
+        Mirrored::new(self, mirror)
+    }
+
+    /// Returns a view with the given decorations, such as an underline, composited
+    /// onto the raster. Get the `metrics` from [`crate::get_decoration_metrics`].
+    fn decorate(self, decorations: Decorations, metrics: DecorationMetrics) -> Decorated<Self>
+    where
+        Self: Sized,
+    {
+        Decorated::new(self, decorations, metrics)
+    }
+
+    /// Returns a view with thicker stems that approximates a bolder font weight.
+    /// An `amount` of 255 thickens stems by one pixel.
+    fn embolden(self, amount: u8) -> Emboldened<Self>
+    where
+        Self: Sized,
+    {
+        Emboldened::new(self, amount)
+    }
+
+    /// Returns a view with thinner stems that approximates a lighter font weight.
+    /// An `amount` of 255 thins stems by one pixel.
+    fn thin(self, amount: u8) -> Thinned<Self>
+    where
+        Self: Sized,
+    {
+        Thinned::new(self, amount)
+    }
+}
+
+impl Raster for RasterizedChar {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.raster
+            .get(y)
+            .and_then(|row| row.get(x))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl<R: Raster + ?Sized> Raster for &R {
+    #[inline]
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        (**self).height()
+    }
+
+    #[inline]
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        (**self).pixel(x, y)
+    }
+}
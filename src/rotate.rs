@@ -0,0 +1,362 @@
+//! Module for rotated and mirrored glyphs, e.g., for physically rotated displays
+//! whose framebuffer can't be rotated by the hardware.
+
+use crate::measure::char_columns;
+use crate::{
+    FontWeight, GlyphSource, Raster, RasterHeight, TabWidth, get_raster_width, measure_str,
+};
+
+/// Clockwise rotation in steps of 90 degrees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    Deg0,
+    /// Rotated by 90 degrees clockwise. Text runs from top to bottom.
+    Deg90,
+    /// Rotated by 180 degrees. Text runs from right to left and is upside down.
+    Deg180,
+    /// Rotated by 270 degrees clockwise. Text runs from bottom to top.
+    Deg270,
+}
+
+impl Rotation {
+    /// Maps the coordinate of a pixel in a box of `width` x `height` pixels to the
+    /// coordinate of the pixel in the rotated box.
+    const fn map(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Self::Deg0 => (x, y),
+            Self::Deg90 => (height - 1 - y, x),
+            Self::Deg180 => (width - 1 - x, height - 1 - y),
+            Self::Deg270 => (y, width - 1 - x),
+        }
+    }
+
+    /// Returns true if width and height are swapped by the rotation.
+    const fn swaps_axes(self) -> bool {
+        matches!(self, Self::Deg90 | Self::Deg270)
+    }
+}
+
+/// Axis along which a raster is mirrored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    /// Left and right are swapped.
+    Horizontal,
+    /// Top and bottom are swapped.
+    Vertical,
+}
+
+/// View of a [`Raster`] that is rotated clockwise. Created by [`Raster::rotate`].
+#[derive(Debug, Clone, Copy)]
+pub struct Rotated<R> {
+    raster: R,
+    rotation: Rotation,
+}
+
+impl<R: Raster> Rotated<R> {
+    /// Creates a new view that is rotated by `rotation`.
+    pub const fn new(raster: R, rotation: Rotation) -> Self {
+        Self { raster, rotation }
+    }
+}
+
+impl<R: Raster> Raster for Rotated<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        if self.rotation.swaps_axes() {
+            self.raster.height()
+        } else {
+            self.raster.width()
+        }
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        if self.rotation.swaps_axes() {
+            self.raster.width()
+        } else {
+            self.raster.height()
+        }
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        // Rotating the rotated coordinate back yields the source coordinate.
+        let inverse = match self.rotation {
+            Rotation::Deg90 => Rotation::Deg270,
+            Rotation::Deg270 => Rotation::Deg90,
+            rotation => rotation,
+        };
+        let (x, y) = inverse.map(x, y, self.width(), self.height());
+        self.raster.pixel(x, y)
+    }
+}
+
+/// View of a [`Raster`] that is mirrored. Created by [`Raster::mirror`].
+#[derive(Debug, Clone, Copy)]
+pub struct Mirrored<R> {
+    raster: R,
+    mirror: Mirror,
+}
+
+impl<R: Raster> Mirrored<R> {
+    /// Creates a new view that is mirrored along `mirror`.
+    pub const fn new(raster: R, mirror: Mirror) -> Self {
+        Self { raster, mirror }
+    }
+}
+
+impl<R: Raster> Raster for Mirrored<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        match self.mirror {
+            Mirror::Horizontal => self.raster.pixel(self.width() - 1 - x, y),
+            Mirror::Vertical => self.raster.pixel(x, self.height() - 1 - y),
+        }
+    }
+}
+
+/// Draws `s` with the glyphs of `glyphs` rotated by `rotation` by calling
+/// `put_pixel(x, y, intensity)` for each pixel of each glyph. Returns the width and
+/// height in pixels of the rotated text.
+///
+/// The coordinates are relative to the top-left corner of the bounding box of the
+/// rotated text, i.e., the caller adds the position on the screen. The text is laid
+/// out as described in [`measure_str`] with the default [`TabWidth`] and then
+/// rotated as a whole. Hence, for [`Rotation::Deg90`], the first character is at
+/// the top and the first line is on the right. Characters that are not supported
+/// by `glyphs` are not drawn but occupy their column. Control characters other than
+/// `\t`, such as `\r`, occupy no column and are never drawn, even if `glyphs` has a
+/// glyph for them. A tab with a glyph is drawn in the first column of its tab stop.
+/// Use [`NotoGlyphs`](crate::NotoGlyphs) for the built-in glyphs.
+///
+/// This needs no allocations and no floating point operations.
+pub fn draw_str_rotated(
+    s: &str,
+    glyphs: &impl GlyphSource,
+    style: FontWeight,
+    size: RasterHeight,
+    rotation: Rotation,
+    mut put_pixel: impl FnMut(usize, usize, u8),
+) -> (usize, usize) {
+    let tab_width = TabWidth::default();
+    let (width, height, _) = measure_str(s, style, size, tab_width);
+    let (cell_width, cell_height) = (get_raster_width(style, size), size.val());
+
+    for (line_i, line) in s.split('\n').enumerate() {
+        let mut column = 0;
+        for c in line.chars() {
+            let columns = char_columns(c, column, tab_width);
+            // zero-width control chars are skipped, even if `glyphs` has a glyph for them
+            if columns == 0 {
+                continue;
+            }
+            if let Some(raster) = glyphs.glyph(c, style, size) {
+                let x_offset = column * cell_width;
+                let y_offset = line_i * cell_height;
+                // custom glyphs that are bigger than the cell are clipped
+                for row_i in 0..raster.height().min(cell_height) {
+                    for col_i in 0..raster.width().min(cell_width) {
+                        let (x, y) =
+                            rotation.map(x_offset + col_i, y_offset + row_i, width, height);
+                        put_pixel(x, y, raster.pixel(col_i, row_i));
+                    }
+                }
+            }
+            column += columns;
+        }
+    }
+
+    if rotation.swaps_axes() {
+        (height, width)
+    } else {
+        (width, height)
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{NotoGlyphs, RasterizedChar, get_raster};
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    const fn raster(c: char) -> RasterizedChar {
+        get_raster(c, WEIGHT, SIZE).unwrap()
+    }
+
+    fn assert_raster_eq(a: impl Raster, b: impl Raster) {
+        assert_eq!(a.width(), b.width());
+        assert_eq!(a.height(), b.height());
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                assert_eq!(a.pixel(x, y), b.pixel(x, y), "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate() {
+        let a = raster('A');
+        let rotated = a.rotate(Rotation::Deg90);
+        assert_eq!((rotated.width(), rotated.height()), (a.height(), a.width()));
+        // top-left becomes top-right
+        assert_eq!(rotated.pixel(a.height() - 1, 0), a.pixel(0, 0));
+        // bottom-left becomes top-left
+        assert_eq!(rotated.pixel(0, 0), a.pixel(0, a.height() - 1));
+        // top-right becomes bottom-right
+        assert_eq!(
+            rotated.pixel(a.height() - 1, a.width() - 1),
+            a.pixel(a.width() - 1, 0)
+        );
+
+        assert_raster_eq(a.rotate(Rotation::Deg0), a);
+        assert_raster_eq(
+            a.rotate(Rotation::Deg90).rotate(Rotation::Deg90),
+            a.rotate(Rotation::Deg180),
+        );
+        assert_raster_eq(
+            a.rotate(Rotation::Deg180).rotate(Rotation::Deg90),
+            a.rotate(Rotation::Deg270),
+        );
+        assert_raster_eq(a.rotate(Rotation::Deg270).rotate(Rotation::Deg90), a);
+    }
+
+    #[test]
+    fn test_mirror() {
+        let a = raster('A');
+        assert_raster_eq(
+            a.mirror(Mirror::Horizontal).mirror(Mirror::Vertical),
+            a.rotate(Rotation::Deg180),
+        );
+        assert_raster_eq(a.mirror(Mirror::Vertical).mirror(Mirror::Vertical), a);
+        assert_eq!(a.mirror(Mirror::Horizontal).pixel(0, 10), a.pixel(6, 10));
+    }
+
+    #[test]
+    fn test_draw_str_rotated() {
+        let w = get_raster_width(WEIGHT, SIZE);
+        let mut buffer = [[0_u8; 2 * 7]; 16];
+        assert_eq!(w, buffer[0].len() / 2);
+
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            let mut rotated_buffer = [[0_u8; 16]; 2 * 7];
+            let (width, height) =
+                draw_str_rotated("Ab", &NotoGlyphs, WEIGHT, SIZE, rotation, |x, y, i| {
+                    if rotation.swaps_axes() {
+                        rotated_buffer[y][x] = i;
+                    } else {
+                        buffer[y][x] = i;
+                    }
+                });
+
+            let expected = match rotation {
+                Rotation::Deg0 | Rotation::Deg180 => (2 * w, 16),
+                Rotation::Deg90 | Rotation::Deg270 => (16, 2 * w),
+            };
+            assert_eq!((width, height), expected);
+
+            for y in 0..16 {
+                for x in 0..2 * w {
+                    let c = if x < w { raster('A') } else { raster('b') };
+                    let (rx, ry) = rotation.map(x, y, 2 * w, 16);
+                    let actual = if rotation.swaps_axes() {
+                        rotated_buffer[ry][rx]
+                    } else {
+                        buffer[ry][rx]
+                    };
+                    assert_eq!(actual, c.pixel(x % w, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_draw_str_rotated_custom_glyphs() {
+        // bigger than the cell of 7 x 16 pixels
+        const ROW: &[u8] = &[9; 8];
+        const WIDE: RasterizedChar = RasterizedChar::new(&[ROW; 17], 8);
+        let icons = |c, _, _| (c == '\u{E000}').then_some(WIDE);
+        let glyphs = icons.chain(NotoGlyphs);
+
+        let mut buffer = [[0_u8; 2 * 7]; 16];
+        let (width, height) = draw_str_rotated(
+            "\u{E000}A",
+            &glyphs,
+            WEIGHT,
+            SIZE,
+            Rotation::Deg0,
+            |x, y, i| {
+                buffer[y][x] = i;
+            },
+        );
+        assert_eq!((width, height), (2 * 7, 16));
+        for (y, row) in buffer.iter().enumerate() {
+            assert_eq!(row[..7], [9; 7]);
+            for (x, intensity) in row[7..].iter().enumerate() {
+                assert_eq!(*intensity, raster('A').pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_draw_str_rotated_control_glyphs() {
+        const ROW: &[u8] = &[9; 7];
+        const BLOCK: RasterizedChar = RasterizedChar::new(&[ROW; 16], 7);
+        let controls = |c: char, _, _| c.is_control().then_some(BLOCK);
+        let glyphs = controls.chain(NotoGlyphs);
+
+        // the tab spans 3 columns, the trailing '\r' none
+        let (w, h) = (7 * 5, 16);
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            let mut pixels = 0;
+            let size = draw_str_rotated("a\tb\r", &glyphs, WEIGHT, SIZE, rotation, |x, y, _| {
+                let (width, height) = if rotation.swaps_axes() {
+                    (h, w)
+                } else {
+                    (w, h)
+                };
+                assert!(x < width && y < height, "pixel ({x}, {y}) out of bounds");
+                pixels += 1;
+            });
+            assert_eq!(
+                size,
+                if rotation.swaps_axes() {
+                    (h, w)
+                } else {
+                    (w, h)
+                }
+            );
+            // 'a', the tab in its first column, and 'b', but not the '\r'
+            assert_eq!(pixels, 3 * 7 * 16);
+        }
+    }
+}
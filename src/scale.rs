@@ -0,0 +1,217 @@
+//! Module for scaling rasters by integer factors at runtime without floating point
+//! operations.
+
+use crate::Raster;
+
+/// Interpolation method used by [`Upscaled`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScaleMode {
+    /// Each pixel becomes a `factor` x `factor` block. Fast, but the result
+    /// looks blocky.
+    #[default]
+    NearestNeighbor,
+    /// Pixels are interpolated between the four nearest pixels of the underlying
+    /// raster with integer arithmetic. The result looks smoother.
+    Bilinear,
+}
+
+/// View of a [`Raster`] that is upscaled by an integer factor.
+/// Created by [`Raster::upscale`].
+#[derive(Debug, Clone, Copy)]
+pub struct Upscaled<R> {
+    raster: R,
+    factor: usize,
+    mode: ScaleMode,
+}
+
+impl<R: Raster> Upscaled<R> {
+    /// Creates a new view that is `factor` times bigger in each dimension.
+    ///
+    /// # Panics
+    /// Panics if `factor` is zero.
+    pub fn new(raster: R, factor: usize, mode: ScaleMode) -> Self {
+        assert!(factor > 0, "the scale factor must not be zero");
+        Self {
+            raster,
+            factor,
+            mode,
+        }
+    }
+
+    /// Maps the coordinate `pos` of the scaled raster to the coordinate of the
+    /// underlying raster with `len` pixels. Returns the two neighboring source
+    /// pixels and the weight of the second one in `1 / (2 * factor)` units.
+    ///
+    /// This corresponds to `(pos + 0.5) / factor - 0.5`, i.e., the centers of the
+    /// pixels are aligned.
+    fn source_coordinates(&self, pos: usize, len: usize) -> (usize, usize, usize) {
+        let denominator = 2 * self.factor;
+        let numerator = (2 * pos + 1) as isize - self.factor as isize;
+        let lower = numerator.div_euclid(denominator as isize);
+        let weight = numerator.rem_euclid(denominator as isize) as usize;
+
+        let clamp = |i: isize| i.clamp(0, len.saturating_sub(1) as isize) as usize;
+        (clamp(lower), clamp(lower + 1), weight)
+    }
+}
+
+impl<R: Raster> Raster for Upscaled<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width() * self.factor
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height() * self.factor
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        match self.mode {
+            ScaleMode::NearestNeighbor => self.raster.pixel(x / self.factor, y / self.factor),
+            ScaleMode::Bilinear => {
+                let (x0, x1, wx) = self.source_coordinates(x, self.raster.width());
+                let (y0, y1, wy) = self.source_coordinates(y, self.raster.height());
+                let d = 2 * self.factor;
+
+                let top = self.raster.pixel(x0, y0) as usize * (d - wx)
+                    + self.raster.pixel(x1, y0) as usize * wx;
+                let bottom = self.raster.pixel(x0, y1) as usize * (d - wx)
+                    + self.raster.pixel(x1, y1) as usize * wx;
+                let sum = top * (d - wy) + bottom * wy;
+
+                // rounded division
+                ((sum + d * d / 2) / (d * d)) as u8
+            }
+        }
+    }
+}
+
+/// View of a [`Raster`] that is downscaled by an integer factor with box averaging.
+/// Created by [`Raster::downscale`].
+///
+/// If the dimensions of the underlying raster are not a multiple of the factor,
+/// the last row and column average only the remaining pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct Downscaled<R> {
+    raster: R,
+    factor: usize,
+}
+
+impl<R: Raster> Downscaled<R> {
+    /// Creates a new view that is `factor` times smaller in each dimension.
+    ///
+    /// # Panics
+    /// Panics if `factor` is zero.
+    pub fn new(raster: R, factor: usize) -> Self {
+        assert!(factor > 0, "the scale factor must not be zero");
+        Self { raster, factor }
+    }
+}
+
+impl<R: Raster> Raster for Downscaled<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width().div_ceil(self.factor)
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height().div_ceil(self.factor)
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        let x_begin = x * self.factor;
+        let x_end = (x_begin + self.factor).min(self.raster.width());
+        let y_begin = y * self.factor;
+        let y_end = (y_begin + self.factor).min(self.raster.height());
+
+        let sum = (y_begin..y_end)
+            .flat_map(|y| (x_begin..x_end).map(move |x| (x, y)))
+            .map(|(x, y)| self.raster.pixel(x, y) as usize)
+            .sum::<usize>();
+        let count = (x_end - x_begin) * (y_end - y_begin);
+
+        // rounded division
+        ((sum + count / 2) / count) as u8
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{FontWeight, RasterHeight, get_raster};
+
+    const fn raster_a() -> crate::RasterizedChar {
+        get_raster('A', FontWeight::Regular, RasterHeight::Size16).unwrap()
+    }
+
+    #[test]
+    fn test_upscale_nearest_neighbor() {
+        let a = raster_a();
+        let scaled = a.upscale(3, ScaleMode::NearestNeighbor);
+        assert_eq!(scaled.width(), a.width() * 3);
+        assert_eq!(scaled.height(), a.height() * 3);
+        for y in 0..scaled.height() {
+            for x in 0..scaled.width() {
+                assert_eq!(scaled.pixel(x, y), a.pixel(x / 3, y / 3));
+            }
+        }
+        assert_eq!(scaled.pixel(scaled.width(), 0), 0);
+    }
+
+    #[test]
+    fn test_upscale_bilinear() {
+        let a = raster_a();
+        let scaled = a.upscale(2, ScaleMode::Bilinear);
+        // interpolated pixels stay within the intensities of their neighbors
+        for y in 0..scaled.height() {
+            for x in 0..scaled.width() {
+                let neighbors = [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(dx, dy)| {
+                    let sx = ((x / 2 + dx) as isize - 1 + (x % 2) as isize).max(0) as usize;
+                    let sy = ((y / 2 + dy) as isize - 1 + (y % 2) as isize).max(0) as usize;
+                    a.pixel(sx.min(a.width() - 1), sy.min(a.height() - 1))
+                });
+                let pixel = scaled.pixel(x, y);
+                assert!(pixel >= *neighbors.iter().min().unwrap());
+                assert!(pixel <= *neighbors.iter().max().unwrap());
+            }
+        }
+
+        // a factor of one is the identity
+        let scaled = a.upscale(1, ScaleMode::Bilinear);
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                assert_eq!(scaled.pixel(x, y), a.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_downscale() {
+        let a = raster_a();
+        let roundtrip = a.upscale(4, ScaleMode::NearestNeighbor).downscale(4);
+        assert_eq!(roundtrip.width(), a.width());
+        assert_eq!(roundtrip.height(), a.height());
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                assert_eq!(roundtrip.pixel(x, y), a.pixel(x, y));
+            }
+        }
+
+        // width 7 is not a multiple of 2
+        let scaled = a.downscale(2);
+        assert_eq!(scaled.width(), 4);
+        assert_eq!(scaled.height(), 8);
+        let expected = (a.pixel(6, 8) as usize + a.pixel(6, 9) as usize).div_ceil(2);
+        assert_eq!(scaled.pixel(3, 4) as usize, expected);
+    }
+}
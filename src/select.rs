@@ -0,0 +1,333 @@
+//! Module for selecting font weights and raster heights at runtime, e.g., from a
+//! kernel command line option such as `font=bold:24` or from the resolution of a
+//! framebuffer.
+//!
+//! All parsers are `const`, hence, they also work for compile time configuration.
+
+use crate::{FontWeight, RasterHeight, get_raster_width};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Errors of the parsers for [`FontWeight`], [`RasterHeight`], and [`FontSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFontError {
+    /// The font weight is unknown or not enabled by a Cargo feature.
+    UnknownFontWeight,
+    /// The raster height is unknown or not enabled by a Cargo feature.
+    UnknownRasterHeight,
+    /// The font specification is not of the form `<weight>:<height>`.
+    MissingSeparator,
+}
+
+impl Display for ParseFontError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::UnknownFontWeight => "unknown or disabled font weight",
+            Self::UnknownRasterHeight => "unknown or disabled raster height",
+            Self::MissingSeparator => "expected '<weight>:<height>'",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for ParseFontError {}
+
+/// Compares two ASCII strings case-insensitively in a `const` context.
+const fn eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Parses a decimal number without sign in a `const` context.
+const fn parse_usize(bytes: &[u8]) -> Option<usize> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let mut value = 0_usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b @ b'0'..=b'9' => (b - b'0') as usize,
+            _ => return None,
+        };
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add(digit) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(value)
+}
+
+impl FontWeight {
+    /// Returns the enabled font weight with the given name, such as `"bold"`. See
+    /// [`FontWeight::name`]. The name is case-insensitive.
+    #[inline]
+    pub const fn from_name(name: &str) -> Option<Self> {
+        Self::from_name_bytes(name.as_bytes())
+    }
+
+    const fn from_name_bytes(name: &[u8]) -> Option<Self> {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            if eq_ignore_ascii_case(Self::ALL[i].name().as_bytes(), name) {
+                return Some(Self::ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+impl FromStr for FontWeight {
+    type Err = ParseFontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(ParseFontError::UnknownFontWeight)
+    }
+}
+
+/// Converts the numeric value of the enum variant. See [`FontWeight::val`].
+impl TryFrom<usize> for FontWeight {
+    type Error = ParseFontError;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|weight| weight.val() == value)
+            .ok_or(ParseFontError::UnknownFontWeight)
+    }
+}
+
+impl RasterHeight {
+    /// Returns the enabled raster height of exactly `px` pixels.
+    #[inline]
+    pub const fn new(px: usize) -> Option<Self> {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            if Self::ALL[i].val() == px {
+                return Some(Self::ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    const fn from_decimal_bytes(px: &[u8]) -> Option<Self> {
+        match parse_usize(px) {
+            Some(px) => Self::new(px),
+            None => None,
+        }
+    }
+
+    /// Returns the enabled raster height that is the closest to `px` pixels. On a
+    /// tie, the smaller one wins.
+    ///
+    /// # Panics
+    /// Panics if no raster height is enabled.
+    pub const fn closest(px: usize) -> Self {
+        let mut closest = Self::ALL[0];
+        let mut i = 1;
+        while i < Self::ALL.len() {
+            if Self::ALL[i].val().abs_diff(px) < closest.val().abs_diff(px) {
+                closest = Self::ALL[i];
+            }
+            i += 1;
+        }
+        closest
+    }
+
+    /// Returns the largest enabled raster height whose characters of the given
+    /// [`FontWeight`] fit at least `columns` x `rows` times on a framebuffer of
+    /// `width_px` x `height_px` pixels. Returns `None`, if not even the smallest
+    /// one fits.
+    pub const fn largest_for_grid(
+        style: FontWeight,
+        columns: usize,
+        rows: usize,
+        width_px: usize,
+        height_px: usize,
+    ) -> Option<Self> {
+        let mut i = Self::ALL.len();
+        while i > 0 {
+            i -= 1;
+            let size = Self::ALL[i];
+            if get_raster_width(style, size) * columns <= width_px && size.val() * rows <= height_px
+            {
+                return Some(size);
+            }
+        }
+        None
+    }
+}
+
+/// Parses a raster height in pixels, such as `"24"`. See [`RasterHeight::new`].
+impl FromStr for RasterHeight {
+    type Err = ParseFontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_decimal_bytes(s.as_bytes()).ok_or(ParseFontError::UnknownRasterHeight)
+    }
+}
+
+/// Converts a raster height in pixels. See [`RasterHeight::new`].
+impl TryFrom<usize> for RasterHeight {
+    type Error = ParseFontError;
+
+    fn try_from(px: usize) -> Result<Self, Self::Error> {
+        Self::new(px).ok_or(ParseFontError::UnknownRasterHeight)
+    }
+}
+
+/// A combination of [`FontWeight`] and [`RasterHeight`], as it is usually passed in
+/// configurations, e.g., on a kernel command line as `font=bold:24`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontSpec {
+    /// The font weight.
+    pub weight: FontWeight,
+    /// The raster height.
+    pub height: RasterHeight,
+}
+
+impl FontSpec {
+    /// Parses a font specification of the form `<weight>:<height>`, such as
+    /// `"bold:24"`. The font weight is case-insensitive.
+    pub const fn parse(s: &str) -> Result<Self, ParseFontError> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() && bytes[i] != b':' {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return Err(ParseFontError::MissingSeparator);
+        }
+        let (weight, height) = bytes.split_at(i);
+        let (_, height) = height.split_at(1);
+
+        let Some(weight) = FontWeight::from_name_bytes(weight) else {
+            return Err(ParseFontError::UnknownFontWeight);
+        };
+        match RasterHeight::from_decimal_bytes(height) {
+            Some(height) => Ok(Self { weight, height }),
+            None => Err(ParseFontError::UnknownRasterHeight),
+        }
+    }
+}
+
+impl FromStr for FontSpec {
+    type Err = ParseFontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Formats the font specification as `<weight>:<height>`, such as `bold:24`.
+impl Display for FontSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.weight.name(), self.height.val())
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_weight() {
+        assert_eq!(FontWeight::from_name("regular"), Some(FontWeight::Regular));
+        assert_eq!("Regular".parse(), Ok(FontWeight::Regular));
+        assert_eq!(
+            "heavy".parse::<FontWeight>(),
+            Err(ParseFontError::UnknownFontWeight)
+        );
+        for &weight in FontWeight::ALL {
+            assert_eq!(FontWeight::from_name(weight.name()), Some(weight));
+            assert_eq!(FontWeight::try_from(weight.val()), Ok(weight));
+        }
+    }
+
+    #[test]
+    fn test_raster_height() {
+        assert_eq!(RasterHeight::new(16), Some(RasterHeight::Size16));
+        assert_eq!(RasterHeight::new(17), None);
+        assert_eq!("16".parse(), Ok(RasterHeight::Size16));
+        assert_eq!(RasterHeight::try_from(16), Ok(RasterHeight::Size16));
+        assert_eq!(
+            "+16".parse::<RasterHeight>(),
+            Err(ParseFontError::UnknownRasterHeight)
+        );
+        assert_eq!(RasterHeight::closest(0), RasterHeight::ALL[0]);
+        assert_eq!(RasterHeight::closest(16), RasterHeight::Size16);
+        assert_eq!(
+            RasterHeight::closest(usize::MAX),
+            *RasterHeight::ALL.last().unwrap()
+        );
+        for &height in RasterHeight::ALL {
+            assert_eq!(RasterHeight::new(height.val()), Some(height));
+        }
+    }
+
+    #[test]
+    fn test_largest_for_grid() {
+        let style = FontWeight::Regular;
+        let width = get_raster_width(style, RasterHeight::Size16);
+        assert_eq!(
+            RasterHeight::largest_for_grid(style, 80, 25, 80 * width, 25 * 16),
+            Some(RasterHeight::Size16)
+        );
+        assert_eq!(
+            RasterHeight::largest_for_grid(style, 80, 25, 80 * width - 1, 25 * 16),
+            None
+        );
+        let largest = *RasterHeight::ALL.last().unwrap();
+        assert_eq!(
+            RasterHeight::largest_for_grid(style, 1, 1, 1000, 1000),
+            Some(largest)
+        );
+    }
+
+    #[test]
+    fn test_font_spec() {
+        const SPEC: Result<FontSpec, ParseFontError> = FontSpec::parse("regular:16");
+        let spec = FontSpec {
+            weight: FontWeight::Regular,
+            height: RasterHeight::Size16,
+        };
+        assert_eq!(SPEC, Ok(spec));
+        assert_eq!("REGULAR:16".parse(), Ok(spec));
+        assert_eq!(
+            FontSpec::parse("regular"),
+            Err(ParseFontError::MissingSeparator)
+        );
+        assert_eq!(
+            FontSpec::parse("heavy:16"),
+            Err(ParseFontError::UnknownFontWeight)
+        );
+        assert_eq!(
+            FontSpec::parse("regular:17"),
+            Err(ParseFontError::UnknownRasterHeight)
+        );
+        assert_eq!(
+            FontSpec::parse("regular:"),
+            Err(ParseFontError::UnknownRasterHeight)
+        );
+        assert_eq!(
+            FontSpec::parse("regular:99999999999999999999999"),
+            Err(ParseFontError::UnknownRasterHeight)
+        );
+    }
+}
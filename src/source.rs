@@ -0,0 +1,129 @@
+//! Module for the [`GlyphSource`] abstraction, which lets users mix their own glyphs,
+//! such as logos or icons in the private-use area, into the text of the renderers
+//! of this crate.
+
+use crate::{FontWeight, RasterHeight, RasterizedChar, get_raster};
+
+/// Provides the raster of a char for a [`FontWeight`] and a [`RasterHeight`].
+///
+/// [`NotoGlyphs`] provides the built-in glyphs of this crate. Custom glyphs are
+/// usually consulted first and fall back to the built-in ones, see
+/// [`GlyphSource::chain`]:
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{FontWeight, GlyphSource, NotoGlyphs, RasterHeight, RasterizedChar};
+///
+/// const LOGO: RasterizedChar = RasterizedChar::new(&[&[255, 0], &[0, 255]], 2);
+///
+/// let icons = |c, _style, _size| (c == '\u{E000}').then_some(LOGO);
+/// let glyphs = icons.chain(NotoGlyphs);
+/// assert!(glyphs.glyph('\u{E000}', FontWeight::Regular, RasterHeight::Size16).is_some());
+/// assert!(glyphs.glyph('A', FontWeight::Regular, RasterHeight::Size16).is_some());
+/// ```
+///
+/// Renderers lay out text in a grid of cells of
+/// [`get_raster_width`](crate::get_raster_width) x [`RasterHeight::val`] pixels.
+/// Custom glyphs should have the same dimensions; bigger ones are clipped.
+pub trait GlyphSource {
+    /// Returns the raster of `c` or `None`, if `c` is not supported.
+    fn glyph(&self, c: char, style: FontWeight, size: RasterHeight) -> Option<RasterizedChar>;
+
+    /// Returns a source that consults `self` first and `fallback` second.
+    fn chain<S: GlyphSource>(self, fallback: S) -> Chain<Self, S>
+    where
+        Self: Sized,
+    {
+        Chain::new(self, fallback)
+    }
+}
+
+/// The built-in glyphs of the Noto Sans Mono font, i.e., [`get_raster`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NotoGlyphs;
+
+impl GlyphSource for NotoGlyphs {
+    #[inline]
+    fn glyph(&self, c: char, style: FontWeight, size: RasterHeight) -> Option<RasterizedChar> {
+        get_raster(c, style, size)
+    }
+}
+
+/// Custom glyphs from a function or closure, such as a `match` over a few icons.
+impl<F> GlyphSource for F
+where
+    F: Fn(char, FontWeight, RasterHeight) -> Option<RasterizedChar>,
+{
+    #[inline]
+    fn glyph(&self, c: char, style: FontWeight, size: RasterHeight) -> Option<RasterizedChar> {
+        self(c, style, size)
+    }
+}
+
+/// Consults the first source and falls back to the second one. Created by
+/// [`GlyphSource::chain`].
+#[derive(Debug, Clone, Copy)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: GlyphSource, B: GlyphSource> Chain<A, B> {
+    /// Creates a source that consults `first` first and `second` second.
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: GlyphSource, B: GlyphSource> GlyphSource for Chain<A, B> {
+    #[inline]
+    fn glyph(&self, c: char, style: FontWeight, size: RasterHeight) -> Option<RasterizedChar> {
+        self.first
+            .glyph(c, style, size)
+            .or_else(|| self.second.glyph(c, style, size))
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::Raster;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    const ICON: RasterizedChar = RasterizedChar::new(&[&[1, 2], &[3, 4], &[5, 6]], 2);
+
+    fn icons(c: char, _style: FontWeight, _size: RasterHeight) -> Option<RasterizedChar> {
+        matches!(c, '\u{E000}' | 'A').then_some(ICON)
+    }
+
+    #[test]
+    fn test_rasterized_char_new() {
+        assert_eq!((ICON.width(), ICON.height()), (2, 3));
+        assert_eq!(ICON.pixel(1, 2), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rasterized_char_new_invalid() {
+        let _ = RasterizedChar::new(&[&[1, 2], &[3]], 2);
+    }
+
+    #[test]
+    fn test_chain() {
+        let glyphs = icons.chain(NotoGlyphs);
+        assert_eq!(
+            glyphs.glyph('\u{E000}', WEIGHT, SIZE).unwrap().pixel(0, 0),
+            1
+        );
+        // the first source wins
+        assert_eq!(glyphs.glyph('A', WEIGHT, SIZE).unwrap().width(), 2);
+        // the second source is the fallback
+        let b = glyphs.glyph('B', WEIGHT, SIZE).unwrap();
+        assert_eq!(b.raster(), get_raster('B', WEIGHT, SIZE).unwrap().raster());
+        assert!(glyphs.glyph('\u{E001}', WEIGHT, SIZE).is_none());
+
+        let glyphs = NotoGlyphs.chain(icons);
+        assert_eq!(glyphs.glyph('A', WEIGHT, SIZE).unwrap().height(), 16);
+    }
+}
@@ -0,0 +1,155 @@
+//! Module for LCD subpixel rendering (ClearType-style) of characters, which visibly
+//! sharpens small text on LCD panels.
+
+/// Order of the subpixels of each pixel of an LCD panel, from left to right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubpixelOrder {
+    /// Red, green, blue. This is the most common order.
+    #[default]
+    Rgb,
+    /// Blue, green, red.
+    Bgr,
+}
+
+/// Weights of the 5-tap FIR filter that distributes the coverage of each subpixel to
+/// its neighbors to reduce color fringes. These are the weights of the default LCD
+/// filter of FreeType. They add up to 256, hence, the division is a shift.
+const FILTER: [u32; 5] = [8, 77, 86, 77, 8];
+
+/// Describes a char that is rasterized for LCD subpixel rendering.
+///
+/// Get it with [`crate::get_raster_subpixel`]. Each pixel consists of three
+/// subpixels. The raster stores their unfiltered coverage. [`SubpixelChar::pixel`]
+/// filters it with integer operations only, so that this works in "soft-float"
+/// environments.
+#[derive(Debug, Clone, Copy)]
+pub struct SubpixelChar {
+    /// The unfiltered coverage of the subpixels. `height` rows of `3 * width` bytes.
+    pub(crate) raster: &'static [&'static [u8]],
+    /// Height of the raster box. The actual font size is slightly smaller.
+    pub(crate) height: usize,
+    /// Width of the raster box in pixels. This equals the width of the
+    /// [`crate::RasterizedChar`] of the same font weight and raster height.
+    pub(crate) width: usize,
+}
+
+impl SubpixelChar {
+    /// The unfiltered coverage of the subpixels from 0 to 255. Each row has three
+    /// bytes per pixel, ordered from left to right.
+    #[inline]
+    pub const fn raster(&self) -> &'static [&'static [u8]] {
+        self.raster
+    }
+
+    /// Height of the raster box. The actual font size is slightly smaller.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Width of the raster box in pixels, not in subpixels.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the filtered coverage of the subpixel with the index `i` in row `y`.
+    const fn filtered_subpixel(&self, i: usize, y: usize) -> u8 {
+        let row = self.raster[y];
+        let mut sum = 0;
+        let mut tap = 0;
+        while tap < FILTER.len() {
+            // the filter is centered on `i`
+            if i + tap >= 2 && i + tap - 2 < row.len() {
+                sum += FILTER[tap] * row[i + tap - 2] as u32;
+            }
+            tap += 1;
+        }
+        (sum >> 8) as u8
+    }
+
+    /// Returns the filtered coverage from 0 to 255 of the red, green, and blue
+    /// subpixels, in this order, of the pixel at `(x, y)` on a panel with the given
+    /// [`SubpixelOrder`]. Pixels outside the raster have no coverage.
+    ///
+    /// Blend each color channel separately onto the background, e.g.,
+    /// `red = bg_red + (fg_red - bg_red) * coverage[0] / 255`.
+    pub const fn pixel(&self, x: usize, y: usize, order: SubpixelOrder) -> [u8; 3] {
+        if x >= self.width || y >= self.height {
+            return [0; 3];
+        }
+
+        let left = self.filtered_subpixel(3 * x, y);
+        let center = self.filtered_subpixel(3 * x + 1, y);
+        let right = self.filtered_subpixel(3 * x + 2, y);
+        match order {
+            SubpixelOrder::Rgb => [left, center, right],
+            SubpixelOrder::Bgr => [right, center, left],
+        }
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{FontWeight, RasterHeight, get_raster, get_raster_subpixel, get_raster_width};
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    #[test]
+    fn test_get_raster_subpixel() {
+        let a = get_raster_subpixel('A', WEIGHT, SIZE).unwrap();
+        assert_eq!(a.height(), 16);
+        assert_eq!(a.width(), get_raster_width(WEIGHT, SIZE));
+        assert_eq!(a.raster().len(), a.height());
+        for row in a.raster() {
+            assert_eq!(row.len(), 3 * a.width());
+        }
+
+        // all enabled unicode ranges have subpixel rasters
+        for c in ['Ä', 'ő', '\u{FFFD}'] {
+            assert_eq!(
+                get_raster_subpixel(c, WEIGHT, SIZE).is_some(),
+                get_raster(c, WEIGHT, SIZE).is_some()
+            );
+        }
+        assert!(get_raster_subpixel('\u{1}', WEIGHT, SIZE).is_none());
+    }
+
+    #[test]
+    fn test_filter() {
+        let full = SubpixelChar {
+            raster: &[&[255; 9]],
+            height: 1,
+            width: 3,
+        };
+        assert_eq!(full.pixel(1, 0, SubpixelOrder::Rgb), [255; 3]);
+        // the coverage at the border is spread to the outside
+        assert!(full.pixel(0, 0, SubpixelOrder::Rgb)[0] < 255);
+        assert_eq!(full.pixel(3, 0, SubpixelOrder::Rgb), [0; 3]);
+
+        let single = SubpixelChar {
+            raster: &[&[0, 0, 0, 0, 255, 0, 0, 0, 0]],
+            height: 1,
+            width: 3,
+        };
+        assert_eq!(single.pixel(0, 0, SubpixelOrder::Rgb), [0, 0, 7]);
+        assert_eq!(single.pixel(1, 0, SubpixelOrder::Rgb), [76, 85, 76]);
+        assert_eq!(single.pixel(2, 0, SubpixelOrder::Rgb), [7, 0, 0]);
+        assert_eq!(single.pixel(0, 0, SubpixelOrder::Bgr), [7, 0, 0]);
+    }
+
+    #[test]
+    fn test_order() {
+        let a = get_raster_subpixel('A', WEIGHT, SIZE).unwrap();
+        let space = get_raster_subpixel(' ', WEIGHT, SIZE).unwrap();
+        for y in 0..a.height() {
+            for x in 0..a.width() {
+                let [r, g, b] = a.pixel(x, y, SubpixelOrder::Rgb);
+                assert_eq!(a.pixel(x, y, SubpixelOrder::Bgr), [b, g, r]);
+                assert_eq!(space.pixel(x, y, SubpixelOrder::Rgb), [0; 3]);
+            }
+        }
+    }
+}
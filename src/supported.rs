@@ -0,0 +1,112 @@
+//! Module for querying the characters that are supported by the enabled Cargo
+//! features at runtime, e.g., to build font pickers or to validate strings.
+
+use crate::SUPPORTED_CHAR_RANGES;
+
+/// Returns whether [`crate::get_raster`] returns a raster for `c`, i.e., whether
+/// `c` is part of an enabled unicode range and not ignored, such as control
+/// characters.
+///
+/// This is faster than calling [`crate::get_raster`], as it only compares `c`
+/// with the boundaries of a few ranges.
+#[inline]
+pub const fn is_supported(c: char) -> bool {
+    let mut i = 0;
+    while i < SUPPORTED_CHAR_RANGES.len() {
+        let (start, end) = SUPPORTED_CHAR_RANGES[i];
+        if c as u32 >= start as u32 && c as u32 <= end as u32 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns an iterator over all characters that are supported by the enabled
+/// unicode ranges in ascending order. See [`is_supported`].
+#[inline]
+pub const fn supported_chars() -> SupportedChars {
+    SupportedChars {
+        range: 0,
+        next: match SUPPORTED_CHAR_RANGES.first() {
+            Some((start, _)) => *start as u32,
+            None => 0,
+        },
+    }
+}
+
+/// Iterator over all supported characters. Created by [`supported_chars`].
+#[derive(Debug, Clone)]
+pub struct SupportedChars {
+    /// Index into [`SUPPORTED_CHAR_RANGES`].
+    range: usize,
+    /// The code point of the next char inside the current range.
+    next: u32,
+}
+
+impl Iterator for SupportedChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, end) = SUPPORTED_CHAR_RANGES.get(self.range)?;
+        let c = char::from_u32(self.next)?;
+        if self.next < *end as u32 {
+            self.next += 1;
+        } else {
+            self.range += 1;
+            if let Some((start, _)) = SUPPORTED_CHAR_RANGES.get(self.range) {
+                self.next = *start as u32;
+            }
+        }
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut remaining = 0;
+        for (i, (start, end)) in SUPPORTED_CHAR_RANGES.iter().enumerate().skip(self.range) {
+            // the current range is partially consumed
+            let start = if i == self.range {
+                self.next
+            } else {
+                *start as u32
+            };
+            remaining += (*end as u32 - start + 1) as usize;
+        }
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for SupportedChars {}
+
+impl core::iter::FusedIterator for SupportedChars {}
+
+#[cfg(all(test, feature = "unicode-basic-latin"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_supported() {
+        assert!(is_supported('A'));
+        assert!(is_supported(' '));
+        assert!(is_supported('~'));
+        assert!(!is_supported('\n'));
+        assert!(!is_supported('\u{7f}'));
+        assert_eq!(
+            is_supported('ä'),
+            cfg!(feature = "unicode-latin-1-supplement")
+        );
+        assert_eq!(is_supported('�'), cfg!(feature = "unicode-specials"));
+    }
+
+    #[test]
+    fn test_supported_chars() {
+        let mut chars = supported_chars();
+        let count = chars.len();
+        assert_eq!(chars.next(), Some(' '));
+        assert_eq!(chars.len(), count - 1);
+        assert!(chars.clone().all(is_supported));
+        assert_eq!(chars.by_ref().count(), count - 1);
+        assert_eq!(chars.len(), 0);
+        assert_eq!(chars.next(), None);
+    }
+}
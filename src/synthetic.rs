@@ -0,0 +1,169 @@
+//! Module for synthetic font weights, i.e., bold and light approximations that are
+//! computed at runtime from the raster of another font weight.
+//!
+//! This way, emphasis is possible even if only the `regular` feature is enabled,
+//! which saves the binary size of the other font weights. The real font weights
+//! look better, though. Compared with the real font weights, an `amount` of about
+//! 96 for [`Raster::embolden`] and 128 for [`Raster::thin`] come closest on
+//! average (see `codegen/tests/synthetic_weights.rs`).
+
+use crate::Raster;
+
+/// Returns the coverage of the union of two pixels with the intensities `a` and `b`.
+const fn union(a: u8, b: u8) -> u8 {
+    let (a, b) = (a as u32, b as u32);
+    (a + b - a * b / 255) as u8
+}
+
+/// Returns the coverage of the intersection of two pixels with the intensities `a`
+/// and `b`.
+const fn intersection(a: u8, b: u8) -> u8 {
+    (a as u32 * b as u32 / 255) as u8
+}
+
+/// Returns `value` scaled by `amount / 255`.
+const fn scale(value: u8, amount: u8) -> u8 {
+    (value as u32 * amount as u32 / 255) as u8
+}
+
+/// View of a [`Raster`] with thicker vertical stems. Created by [`Raster::embolden`].
+///
+/// Each pixel is united with its left neighbor, i.e., the raster is dilated
+/// horizontally by up to one pixel. The width of the raster stays the same.
+#[derive(Debug, Clone, Copy)]
+pub struct Emboldened<R> {
+    raster: R,
+    amount: u8,
+}
+
+impl<R: Raster> Emboldened<R> {
+    /// Creates a new view. An `amount` of 255 thickens stems by one pixel, lower
+    /// values by a fraction of a pixel.
+    pub const fn new(raster: R, amount: u8) -> Self {
+        Self { raster, amount }
+    }
+}
+
+impl<R: Raster> Raster for Emboldened<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        let left = if x == 0 {
+            0
+        } else {
+            self.raster.pixel(x - 1, y)
+        };
+        union(self.raster.pixel(x, y), scale(left, self.amount))
+    }
+}
+
+/// View of a [`Raster`] with thinner vertical stems. Created by [`Raster::thin`].
+///
+/// Each pixel is intersected with its left neighbor, i.e., the raster is eroded
+/// horizontally by up to one pixel. The width of the raster stays the same.
+#[derive(Debug, Clone, Copy)]
+pub struct Thinned<R> {
+    raster: R,
+    amount: u8,
+}
+
+impl<R: Raster> Thinned<R> {
+    /// Creates a new view. An `amount` of 255 thins stems by one pixel, lower
+    /// values by a fraction of a pixel.
+    pub const fn new(raster: R, amount: u8) -> Self {
+        Self { raster, amount }
+    }
+}
+
+impl<R: Raster> Raster for Thinned<R> {
+    #[inline]
+    fn width(&self) -> usize {
+        self.raster.width()
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.raster.height()
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x >= self.width() || y >= self.height() {
+            return 0;
+        }
+
+        let intensity = self.raster.pixel(x, y);
+        let left = if x == 0 {
+            0
+        } else {
+            self.raster.pixel(x - 1, y)
+        };
+        let eroded = intensity - intersection(intensity, left);
+        intensity - scale(eroded, self.amount)
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::{FontWeight, RasterHeight, RasterizedChar, get_raster};
+
+    const fn raster(c: char) -> RasterizedChar {
+        get_raster(c, FontWeight::Regular, RasterHeight::Size16).unwrap()
+    }
+
+    fn sum(raster: impl Raster) -> usize {
+        (0..raster.height())
+            .flat_map(|y| (0..raster.width()).map(move |x| (x, y)))
+            .map(|(x, y)| raster.pixel(x, y) as usize)
+            .sum()
+    }
+
+    #[test]
+    fn test_embolden() {
+        let l = raster('l');
+        assert_eq!(sum(l.embolden(0)), sum(l));
+        assert!(sum(l.embolden(128)) > sum(l));
+        assert!(sum(l.embolden(255)) > sum(l.embolden(128)));
+        for y in 0..l.height() {
+            for x in 0..l.width() {
+                assert!(l.embolden(255).pixel(x, y) >= l.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_thin() {
+        let l = raster('l');
+        assert_eq!(sum(l.thin(0)), sum(l));
+        assert!(sum(l.thin(128)) < sum(l));
+        assert!(sum(l.thin(255)) < sum(l.thin(128)));
+        for y in 0..l.height() {
+            for x in 0..l.width() {
+                assert!(l.thin(255).pixel(x, y) <= l.pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        assert_eq!(union(0, 0), 0);
+        assert_eq!(union(255, 0), 255);
+        assert_eq!(union(255, 255), 255);
+        assert_eq!(union(128, 128), 192);
+        assert_eq!(intersection(255, 255), 255);
+        assert_eq!(intersection(255, 0), 0);
+        assert_eq!(intersection(128, 128), 64);
+    }
+}
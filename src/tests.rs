@@ -0,0 +1,169 @@
+//! Tests for the invariants of all generated rasters of the enabled font weights,
+//! raster heights, and unicode ranges.
+
+// # THIS FILE IS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::{
+    FontWeight, MAX_RASTER_HEIGHT, MAX_RASTER_WIDTH, RasterHeight, get_raster, get_raster_width,
+    is_supported, supported_chars,
+};
+use core::ops::RangeInclusive;
+
+/// A unicode range of the codegen, including the disabled ones.
+struct UnicodeRange {
+    /// Whether the Cargo feature of the range is enabled.
+    enabled: bool,
+    /// All code points of the range, including the ignored ones.
+    code_points: RangeInclusive<u32>,
+    /// Code points without a raster, such as control characters.
+    ignored: &'static [RangeInclusive<u32>],
+}
+
+impl UnicodeRange {
+    /// Returns all chars of the range and whether they have a raster.
+    fn chars(&self) -> impl Iterator<Item = (char, bool)> + '_ {
+        self.code_points.clone().filter_map(move |c| {
+            let supported = self.enabled && !self.ignored.iter().any(|r| r.contains(&c));
+            char::from_u32(c).map(|c| (c, supported))
+        })
+    }
+}
+
+const FONT_WEIGHTS: &[FontWeight] = &[
+    #[cfg(feature = "light")]
+    FontWeight::Light,
+    #[cfg(feature = "regular")]
+    FontWeight::Regular,
+    #[cfg(feature = "bold")]
+    FontWeight::Bold,
+];
+
+const RASTER_HEIGHTS: &[RasterHeight] = &[
+    #[cfg(feature = "size_16")]
+    RasterHeight::Size16,
+    #[cfg(feature = "size_20")]
+    RasterHeight::Size20,
+    #[cfg(feature = "size_24")]
+    RasterHeight::Size24,
+    #[cfg(feature = "size_32")]
+    RasterHeight::Size32,
+];
+
+const UNICODE_RANGES: &[UnicodeRange] = &[
+    UnicodeRange {
+        enabled: cfg!(feature = "unicode-basic-latin"),
+        code_points: 0x0..=0x7f,
+        ignored: &[0x0..=0x1f, 0x7f..=0x7f],
+    },
+    UnicodeRange {
+        enabled: cfg!(feature = "unicode-latin-1-supplement"),
+        code_points: 0x80..=0xff,
+        ignored: &[0x80..=0x9f, 0xa0..=0xa0, 0xad..=0xad],
+    },
+    UnicodeRange {
+        enabled: cfg!(feature = "unicode-latin-extended-a"),
+        code_points: 0x100..=0x17f,
+        ignored: &[],
+    },
+    UnicodeRange {
+        enabled: cfg!(feature = "unicode-specials"),
+        code_points: 0xfff0..=0xffff,
+        ignored: &[0xfff0..=0xfffc, 0xfffe..=0xffff],
+    },
+];
+
+/// Calls `f` for every enabled combination of font weight and raster height.
+fn for_each_font(mut f: impl FnMut(FontWeight, RasterHeight)) {
+    for &style in FONT_WEIGHTS {
+        for &size in RASTER_HEIGHTS {
+            f(style, size);
+        }
+    }
+}
+
+#[test]
+fn test_enabled_font_weights_and_raster_heights() {
+    assert_eq!(FontWeight::ALL, FONT_WEIGHTS);
+    assert_eq!(RasterHeight::ALL, RASTER_HEIGHTS);
+}
+
+#[test]
+fn test_supported_chars_have_mono_rasters() {
+    for_each_font(|style, size| {
+        let width = get_raster_width(style, size);
+        assert!(width > 0 && width <= MAX_RASTER_WIDTH);
+        assert!(size.val() <= MAX_RASTER_HEIGHT);
+
+        let chars = UNICODE_RANGES.iter().flat_map(UnicodeRange::chars);
+        for (c, _) in chars.filter(|(_, supported)| *supported) {
+            let raster = get_raster(c, style, size)
+                .unwrap_or_else(|| panic!("{c:?} {style:?} {size:?} has no raster"));
+            assert_eq!(raster.height(), size.val(), "{c:?} {style:?} {size:?}");
+            assert_eq!(raster.width(), width, "{c:?} {style:?} {size:?}");
+            assert_eq!(
+                raster.raster().len(),
+                size.val(),
+                "{c:?} {style:?} {size:?}"
+            );
+            for row in raster.raster() {
+                assert_eq!(row.len(), width, "{c:?} {style:?} {size:?}");
+            }
+        }
+    });
+}
+
+#[test]
+fn test_unsupported_chars_have_no_raster() {
+    for_each_font(|style, size| {
+        let chars = UNICODE_RANGES.iter().flat_map(UnicodeRange::chars);
+        for (c, _) in chars.filter(|(_, supported)| !*supported) {
+            assert!(
+                get_raster(c, style, size).is_none(),
+                "{c:?} {style:?} {size:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn test_supported_chars() {
+    for (c, supported) in UNICODE_RANGES.iter().flat_map(UnicodeRange::chars) {
+        assert_eq!(is_supported(c), supported, "{c:?}");
+    }
+    let supported = UNICODE_RANGES
+        .iter()
+        .flat_map(UnicodeRange::chars)
+        .filter_map(|(c, supported)| supported.then_some(c));
+    assert!(supported_chars().eq(supported));
+}
+
+#[cfg(feature = "subpixel")]
+#[test]
+fn test_subpixel_rasters() {
+    use crate::get_raster_subpixel;
+
+    for_each_font(|style, size| {
+        let width = get_raster_width(style, size);
+        for range in UNICODE_RANGES {
+            for (c, supported) in range.chars() {
+                let raster = get_raster_subpixel(c, style, size);
+                if !supported {
+                    assert!(raster.is_none(), "{c:?} {style:?} {size:?}");
+                    continue;
+                }
+                let raster = raster
+                    .unwrap_or_else(|| panic!("{c:?} {style:?} {size:?} has no subpixel raster"));
+                assert_eq!(raster.height(), size.val(), "{c:?} {style:?} {size:?}");
+                assert_eq!(raster.width(), width, "{c:?} {style:?} {size:?}");
+                assert_eq!(
+                    raster.raster().len(),
+                    size.val(),
+                    "{c:?} {style:?} {size:?}"
+                );
+                for row in raster.raster() {
+                    assert_eq!(row.len(), 3 * width, "{c:?} {style:?} {size:?}");
+                }
+            }
+        }
+    });
+}
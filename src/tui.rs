@@ -0,0 +1,595 @@
+//! Module for the integration with [`ratatui`](https://ratatui.rs), which renders
+//! terminal UIs into a pixel framebuffer, e.g., on a machine without a terminal
+//! emulator.
+//!
+//! [`FramebufferBackend`] implements [`Backend`], hence, it works with the
+//! `Terminal` of `ratatui` and all widgets.
+
+use crate::color::blend;
+use crate::{
+    Decorations, FontWeight, GlyphSource, NotoGlyphs, Raster, RasterHeight, get_decoration_metrics,
+    get_raster_width,
+};
+use core::convert::Infallible;
+use core::ops::Range;
+use ratatui_core::backend::{Backend, ClearType, WindowSize};
+use ratatui_core::buffer::Cell;
+use ratatui_core::layout::{Position, Size};
+use ratatui_core::style::{Color, Modifier};
+use std::vec;
+use std::vec::Vec;
+
+/// Font weight of cells with [`Modifier::BOLD`] or `None`, if it is not enabled.
+#[cfg(feature = "bold")]
+const BOLD: Option<FontWeight> = Some(FontWeight::Bold);
+#[cfg(not(feature = "bold"))]
+const BOLD: Option<FontWeight> = None;
+
+/// Amount for [`Raster::embolden`], if the `bold` font weight is not enabled.
+const SYNTHETIC_BOLD: u8 = 96;
+
+/// The 16 ANSI colors of the default palette of xterm, in the order of their index.
+const ANSI_COLORS: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// Returns `color` as `0x00RRGGBB` or `None` for [`Color::Reset`].
+const fn rgb(color: Color) -> Option<u32> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some(u32::from_be_bytes([0, r, g, b])),
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+    Some(indexed(index))
+}
+
+/// Returns the color at `index` of the 256-color palette of xterm, i.e., the 16
+/// ANSI colors, a 6x6x6 color cube, and 24 shades of gray.
+const fn indexed(index: u8) -> u32 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let i = (index - 16) as usize;
+            u32::from_be_bytes([0, LEVELS[i / 36], LEVELS[i / 6 % 6], LEVELS[i % 6]])
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            u32::from_be_bytes([0, level, level, level])
+        }
+    }
+}
+
+/// Raster without ink, for chars that the [`GlyphSource`] doesn't support.
+#[derive(Debug, Clone, Copy)]
+struct Blank {
+    width: usize,
+    height: usize,
+}
+
+impl Raster for Blank {
+    #[inline]
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn pixel(&self, _x: usize, _y: usize) -> u8 {
+        0
+    }
+}
+
+/// [`Backend`] of `ratatui` that renders the cells of a terminal into a pixel
+/// buffer with the anti-aliased glyphs of this crate.
+///
+/// Each cell occupies [`get_raster_width`] x [`RasterHeight::val`] pixels. Pixels
+/// are stored row by row as `0x00RRGGBB`, which most framebuffers and windowing
+/// libraries accept as is. The backend maps the cells as follows:
+/// - foreground and background colors, including the 256-color palette of xterm;
+///   [`Color::Reset`] uses the default colors
+/// - [`Modifier::BOLD`] uses [`FontWeight::Bold`], or [`Raster::embolden`] if the
+///   `bold` feature is not enabled
+/// - [`Modifier::UNDERLINED`] and [`Modifier::CROSSED_OUT`] as lines, see
+///   [`get_decoration_metrics`]
+/// - [`Modifier::REVERSED`] swaps the foreground and background colors
+/// - [`Modifier::HIDDEN`] only draws the background
+///
+/// All other modifiers are ignored. Chars that the [`GlyphSource`] doesn't
+/// support are drawn as blanks. The cursor is tracked but not drawn.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{FontWeight, FramebufferBackend, RasterHeight};
+/// use ratatui_core::style::{Color, Style};
+/// use ratatui_core::terminal::Terminal;
+///
+/// let backend = FramebufferBackend::new(80, 25, FontWeight::Regular, RasterHeight::Size16);
+/// let mut terminal = Terminal::new(backend).unwrap();
+/// terminal
+///     .draw(|frame| {
+///         let style = Style::new().fg(Color::Green);
+///         frame.buffer_mut().set_string(0, 0, "Hello", style);
+///     })
+///     .unwrap();
+/// let pixels: &[u32] = terminal.backend().pixels();
+/// assert_eq!(pixels.len(), 80 * 7 * 25 * 16);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FramebufferBackend<G = NotoGlyphs> {
+    glyphs: G,
+    style: FontWeight,
+    size: RasterHeight,
+    columns: u16,
+    rows: u16,
+    foreground: u32,
+    background: u32,
+    pixels: Vec<u32>,
+    cursor: Position,
+    cursor_visible: bool,
+}
+
+impl FramebufferBackend {
+    /// Creates a backend for a terminal of `columns` x `rows` cells with the
+    /// built-in glyphs. The default colors are light gray on black.
+    pub fn new(columns: u16, rows: u16, style: FontWeight, size: RasterHeight) -> Self {
+        let (foreground, background) = (ANSI_COLORS[7], ANSI_COLORS[0]);
+        let pixels = usize::from(columns) * get_raster_width(style, size);
+        let pixels = pixels * usize::from(rows) * size.val();
+        Self {
+            glyphs: NotoGlyphs,
+            style,
+            size,
+            columns,
+            rows,
+            foreground,
+            background,
+            pixels: vec![background; pixels],
+            cursor: Position::ORIGIN,
+            cursor_visible: true,
+        }
+    }
+}
+
+impl<G: GlyphSource> FramebufferBackend<G> {
+    /// Replaces the glyphs, e.g., with custom glyphs that fall back to the built-in
+    /// ones. See [`GlyphSource::chain`].
+    pub fn with_glyphs<S: GlyphSource>(self, glyphs: S) -> FramebufferBackend<S> {
+        FramebufferBackend {
+            glyphs,
+            style: self.style,
+            size: self.size,
+            columns: self.columns,
+            rows: self.rows,
+            foreground: self.foreground,
+            background: self.background,
+            pixels: self.pixels,
+            cursor: self.cursor,
+            cursor_visible: self.cursor_visible,
+        }
+    }
+
+    /// Sets the colors for [`Color::Reset`] as `0x00RRGGBB` and clears the buffer.
+    pub fn with_default_colors(mut self, foreground: u32, background: u32) -> Self {
+        self.foreground = foreground;
+        self.background = background;
+        self.pixels.fill(background);
+        self
+    }
+
+    /// Returns the width of the buffer in pixels.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.columns as usize * self.cell_width()
+    }
+
+    /// Returns the height of the buffer in pixels.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.rows as usize * self.size.val()
+    }
+
+    /// Returns all pixels as `0x00RRGGBB`, row by row.
+    #[inline]
+    pub fn pixels(&self) -> &[u32] {
+        &self.pixels
+    }
+
+    /// Returns the pixel at `x` and `y` as `0x00RRGGBB`.
+    ///
+    /// # Panics
+    /// Panics if the pixel is outside the buffer.
+    #[inline]
+    pub fn pixel(&self, x: usize, y: usize) -> u32 {
+        assert!(x < self.width() && y < self.height());
+        self.pixels[y * self.width() + x]
+    }
+
+    /// Returns true if the application wants the cursor to be shown. See
+    /// [`Backend::show_cursor`].
+    #[inline]
+    pub const fn is_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    #[inline]
+    const fn cell_width(&self) -> usize {
+        get_raster_width(self.style, self.size)
+    }
+
+    #[inline]
+    const fn cells(&self) -> usize {
+        self.columns as usize * self.rows as usize
+    }
+
+    /// Draws `raster` into the cell at `column` and `row`. Pixels outside the cell
+    /// are clipped.
+    fn draw_raster(
+        &mut self,
+        column: usize,
+        row: usize,
+        raster: &impl Raster,
+        foreground: u32,
+        background: u32,
+    ) {
+        let (cell_width, cell_height) = (self.cell_width(), self.size.val());
+        let width = self.width();
+        let (left, top) = (column * cell_width, row * cell_height);
+        for y in 0..cell_height {
+            let line = &mut self.pixels[(top + y) * width + left..][..cell_width];
+            for (x, pixel) in line.iter_mut().enumerate() {
+                *pixel = blend(background, foreground, raster.pixel(x, y));
+            }
+        }
+    }
+
+    fn draw_cell(&mut self, column: usize, row: usize, cell: &Cell) {
+        let modifier = cell.modifier;
+        let mut foreground = rgb(cell.fg).unwrap_or(self.foreground);
+        let mut background = rgb(cell.bg).unwrap_or(self.background);
+        if modifier.contains(Modifier::REVERSED) {
+            core::mem::swap(&mut foreground, &mut background);
+        }
+
+        let mut decorations = Decorations::NONE;
+        if modifier.contains(Modifier::UNDERLINED) {
+            decorations |= Decorations::UNDERLINE;
+        }
+        if modifier.contains(Modifier::CROSSED_OUT) {
+            decorations |= Decorations::STRIKETHROUGH;
+        }
+
+        let bold = modifier.contains(Modifier::BOLD);
+        let (style, amount) = match BOLD {
+            Some(weight) if bold => (weight, 0),
+            None if bold => (self.style, SYNTHETIC_BOLD),
+            _ => (self.style, 0),
+        };
+        let metrics = get_decoration_metrics(style, self.size);
+
+        let c = cell.symbol().chars().next().unwrap_or(' ');
+        let glyph = if modifier.contains(Modifier::HIDDEN) {
+            None
+        } else {
+            self.glyphs.glyph(c, style, self.size)
+        };
+        match glyph {
+            Some(glyph) => {
+                let raster = glyph.embolden(amount).decorate(decorations, metrics);
+                self.draw_raster(column, row, &raster, foreground, background);
+            }
+            None => {
+                let blank = Blank {
+                    width: self.cell_width(),
+                    height: self.size.val(),
+                };
+                let raster = blank.decorate(decorations, metrics);
+                self.draw_raster(column, row, &raster, foreground, background);
+            }
+        }
+    }
+
+    /// Fills the cells in `range`, counted row by row, with the default background.
+    fn clear_cells(&mut self, range: Range<usize>) {
+        let blank = Blank {
+            width: self.cell_width(),
+            height: self.size.val(),
+        };
+        let columns = usize::from(self.columns);
+        for i in range.start..range.end.min(self.cells()) {
+            self.draw_raster(i % columns, i / columns, &blank, 0, self.background);
+        }
+    }
+
+    /// Returns the pixel range of the rows of cells in `region` and the number of
+    /// rows to scroll, which is at most the height of the region.
+    fn scroll_region(&self, region: Range<u16>, line_count: u16) -> (Range<usize>, usize) {
+        let row_len = self.width() * self.size.val();
+        let end = region.end.min(self.rows);
+        let start = region.start.min(end);
+        let count = line_count.min(end - start);
+        (
+            usize::from(start) * row_len..usize::from(end) * row_len,
+            usize::from(count) * row_len,
+        )
+    }
+}
+
+impl<G: GlyphSource> Backend for FramebufferBackend<G> {
+    type Error = Infallible;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (column, row, cell) in content {
+            if column < self.columns && row < self.rows {
+                self.draw_cell(usize::from(column), usize::from(row), cell);
+            }
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.clear_region(ClearType::All)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        let columns = usize::from(self.columns);
+        let line = usize::from(self.cursor.y) * columns;
+        let cursor = line + usize::from(self.cursor.x);
+        let range = match clear_type {
+            ClearType::All => 0..self.cells(),
+            ClearType::AfterCursor => cursor..self.cells(),
+            ClearType::BeforeCursor => 0..cursor + 1,
+            ClearType::CurrentLine => line..line + columns,
+            ClearType::UntilNewLine => cursor..line + columns,
+        };
+        self.clear_cells(range);
+        Ok(())
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        Ok(Size::new(self.columns, self.rows))
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.height()).unwrap_or(u16::MAX);
+        Ok(WindowSize {
+            columns_rows: Size::new(self.columns, self.rows),
+            pixels: Size::new(width, height),
+        })
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> Result<(), Self::Error> {
+        let (pixels, count) = self.scroll_region(region, line_count);
+        self.pixels
+            .copy_within(pixels.start + count..pixels.end, pixels.start);
+        self.pixels[pixels.end - count..pixels.end].fill(self.background);
+        Ok(())
+    }
+
+    fn scroll_region_down(
+        &mut self,
+        region: Range<u16>,
+        line_count: u16,
+    ) -> Result<(), Self::Error> {
+        let (pixels, count) = self.scroll_region(region, line_count);
+        self.pixels
+            .copy_within(pixels.start..pixels.end - count, pixels.start + count);
+        self.pixels[pixels.start..pixels.start + count].fill(self.background);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+    use crate::get_raster;
+    use ratatui_core::style::Style;
+    use ratatui_core::terminal::Terminal;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    fn render(text: &str, style: Style) -> FramebufferBackend {
+        let backend = FramebufferBackend::new(4, 2, WEIGHT, SIZE);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.buffer_mut().set_string(0, 0, text, style);
+            })
+            .unwrap();
+        terminal.backend().clone()
+    }
+
+    /// Asserts that the cell at `column` and `row` shows `raster` in the colors.
+    fn assert_cell(
+        backend: &FramebufferBackend,
+        column: usize,
+        row: usize,
+        raster: &impl Raster,
+        foreground: u32,
+        background: u32,
+    ) {
+        let width = get_raster_width(WEIGHT, SIZE);
+        for y in 0..SIZE.val() {
+            for x in 0..width {
+                assert_eq!(
+                    backend.pixel(column * width + x, row * SIZE.val() + y),
+                    blend(background, foreground, raster.pixel(x, y)),
+                    "pixel ({x}, {y}) of cell ({column}, {row})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(rgb(Color::Reset), None);
+        assert_eq!(rgb(Color::Rgb(1, 2, 3)), Some(0x010203));
+        assert_eq!(rgb(Color::LightBlue), Some(0x5c5cff));
+        assert_eq!(rgb(Color::Indexed(9)), rgb(Color::LightRed));
+        assert_eq!(indexed(16), 0x000000);
+        assert_eq!(indexed(231), 0xffffff);
+        assert_eq!(indexed(196), 0xff0000);
+        assert_eq!(indexed(232), 0x080808);
+        assert_eq!(indexed(255), 0xeeeeee);
+    }
+
+    #[test]
+    fn test_draw() {
+        let a = get_raster('A', WEIGHT, SIZE).unwrap();
+        let blank = Blank {
+            width: a.width(),
+            height: a.height(),
+        };
+
+        let backend = render("A", Style::new().fg(Color::Red).bg(Color::Blue));
+        assert_eq!((backend.width(), backend.height()), (4 * 7, 2 * 16));
+        assert_cell(&backend, 0, 0, &a, 0xcd0000, 0x0000ee);
+        // untouched cells have the default colors
+        assert_cell(&backend, 1, 0, &blank, 0xe5e5e5, 0x000000);
+        assert_cell(&backend, 0, 1, &blank, 0xe5e5e5, 0x000000);
+
+        let backend = render("A\u{E000}", Style::new().reversed());
+        assert_cell(&backend, 0, 0, &a, 0x000000, 0xe5e5e5);
+        // unsupported chars are blanks
+        assert_cell(&backend, 1, 0, &blank, 0x000000, 0xe5e5e5);
+
+        let backend = render("A", Style::new().hidden());
+        assert_cell(&backend, 0, 0, &blank, 0xe5e5e5, 0x000000);
+    }
+
+    #[test]
+    fn test_draw_modifiers() {
+        let backend = render("A", Style::new().bold());
+        let regular = get_raster('A', WEIGHT, SIZE).unwrap();
+        match BOLD {
+            Some(bold) => {
+                let a = get_raster('A', bold, SIZE).unwrap();
+                assert_cell(&backend, 0, 0, &a, 0xe5e5e5, 0x000000);
+            }
+            None => {
+                let a = regular.embolden(SYNTHETIC_BOLD);
+                assert_cell(&backend, 0, 0, &a, 0xe5e5e5, 0x000000);
+            }
+        }
+
+        let backend = render("A ", Style::new().underlined().crossed_out());
+        let metrics = get_decoration_metrics(WEIGHT, SIZE);
+        let decorations = Decorations::UNDERLINE | Decorations::STRIKETHROUGH;
+        let a = regular.decorate(decorations, metrics);
+        assert_cell(&backend, 0, 0, &a, 0xe5e5e5, 0x000000);
+        let y = metrics.underline_position();
+        assert_eq!(backend.pixel(0, y), 0xe5e5e5);
+        assert_eq!(backend.pixel(7 + 6, y), 0xe5e5e5);
+    }
+
+    #[test]
+    fn test_custom_glyphs() {
+        const ROW: &[u8] = &[255; 9];
+        const ICON: crate::RasterizedChar = crate::RasterizedChar::new(&[ROW; 20], 9);
+        let icons = |c, _style, _size| (c == '\u{E000}').then_some(ICON);
+        let backend = FramebufferBackend::new(2, 1, WEIGHT, SIZE).with_glyphs(icons);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                frame
+                    .buffer_mut()
+                    .set_string(0, 0, "\u{E000}", Style::new());
+            })
+            .unwrap();
+        let backend = terminal.backend();
+        // the icon is clipped to its cell
+        assert!(backend.pixels()[..7].iter().all(|&p| p == 0xe5e5e5));
+        assert!(backend.pixels()[7..14].iter().all(|&p| p == 0x000000));
+    }
+
+    #[test]
+    fn test_clear_and_scroll() {
+        let mut backend =
+            FramebufferBackend::new(2, 3, WEIGHT, SIZE).with_default_colors(0, 0x0000ff);
+        assert!(backend.pixels().iter().all(|&p| p == 0x0000ff));
+        let width = backend.width();
+        let row_len = width * SIZE.val();
+
+        backend.pixels.fill(0xff0000);
+        backend.set_cursor_position((1, 1)).unwrap();
+        assert_eq!(backend.get_cursor_position(), Ok(Position::new(1, 1)));
+        backend.clear_region(ClearType::UntilNewLine).unwrap();
+        assert_eq!(backend.pixel(6, SIZE.val()), 0xff0000);
+        assert_eq!(backend.pixel(7, SIZE.val()), 0x0000ff);
+        assert_eq!(backend.pixel(13, 2 * SIZE.val() - 1), 0x0000ff);
+        assert_eq!(backend.pixel(0, 2 * SIZE.val()), 0xff0000);
+
+        backend.clear().unwrap();
+        assert!(backend.pixels().iter().all(|&p| p == 0x0000ff));
+
+        // mark each row with a color
+        for (row, pixels) in backend.pixels.chunks_mut(row_len).enumerate() {
+            pixels.fill(row as u32 + 1);
+        }
+        backend.scroll_region_up(0..2, 1).unwrap();
+        assert_eq!(backend.pixels()[0], 2);
+        assert_eq!(backend.pixels()[row_len], 0x0000ff);
+        assert_eq!(backend.pixels()[2 * row_len], 3);
+        backend.scroll_region_down(1..3, 5).unwrap();
+        assert_eq!(backend.pixels()[0], 2);
+        assert!(backend.pixels()[row_len..].iter().all(|&p| p == 0x0000ff));
+    }
+
+    #[test]
+    fn test_size() {
+        let mut backend = FramebufferBackend::new(80, 25, WEIGHT, SIZE);
+        assert_eq!(backend.size(), Ok(Size::new(80, 25)));
+        let window = backend.window_size().unwrap();
+        assert_eq!(window.pixels, Size::new(80 * 7, 25 * 16));
+        backend.hide_cursor().unwrap();
+        assert!(!backend.is_cursor_visible());
+    }
+}
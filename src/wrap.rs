@@ -0,0 +1,356 @@
+//! Module for wrapping text into lines that fit into a box of a given pixel width.
+
+use crate::measure::char_columns;
+use crate::{FontWeight, RasterHeight, TabWidth, get_raster_width};
+
+/// The ellipsis that marks truncated text. `…` is not part of any unicode range
+/// of the font.
+const ELLIPSIS: &str = "...";
+
+/// Horizontal alignment of wrapped lines inside their box.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    /// Lines start at the left edge of the box.
+    #[default]
+    Left,
+    /// Lines are centered in the box. If the remaining space can't be split
+    /// evenly, the line is one pixel closer to the left edge.
+    Center,
+    /// Lines end at the right edge of the box.
+    Right,
+}
+
+/// A single line produced by [`WrapIter`].
+///
+/// The line references a span of the original text, hence, no allocations
+/// are required. Renderers should draw [`Self::text`] followed by
+/// [`Self::ellipsis`], if present, starting at [`Self::x`] and [`Self::y`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrappedLine<'a> {
+    text: &'a str,
+    ellipsis: Option<&'static str>,
+    x: usize,
+    y: usize,
+    width: usize,
+}
+
+impl<'a> WrappedLine<'a> {
+    /// The span of the original text that belongs to this line. Whitespace at
+    /// the end of the line and the line break itself are not included.
+    #[inline]
+    pub const fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// The ellipsis that must be printed after [`Self::text`] if the text was
+    /// truncated, because it didn't fit into the height of the box. This is `...`,
+    /// or fewer dots if the box is less than three columns wide.
+    #[inline]
+    pub const fn ellipsis(&self) -> Option<&'static str> {
+        self.ellipsis
+    }
+
+    /// Horizontal offset in pixels of the line relative to the left edge of the
+    /// box, according to the [`Alignment`].
+    #[inline]
+    pub const fn x(&self) -> usize {
+        self.x
+    }
+
+    /// Vertical offset in pixels of the line relative to the top edge of the box.
+    #[inline]
+    pub const fn y(&self) -> usize {
+        self.y
+    }
+
+    /// Width in pixels of the line including the ellipsis.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+}
+
+/// Iterator that wraps text into lines that fit into a box of a given pixel
+/// width. Created by [`wrap_str`].
+///
+/// Lines are broken at whitespace and after hyphens. Words that are wider than
+/// the box are broken at character boundaries. Each `\n` forces a line break.
+/// Whitespace at the beginning of lines that result from a wrap is skipped.
+/// Characters occupy columns as described in [`crate::measure_str`].
+///
+/// This needs no allocations and no floating point operations.
+#[derive(Debug, Clone)]
+pub struct WrapIter<'a> {
+    /// The text that is not yet consumed. `None` if the iterator is exhausted.
+    rest: Option<&'a str>,
+    /// Number of lines that were emitted so far.
+    line: usize,
+    /// Width of the box in pixels.
+    box_width: usize,
+    /// Maximum amount of lines that fit into the height of the box.
+    max_lines: Option<usize>,
+    /// Width of each character in pixels.
+    char_width: usize,
+    /// Height of each line in pixels.
+    line_height: usize,
+    alignment: Alignment,
+    tab_width: TabWidth,
+}
+
+/// Wraps `s` into lines that are at most `width_px` pixels wide when printed with
+/// the given [`FontWeight`] and [`RasterHeight`].
+///
+/// The returned [`WrapIter`] can be further configured with an [`Alignment`], a
+/// maximum box height, and a [`TabWidth`].
+///
+/// # Example
+/// ```rust
+/// use noto_sans_mono_bitmap::{Alignment, FontWeight, RasterHeight, wrap_str};
+///
+/// let lines = wrap_str("Installing packages...", FontWeight::Regular, RasterHeight::Size16, 80)
+///     .with_alignment(Alignment::Center)
+///     .with_max_height(32);
+/// for line in lines {
+///     // draw `line.text()` and `line.ellipsis()` at `line.x()`, `line.y()`
+/// }
+/// ```
+pub fn wrap_str(s: &str, style: FontWeight, size: RasterHeight, width_px: usize) -> WrapIter<'_> {
+    WrapIter {
+        rest: Some(s),
+        line: 0,
+        box_width: width_px,
+        max_lines: None,
+        char_width: get_raster_width(style, size),
+        line_height: size.val(),
+        alignment: Alignment::Left,
+        tab_width: TabWidth::default(),
+    }
+}
+
+impl<'a> WrapIter<'a> {
+    /// Aligns all lines horizontally inside the box.
+    pub const fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Limits the height of the box in pixels. If the text doesn't fit, the last
+    /// line that fits is truncated and marked with an ellipsis.
+    pub const fn with_max_height(mut self, height_px: usize) -> Self {
+        self.max_lines = Some(height_px / self.line_height);
+        self
+    }
+
+    /// Sets the distance between two tab stops. The default is [`TabWidth::default`].
+    pub const fn with_tab_width(mut self, tab_width: TabWidth) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Maximum number of columns per line.
+    const fn max_columns(&self) -> usize {
+        self.box_width / self.char_width
+    }
+
+    /// Returns the number of columns of a line.
+    fn columns(&self, line: &str) -> usize {
+        line.chars().fold(0, |column, c| {
+            column + char_columns(c, column, self.tab_width)
+        })
+    }
+
+    /// Splits the next line from `rest`. Returns the line and the text that
+    /// remains for the next lines.
+    fn split_line(&self, rest: &'a str) -> (&'a str, Option<&'a str>) {
+        let max_columns = self.max_columns();
+        let mut column = 0;
+        // byte index where the line ends and byte index where the next one starts
+        let mut last_break = None;
+
+        for (i, c) in rest.char_indices() {
+            if c == '\n' {
+                return (&rest[..i], Some(&rest[i + 1..]));
+            }
+
+            let is_whitespace = c == ' ' || c == '\t';
+            column += char_columns(c, column, self.tab_width);
+
+            if is_whitespace {
+                let next_start = rest[i..]
+                    .find(|c| c != ' ' && c != '\t')
+                    .map_or(rest.len(), |offset| i + offset);
+                // Whitespace overflowing the box doesn't need to be displayed.
+                if column > max_columns {
+                    return (&rest[..i], Some(&rest[next_start..]));
+                }
+                last_break = Some((i, next_start));
+            } else if column > max_columns {
+                return match last_break {
+                    Some((end, next_start)) => (&rest[..end], Some(&rest[next_start..])),
+                    // The word doesn't fit into the line. Break at this character but
+                    // always consume at least one character to guarantee progress.
+                    None if i == 0 => {
+                        let end = c.len_utf8();
+                        (&rest[..end], Some(&rest[end..]))
+                    }
+                    None => (&rest[..i], Some(&rest[i..])),
+                };
+            } else if c == '-' && i > 0 {
+                let end = i + c.len_utf8();
+                last_break = Some((end, end));
+            }
+        }
+
+        (rest, None)
+    }
+
+    /// The ellipsis, shortened to the width of the box if necessary.
+    fn ellipsis(&self) -> &'static str {
+        &ELLIPSIS[..ELLIPSIS.len().min(self.max_columns())]
+    }
+
+    /// Shortens `line` so that it fits into the box together with the ellipsis.
+    fn truncate_for_ellipsis(&self, line: &'a str) -> &'a str {
+        let max_columns = self.max_columns() - self.ellipsis().len();
+        let mut column = 0;
+        let end = line
+            .char_indices()
+            .find(|&(_, c)| {
+                column += char_columns(c, column, self.tab_width);
+                column > max_columns
+            })
+            .map_or(line.len(), |(i, _)| i);
+        line[..end].trim_end_matches([' ', '\t'])
+    }
+}
+
+impl<'a> Iterator for WrapIter<'a> {
+    type Item = WrappedLine<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        if self.max_columns() == 0 || self.max_lines.is_some_and(|max| self.line >= max) {
+            self.rest = None;
+            return None;
+        }
+
+        let (line, next_rest) = self.split_line(rest);
+        let mut line = line.trim_end_matches([' ', '\t']);
+        self.rest = next_rest;
+
+        let mut ellipsis = None;
+        let is_last_line = self.max_lines.is_some_and(|max| self.line + 1 == max);
+        let has_more_text = next_rest.is_some_and(|r| !r.trim().is_empty());
+        if is_last_line && has_more_text {
+            line = self.truncate_for_ellipsis(line);
+            ellipsis = Some(self.ellipsis());
+            self.rest = None;
+        }
+
+        let columns = self.columns(line) + ellipsis.map_or(0, str::len);
+        let width = (columns * self.char_width).min(self.box_width);
+        let x = match self.alignment {
+            Alignment::Left => 0,
+            Alignment::Center => (self.box_width - width) / 2,
+            Alignment::Right => self.box_width - width,
+        };
+        let y = self.line * self.line_height;
+        self.line += 1;
+
+        Some(WrappedLine {
+            text: line,
+            ellipsis,
+            x,
+            y,
+            width,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "regular", feature = "size_16"))]
+mod tests {
+    use super::*;
+
+    const WEIGHT: FontWeight = FontWeight::Regular;
+    const SIZE: RasterHeight = RasterHeight::Size16;
+
+    /// Returns a box width in pixels for the given amount of columns.
+    const fn cols(n: usize) -> usize {
+        n * get_raster_width(WEIGHT, SIZE)
+    }
+
+    fn assert_lines(iter: WrapIter, expected: &[&str]) {
+        let mut iter = iter.map(|line| line.text());
+        for expected in expected {
+            assert_eq!(iter.next(), Some(*expected));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_wrap_at_whitespace() {
+        let iter = wrap_str("hello  world foo bar", WEIGHT, SIZE, cols(11));
+        assert_lines(iter, &["hello", "world foo", "bar"]);
+        let iter = wrap_str("hello world", WEIGHT, SIZE, cols(5));
+        assert_lines(iter, &["hello", "world"]);
+    }
+
+    #[test]
+    fn test_wrap_at_hyphen_and_long_words() {
+        let iter = wrap_str("well-known", WEIGHT, SIZE, cols(7));
+        assert_lines(iter, &["well-", "known"]);
+        let iter = wrap_str("abcdefghij k", WEIGHT, SIZE, cols(4));
+        assert_lines(iter, &["abcd", "efgh", "ij k"]);
+    }
+
+    #[test]
+    fn test_wrap_hard_line_breaks() {
+        let iter = wrap_str("a\n\nb c", WEIGHT, SIZE, cols(10));
+        assert_lines(iter, &["a", "", "b c"]);
+    }
+
+    #[test]
+    fn test_wrap_alignment() {
+        let mut iter = wrap_str("ab", WEIGHT, SIZE, cols(6)).with_alignment(Alignment::Center);
+        assert_eq!(iter.next().unwrap().x(), cols(2));
+        let mut iter = wrap_str("ab\nc", WEIGHT, SIZE, cols(6)).with_alignment(Alignment::Right);
+        let line = iter.next().unwrap();
+        assert_eq!((line.x(), line.y(), line.width()), (cols(4), 0, cols(2)));
+        let line = iter.next().unwrap();
+        assert_eq!((line.x(), line.y(), line.width()), (cols(5), 16, cols(1)));
+    }
+
+    #[test]
+    fn test_wrap_ellipsis() {
+        let mut iter = wrap_str("one two three four", WEIGHT, SIZE, cols(8)).with_max_height(35);
+        assert_eq!(iter.next().unwrap().text(), "one two");
+        let line = iter.next().unwrap();
+        assert_eq!((line.text(), line.ellipsis()), ("three", Some("...")));
+        assert_eq!(line.width(), cols(8));
+        assert_eq!(iter.next(), None);
+
+        // no ellipsis if everything fits
+        let mut iter = wrap_str("one two", WEIGHT, SIZE, cols(8)).with_max_height(16);
+        assert_eq!(iter.next().unwrap().ellipsis(), None);
+    }
+
+    #[test]
+    fn test_wrap_ellipsis_narrow_box() {
+        for (columns, ellipsis) in [(1, "."), (2, ".."), (3, "...")] {
+            let mut iter = wrap_str("abc def", WEIGHT, SIZE, cols(columns)).with_max_height(16);
+            let line = iter.next().unwrap();
+            assert_eq!((line.text(), line.ellipsis()), ("", Some(ellipsis)));
+            assert_eq!((line.x(), line.width()), (0, cols(columns)));
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_wrap_box_too_small() {
+        assert_lines(wrap_str("abc", WEIGHT, SIZE, cols(1) - 1), &[]);
+        assert_lines(
+            wrap_str("abc", WEIGHT, SIZE, cols(3)).with_max_height(15),
+            &[],
+        );
+    }
+}